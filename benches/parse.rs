@@ -0,0 +1,33 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use easy_color::{Hex, CMYK, HSL, HSLA, HSV, RGB, RGBA};
+use std::hint::black_box;
+
+fn parse(c: &mut Criterion) {
+    c.bench_function("Hex", |b| {
+        b.iter(|| Hex::try_from(black_box("#2bc48a")).unwrap())
+    });
+    c.bench_function("RGB", |b| {
+        b.iter(|| RGB::try_from(black_box("rgb(43, 196, 138)")).unwrap())
+    });
+    c.bench_function("RGB named", |b| {
+        b.iter(|| RGB::try_from(black_box("RebeccaPurple")).unwrap())
+    });
+    c.bench_function("RGBA", |b| {
+        b.iter(|| RGBA::try_from(black_box("rgb(43 196 138 / 50%)")).unwrap())
+    });
+    c.bench_function("HSL", |b| {
+        b.iter(|| HSL::try_from(black_box("hsl(157deg 64% 47%)")).unwrap())
+    });
+    c.bench_function("HSLA", |b| {
+        b.iter(|| HSLA::try_from(black_box("hsla(157, 64%, 47%, 0.5)")).unwrap())
+    });
+    c.bench_function("HSV", |b| {
+        b.iter(|| HSV::try_from(black_box("hsv(157,78%,77%)")).unwrap())
+    });
+    c.bench_function("CMYK", |b| {
+        b.iter(|| CMYK::try_from(black_box("cmyk(78,0,30,23)")).unwrap())
+    });
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,151 @@
+use crate::common::{
+    is_in_unit_range, multiply_matrix, parse_color_space, rgb_to_linear_srgb, ADOBE_RGB_GAMMA,
+    ADOBE_RGB_TO_XYZ, SRGB_TO_XYZ, XYZ_TO_ADOBE_RGB, XYZ_TO_SRGB,
+};
+use crate::{ColorError, Hex, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// AdobeRGB is the Adobe RGB (1998) color space used in print and photography, its greens and cyans are more saturated than sRGB.
+/// It can be parsed from a string in the CSS format "color(a98-rgb r g b)", from a tuple of f32 (r,g,b) or from a tuple of u8 (r,g,b).
+/// * r:f32 - red value(0~1)
+/// * g:f32 - green value(0~1)
+/// * b:f32 - blue value(0~1)
+///
+/// The channels are encoded with a gamma of 2.2 (563/256), the white point is D65.
+/// Colors out of the sRGB gamut are clamped when converted to RGB, use `is_in_srgb_gamut` to check it first.
+/// ### example
+/// ```rust
+/// use easy_color::{AdobeRGB, RGB};
+/// let adobe:AdobeRGB = (0,255,0).try_into().unwrap();
+/// assert_eq!(adobe.to_string(), "color(a98-rgb 0.0000 1.0000 0.0000)");
+/// assert!(!adobe.is_in_srgb_gamut());
+/// let rgb:RGB = adobe.into();
+/// assert_eq!(rgb.to_string(), "rgb(0,255,0)");
+///
+/// let rgb:RGB = (0,255,0).try_into().unwrap();
+/// let adobe:AdobeRGB = rgb.into();
+/// assert_eq!(adobe.to_string(), "color(a98-rgb 0.5650 1.0000 0.2344)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct AdobeRGB {
+    pub(crate) r: f32,
+    pub(crate) g: f32,
+    pub(crate) b: f32,
+}
+
+impl TryFrom<&str> for AdobeRGB {
+    type Error = ColorError;
+    fn try_from(adobe_str: &str) -> Result<Self, Self::Error> {
+        parse_color_space(adobe_str, "a98-rgb", "AdobeRGB")?.try_into()
+    }
+}
+
+impl FromStr for AdobeRGB {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for AdobeRGB {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(0.0..=1.0).contains(&value.1)
+            || !(0.0..=1.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "AdobeRGB: args ({},{},{}) value error, all value must between 0~1!",
+                    value.0, value.1, value.2
+                ),
+            })
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl TryFrom<(u8, u8, u8)> for AdobeRGB {
+    type Error = ColorError;
+    fn try_from(value: (u8, u8, u8)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            r: value.0 as f32 / 255.0,
+            g: value.1 as f32 / 255.0,
+            b: value.2 as f32 / 255.0,
+        })
+    }
+}
+
+impl From<Hex> for AdobeRGB {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for AdobeRGB {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let xyz = multiply_matrix(&SRGB_TO_XYZ, rgb_to_linear_srgb(r, g, b));
+        let (r, g, b) = multiply_matrix(&XYZ_TO_ADOBE_RGB, xyz);
+        let encode = |v: f32| v.clamp(0.0, 1.0).powf(1.0 / ADOBE_RGB_GAMMA);
+        Self {
+            r: encode(r),
+            g: encode(g),
+            b: encode(b),
+        }
+    }
+}
+
+impl From<RGBA> for AdobeRGB {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for AdobeRGB {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "color(a98-rgb {:.4} {:.4} {:.4})",
+            self.r, self.g, self.b
+        )
+    }
+}
+
+impl AdobeRGB {
+    pub fn red(&self) -> f32 {
+        self.r
+    }
+
+    pub fn green(&self) -> f32 {
+        self.g
+    }
+
+    pub fn blue(&self) -> f32 {
+        self.b
+    }
+
+    /// Returns the linear light sRGB channels of the color, they are out of 0~1 when the color is out of the sRGB gamut.
+    pub(crate) fn to_linear_srgb(self) -> (f32, f32, f32) {
+        let lin = (
+            self.r.powf(ADOBE_RGB_GAMMA),
+            self.g.powf(ADOBE_RGB_GAMMA),
+            self.b.powf(ADOBE_RGB_GAMMA),
+        );
+        multiply_matrix(&XYZ_TO_SRGB, multiply_matrix(&ADOBE_RGB_TO_XYZ, lin))
+    }
+
+    /// Check whether the color can be represented in sRGB without clamping.
+    pub fn is_in_srgb_gamut(&self) -> bool {
+        is_in_unit_range(self.to_linear_srgb())
+    }
+}
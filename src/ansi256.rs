@@ -0,0 +1,168 @@
+use crate::common::redmean_distance_squared;
+use crate::{ColorError, Hex, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+
+/// The default colors of the 16 system entries, as used by xterm.
+const SYSTEM_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (128, 0, 0),
+    (0, 128, 0),
+    (128, 128, 0),
+    (0, 0, 128),
+    (128, 0, 128),
+    (0, 128, 128),
+    (192, 192, 192),
+    (128, 128, 128),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (0, 0, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The channel levels of the 6x6x6 color cube.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The escape sequence that resets the colors of a terminal
+pub(crate) const ANSI_RESET: &str = "\x1b[0m";
+
+/// The color encoding of the terminal escape sequences, from `RGB::to_ansi_fg_with` and `RGB::paint_with`.
+/// * TrueColor - 24 bit colors like "\x1b[38;2;43;196;138m", supported by most modern terminals
+/// * Ansi256 - the nearest entry of the xterm 256 color palette like "\x1b[38;5;36m"
+/// * Ansi16 - the nearest of the 16 system colors like "\x1b[92m", for the most basic terminals
+///
+/// The system colors depend on the terminal, so Ansi16 is matched against the xterm defaults.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum AnsiMode {
+    #[default]
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+impl AnsiMode {
+    /// Returns the escape sequence that sets the foreground or background color
+    pub(crate) fn escape(&self, rgb: RGB, background: bool) -> String {
+        let layer = if background { 48 } else { 38 };
+        match self {
+            AnsiMode::TrueColor => format!("\x1b[{};2;{};{};{}m", layer, rgb.r, rgb.g, rgb.b),
+            AnsiMode::Ansi256 => format!("\x1b[{};5;{}m", layer, Ansi256::from(rgb).0),
+            AnsiMode::Ansi16 => {
+                // 30~37 and 90~97 are the normal and bright foregrounds, the backgrounds are 10 higher
+                let index = Ansi256::nearest_system(rgb);
+                let code = if index < 8 { 30 + index } else { 82 + index };
+                format!("\x1b[{}m", code + if background { 10 } else { 0 })
+            }
+        }
+    }
+}
+
+/// Ansi256 is an index of the xterm 256 color palette used by terminals.
+/// It can be created from an index(0~255) or converted from RGB, RGBA and Hex.
+/// * 0~15 - the system colors, their actual colors depend on the terminal, the xterm defaults are used
+/// * 16~231 - a 6x6x6 color cube
+/// * 232~255 - a grayscale ramp from 8 to 238
+///
+/// Converting from RGB picks the nearest entry of the cube and the grayscale ramp with the "redmean" weighted distance,
+/// the system colors are skipped because terminals change them. When two entries are equally near, the grayscale ramp wins.
+/// ### example
+/// ```rust
+/// use easy_color::{Ansi256, RGB};
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// let ansi:Ansi256 = rgb.into();
+/// assert_eq!(ansi.to_index(), 196);
+///
+/// let rgb:RGB = (128,128,128).try_into().unwrap();
+/// assert_eq!(Ansi256::from(rgb).to_index(), 244);
+///
+/// let rgb:RGB = Ansi256::from_index(208).into();
+/// assert_eq!(rgb.to_string(), "rgb(255,135,0)");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Ansi256(pub(crate) u8);
+
+impl TryFrom<u8> for Ansi256 {
+    type Error = ColorError;
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        Ok(Self(index))
+    }
+}
+
+impl From<Hex> for Ansi256 {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for Ansi256 {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let mut best = (f32::MAX, 16);
+        // the grayscale ramp goes first, so it wins ties against the cube
+        for index in (232..=255).chain(16..=231) {
+            let distance = redmean_distance_squared((r, g, b), Self(index).to_tuple());
+            if distance < best.0 {
+                best = (distance, index);
+            }
+        }
+        Self(best.1)
+    }
+}
+
+impl From<RGBA> for Ansi256 {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for Ansi256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ansi256({})", self.0)
+    }
+}
+
+impl Ansi256 {
+    pub fn from_index(index: u8) -> Self {
+        Self(index)
+    }
+
+    pub fn to_index(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns the index(0~15) of the nearest system color
+    pub(crate) fn nearest_system(rgb: RGB) -> u8 {
+        let RGB { r, g, b } = rgb;
+        let mut best = (f32::MAX, 0);
+        for (index, color) in SYSTEM_COLORS.iter().enumerate() {
+            let distance = redmean_distance_squared((r, g, b), *color);
+            if distance < best.0 {
+                best = (distance, index as u8);
+            }
+        }
+        best.1
+    }
+
+    /// Returns the canonical palette color of the index
+    pub(crate) fn to_tuple(self) -> (u8, u8, u8) {
+        match self.0 {
+            0..=15 => SYSTEM_COLORS[self.0 as usize],
+            16..=231 => {
+                let i = (self.0 - 16) as usize;
+                (
+                    CUBE_LEVELS[i / 36],
+                    CUBE_LEVELS[i / 6 % 6],
+                    CUBE_LEVELS[i % 6],
+                )
+            }
+            _ => {
+                let v = 8 + (self.0 - 232) * 10;
+                (v, v, v)
+            }
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use crate::common::{color_function_args, parse_color_function_args, parse_err};
+use crate::{
+    AdobeRGB, ColorError, Gray, HSLuv, Hex, OkLch, ParseOptions, ProPhotoRGB, RGBf, Rec2020, CMYK,
+    CMYKA, HSI, HSL, HSLA, HSV, HWB, LCH, P3, RGB, RGB16, RGBA,
+};
+use std::fmt::{Display, Formatter};
+
+/// A color parsed by `parse`, the variant tells which format the string was in.
+/// It can be converted into RGB, RGBA and Hex, so the result can be used without matching the variant.
+/// ### example
+/// ```rust
+/// use easy_color::{AnyColor, IntoRGBA};
+/// let color = easy_color::parse("hsl(157 64% 47% / 0.5)").unwrap();
+/// assert!(matches!(color, AnyColor::HSLA(_)));
+/// assert_eq!(color.to_rgba().to_string(), "rgba(43,196,138,0.50)");
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AnyColor {
+    Hex(Hex),
+    RGB(RGB),
+    RGBA(RGBA),
+    HSL(HSL),
+    HSLA(HSLA),
+    HSV(HSV),
+    HSI(HSI),
+    HWB(HWB),
+    CMYK(CMYK),
+    CMYKA(CMYKA),
+    LCH(LCH),
+    OkLch(OkLch),
+    HSLuv(HSLuv),
+    Gray(Gray),
+    RGB16(RGB16),
+    RGBf(RGBf),
+    P3(P3),
+    Rec2020(Rec2020),
+    AdobeRGB(AdobeRGB),
+    ProPhotoRGB(ProPhotoRGB),
+}
+
+/// The formats `parse` tries, used in the error message when nothing matches.
+const FORMATS: &str = "hex with a '#' or '0x' prefix, rgb(), rgba(), hsl(), hsla(), hsv(), hsb(), hsi(), hwb(), cmyk(), device-cmyk(), cmyka(), lch(), oklch(), hsluv(), gray(), color() and named colors";
+
+/// The function names `parse` dispatches on, matched case insensitively
+const FUNCTIONS: [&str; 16] = [
+    "rgb",
+    "rgba",
+    "hsl",
+    "hsla",
+    "hsv",
+    "hsb",
+    "hsi",
+    "hwb",
+    "cmyk",
+    "device-cmyk",
+    "cmyka",
+    "lch",
+    "oklch",
+    "hsluv",
+    "gray",
+    "color",
+];
+
+/// Parse a color string in any supported format, the format is detected from the prefix of the string.
+/// * "#2bc48a" and "0x2bc48a" are Hex, or RGB16 with 12 digits, a bare "2bc48a" is not detected,
+///   since words like "bad" or "face" would be taken as hex, parse it with `Hex::try_from` instead
+/// * "rgb()" and "hsl()" with an alpha value are RGBA and HSLA
+/// * "color()" is RGBf, P3, Rec2020, AdobeRGB or ProPhotoRGB, depending on the color space,
+///   and RGBA in srgb-linear or in srgb with an alpha value, the other color spaces have no alpha
+/// * a named color is RGB, and "transparent" is RGBA
+///
+/// When the prefix is recognized, the error of that format is returned,
+/// otherwise the error lists every format that was attempted.
+pub fn parse(color_str: &str) -> Result<AnyColor, ColorError> {
+    let color = color_str.trim();
+    if let Some((name, _)) = color.split_once('(') {
+        let prefix = FUNCTIONS
+            .iter()
+            .find(|f| f.eq_ignore_ascii_case(name))
+            .copied()
+            .unwrap_or_default();
+        return match prefix {
+            "rgb" | "rgba" => RGB::try_from(color_str)
+                .map(AnyColor::RGB)
+                .or_else(|_| RGBA::try_from(color_str).map(AnyColor::RGBA)),
+            "hsl" | "hsla" => HSL::try_from(color_str)
+                .map(AnyColor::HSL)
+                .or_else(|_| HSLA::try_from(color_str).map(AnyColor::HSLA)),
+            "hsv" | "hsb" => HSV::try_from(color_str).map(AnyColor::HSV),
+            "hsi" => HSI::try_from(color_str).map(AnyColor::HSI),
+            "hwb" => HWB::try_from(color_str).map(AnyColor::HWB),
+            "cmyk" | "device-cmyk" => CMYK::try_from(color_str).map(AnyColor::CMYK),
+            "cmyka" => CMYKA::try_from(color_str).map(AnyColor::CMYKA),
+            "lch" => LCH::try_from(color_str).map(AnyColor::LCH),
+            "oklch" => OkLch::try_from(color_str).map(AnyColor::OkLch),
+            "hsluv" => HSLuv::try_from(color_str).map(AnyColor::HSLuv),
+            "gray" => Gray::try_from(color_str).map(AnyColor::Gray),
+            "color" => parse_color_function(color_str),
+            _ => Err(ColorError::FormatErr {
+                input: color_str.to_string(),
+                reason: format!("'{}' is not a color, tried {}!", color_str, FORMATS),
+            }),
+        };
+    }
+    if color.eq_ignore_ascii_case("transparent") {
+        return RGBA::try_from(color_str).map(AnyColor::RGBA);
+    }
+    let is_hex = color.starts_with('#')
+        || color
+            .get(..2)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("0x"));
+    if !is_hex {
+        return RGB::try_from(color_str)
+            .map(AnyColor::RGB)
+            .map_err(|_| ColorError::FormatErr {
+                input: color_str.to_string(),
+                reason: format!("'{}' is not a color, tried {}!", color_str, FORMATS),
+            });
+    }
+    // RGB16 is tried before Hex, which also accepts 12 digits but rounds them to 8 bit
+    RGB16::try_from(color_str)
+        .map(AnyColor::RGB16)
+        .or_else(|_| Hex::try_from(color_str).map(AnyColor::Hex))
+        .map_err(|_| ColorError::FormatErr {
+            input: color_str.to_string(),
+            reason: format!("'{}' is not a color, tried {}!", color_str, FORMATS),
+        })
+}
+
+/// Parse a CSS `color()` function into the type of its color space
+fn parse_color_function(color_str: &str) -> Result<AnyColor, ColorError> {
+    let Some(args) = color_function_args(color_str.trim(), &["color"]) else {
+        return Err(ColorError::FormatErr {
+            input: color_str.to_string(),
+            reason: format!("'{}' is not a color, tried {}!", color_str, FORMATS),
+        });
+    };
+    let (space, _, alpha) = parse_color_function_args(args, &ParseOptions::default())
+        .map_err(|e| parse_err(color_str, e))?;
+    match space {
+        "srgb" if alpha.is_none() => RGBf::try_from(color_str).map(AnyColor::RGBf),
+        "srgb" | "srgb-linear" => RGBA::try_from(color_str).map(AnyColor::RGBA),
+        "display-p3" => P3::try_from(color_str).map(AnyColor::P3),
+        "rec2020" => Rec2020::try_from(color_str).map(AnyColor::Rec2020),
+        "a98-rgb" => AdobeRGB::try_from(color_str).map(AnyColor::AdobeRGB),
+        _ => ProPhotoRGB::try_from(color_str).map(AnyColor::ProPhotoRGB),
+    }
+}
+
+/// Parse a list of colors like "#2bc48a, #ff7d37, rgb(0,0,0)" or a hex per line, each entry is parsed by `parse`.
+/// The entries are separated by commas, newlines or whitespace outside of parentheses,
+/// empty entries and trailing separators are ignored.
+/// The error reports the index and the content of the first entry that fails.
+/// ### example
+/// ```rust
+/// let colors = easy_color::parse_list("#2bc48a, #ff7d37, rgb(0, 0, 0),").unwrap();
+/// assert_eq!(colors.len(), 3);
+/// assert_eq!(colors[2].to_string(), "rgb(0,0,0)");
+///
+/// let err = easy_color::parse_list("#2bc48a\nnot-a-color").unwrap_err();
+/// assert!(err.to_string().starts_with("entry 1: 'not-a-color' is not a color"));
+/// ```
+pub fn parse_list(list_str: &str) -> Result<Vec<AnyColor>, ColorError> {
+    let mut depth = 0usize;
+    let entries = list_str.split(|c: char| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        depth == 0 && (c == ',' || c.is_whitespace())
+    });
+    entries
+        .filter(|entry| !entry.is_empty())
+        .enumerate()
+        .map(|(idx, entry)| {
+            // the error of the entry already names it, like "'bad' is not a color"
+            parse(entry).map_err(|err| ColorError::FormatErr {
+                input: list_str.to_string(),
+                reason: format!("entry {}: {}", idx, err),
+            })
+        })
+        .collect()
+}
+
+impl From<AnyColor> for RGBA {
+    fn from(color: AnyColor) -> Self {
+        match color {
+            AnyColor::Hex(c) => c.into(),
+            AnyColor::RGB(c) => c.into(),
+            AnyColor::RGBA(c) => c,
+            AnyColor::HSL(c) => c.into(),
+            AnyColor::HSLA(c) => c.into(),
+            AnyColor::HSV(c) => c.into(),
+            AnyColor::HSI(c) => c.into(),
+            AnyColor::HWB(c) => c.into(),
+            AnyColor::CMYK(c) => c.into(),
+            AnyColor::CMYKA(c) => c.into(),
+            AnyColor::LCH(c) => c.into(),
+            AnyColor::OkLch(c) => c.into(),
+            AnyColor::HSLuv(c) => c.into(),
+            AnyColor::Gray(c) => c.into(),
+            AnyColor::RGB16(c) => c.into(),
+            AnyColor::RGBf(c) => c.into(),
+            AnyColor::P3(c) => c.into(),
+            AnyColor::Rec2020(c) => c.into(),
+            AnyColor::AdobeRGB(c) => c.into(),
+            AnyColor::ProPhotoRGB(c) => c.into(),
+        }
+    }
+}
+
+impl From<AnyColor> for RGB {
+    fn from(color: AnyColor) -> Self {
+        let rgba: RGBA = color.into();
+        rgba.into()
+    }
+}
+
+impl From<AnyColor> for Hex {
+    fn from(color: AnyColor) -> Self {
+        let rgba: RGBA = color.into();
+        rgba.into()
+    }
+}
+
+impl Display for AnyColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyColor::Hex(c) => c.fmt(f),
+            AnyColor::RGB(c) => c.fmt(f),
+            AnyColor::RGBA(c) => c.fmt(f),
+            AnyColor::HSL(c) => c.fmt(f),
+            AnyColor::HSLA(c) => c.fmt(f),
+            AnyColor::HSV(c) => c.fmt(f),
+            AnyColor::HSI(c) => c.fmt(f),
+            AnyColor::HWB(c) => c.fmt(f),
+            AnyColor::CMYK(c) => c.fmt(f),
+            AnyColor::CMYKA(c) => c.fmt(f),
+            AnyColor::LCH(c) => c.fmt(f),
+            AnyColor::OkLch(c) => c.fmt(f),
+            AnyColor::HSLuv(c) => c.fmt(f),
+            AnyColor::Gray(c) => c.fmt(f),
+            AnyColor::RGB16(c) => c.fmt(f),
+            AnyColor::RGBf(c) => c.fmt(f),
+            AnyColor::P3(c) => c.fmt(f),
+            AnyColor::Rec2020(c) => c.fmt(f),
+            AnyColor::AdobeRGB(c) => c.fmt(f),
+            AnyColor::ProPhotoRGB(c) => c.fmt(f),
+        }
+    }
+}
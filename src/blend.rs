@@ -0,0 +1,82 @@
+/// The separable blend modes of the CSS Compositing spec, for `RGBA::blend`.
+/// Each mode mixes the channels(0~1) of the backdrop and the source color,
+/// like "mix-blend-mode" of CSS and the layer modes of Photoshop.
+/// ### example
+/// ```rust
+/// use easy_color::{BlendMode, Hex, RGBA};
+/// let gray:RGBA = Hex::try_from("#808080").unwrap().into();
+/// assert_eq!(Hex::from(gray.blend(gray, BlendMode::Multiply)).to_string(), "#404040");
+/// assert_eq!(Hex::from(gray.blend(gray, BlendMode::Screen)).to_string(), "#C0C0C0");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum BlendMode {
+    /// the source color
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    /// HardLight with the backdrop and the source swapped
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+}
+
+impl BlendMode {
+    /// Returns the blended channel of the backdrop and the source, all of them 0~1
+    pub(crate) fn apply(&self, backdrop: f32, source: f32) -> f32 {
+        let (cb, cs) = (backdrop, source);
+        match self {
+            BlendMode::Normal => cs,
+            BlendMode::Multiply => cb * cs,
+            BlendMode::Screen => cb + cs - cb * cs,
+            BlendMode::Overlay => BlendMode::HardLight.apply(cs, cb),
+            BlendMode::Darken => cb.min(cs),
+            BlendMode::Lighten => cb.max(cs),
+            BlendMode::ColorDodge => {
+                if cb == 0.0 {
+                    0.0
+                } else if cs == 1.0 {
+                    1.0
+                } else {
+                    (cb / (1.0 - cs)).min(1.0)
+                }
+            }
+            BlendMode::ColorBurn => {
+                if cb == 1.0 {
+                    1.0
+                } else if cs == 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - cb) / cs).min(1.0)
+                }
+            }
+            BlendMode::HardLight => {
+                if cs <= 0.5 {
+                    BlendMode::Multiply.apply(cb, 2.0 * cs)
+                } else {
+                    BlendMode::Screen.apply(cb, 2.0 * cs - 1.0)
+                }
+            }
+            BlendMode::SoftLight => {
+                if cs <= 0.5 {
+                    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+                } else {
+                    let d = if cb <= 0.25 {
+                        ((16.0 * cb - 12.0) * cb + 4.0) * cb
+                    } else {
+                        cb.sqrt()
+                    };
+                    cb + (2.0 * cs - 1.0) * (d - cb)
+                }
+            }
+            BlendMode::Difference => (cb - cs).abs(),
+            BlendMode::Exclusion => cb + cs - 2.0 * cb * cs,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+/// The order of the channels in a packed u32 color, named from the highest byte to the lowest one.
+/// * Rgb - 0x00RRGGBB, the highest byte is ignored
+/// * Bgr - 0x00BBGGRR, the COLORREF of Win32, the highest byte is ignored
+/// * Rgba - 0xRRGGBBAA, like CSS hex colors
+/// * Bgra - 0xBBGGRRAA
+/// * Argb - 0xAARRGGBB, like Android and .NET colors
+/// * Abgr - 0xAABBGGRR, like the RGBA pixels of a little endian image buffer
+///
+/// The orders without alpha are read with the alpha 255 and written with the highest byte zeroed,
+/// the alpha is ignored when RGB is read from an order with alpha.
+/// ### example
+/// ```rust
+/// use easy_color::{ByteOrder, RGB, RGBA};
+/// let rgb = RGB::from_u32_with(0x008AC42B, ByteOrder::Bgr);
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+/// assert_eq!(rgb.to_u32_with(ByteOrder::Rgb), 0x2BC48A);
+///
+/// let rgba = RGBA::from_u32_with(0x802BC48A, ByteOrder::Argb);
+/// assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+/// assert_eq!(rgba.to_u32_with(ByteOrder::Abgr), 0x808AC42B);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum ByteOrder {
+    #[default]
+    Rgb,
+    Bgr,
+    Rgba,
+    Bgra,
+    Argb,
+    Abgr,
+}
+
+impl ByteOrder {
+    /// Returns true if the order has an alpha byte
+    pub fn has_alpha(&self) -> bool {
+        !matches!(self, ByteOrder::Rgb | ByteOrder::Bgr)
+    }
+
+    /// Returns [r, g, b, a] of the packed value, the alpha is 255 for the orders without alpha
+    pub(crate) fn unpack(&self, value: u32) -> [u8; 4] {
+        let [b0, b1, b2, b3] = value.to_be_bytes();
+        match self {
+            ByteOrder::Rgb => [b1, b2, b3, 255],
+            ByteOrder::Bgr => [b3, b2, b1, 255],
+            ByteOrder::Rgba => [b0, b1, b2, b3],
+            ByteOrder::Bgra => [b2, b1, b0, b3],
+            ByteOrder::Argb => [b1, b2, b3, b0],
+            ByteOrder::Abgr => [b3, b2, b1, b0],
+        }
+    }
+
+    /// Returns the packed value of [r, g, b, a], the highest byte is 0 for the orders without alpha
+    pub(crate) fn pack(&self, rgba: [u8; 4]) -> u32 {
+        let [r, g, b, a] = rgba;
+        let bytes = match self {
+            ByteOrder::Rgb => [0, r, g, b],
+            ByteOrder::Bgr => [0, b, g, r],
+            ByteOrder::Rgba => [r, g, b, a],
+            ByteOrder::Bgra => [b, g, r, a],
+            ByteOrder::Argb => [a, r, g, b],
+            ByteOrder::Abgr => [a, b, g, r],
+        };
+        u32::from_be_bytes(bytes)
+    }
+}
@@ -0,0 +1,319 @@
+use crate::common::{multiply_matrix, rgb_to_xyz, xyz_to_rgb};
+use crate::{ColorError, Hex, RGB, RGBA};
+use std::f32::consts::PI;
+use std::fmt::{Display, Formatter};
+
+/// CIE XYZ to the sharpened cone responses used by CAM16.
+const XYZ_TO_CAM16RGB: [[f32; 3]; 3] = [
+    [0.401_288, 0.650_173, -0.051_461],
+    [-0.250_268, 1.204_414, 0.045_854],
+    [-0.002_079, 0.048_952, 0.953_127],
+];
+
+/// The sharpened cone responses used by CAM16 to CIE XYZ.
+const CAM16RGB_TO_XYZ: [[f32; 3]; 3] = [
+    [1.862_067_9, -1.011_254_7, 0.149_186_78],
+    [0.387_526_5, 0.621_447_4, -0.008_973_98],
+    [-0.015_841_5, -0.034_122_94, 1.049_964_4],
+];
+
+/// The viewing conditions of CAM16, they describe the environment the color is seen in.
+/// The derived parameters of the model are computed once by `new`.
+///
+/// The default conditions are the ones of the reference implementation(Material color utilities):
+/// D65 white, an adapting luminance of 200/π times the luminance of L* 50, a background of L* 50,
+/// an average surround and no discounting of the illuminant.
+/// ### example
+/// ```rust
+/// use easy_color::{Cam16, RGB, ViewingConditions};
+/// let dim = ViewingConditions::new((95.047, 100.0, 108.883), 5.0, 20.0, 1.0, false);
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// let cam16 = Cam16::from_rgb_with(rgb, &dim);
+/// assert_eq!(cam16.to_rgb_with(&dim), rgb);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ViewingConditions {
+    pub(crate) n: f32,
+    pub(crate) aw: f32,
+    pub(crate) nbb: f32,
+    pub(crate) ncb: f32,
+    pub(crate) c: f32,
+    pub(crate) nc: f32,
+    pub(crate) rgb_d: [f32; 3],
+    pub(crate) fl: f32,
+    pub(crate) fl_root: f32,
+    pub(crate) z: f32,
+}
+
+impl Default for ViewingConditions {
+    fn default() -> Self {
+        let background_y = 100.0 * ((50.0 + 16.0) / 116.0_f32).powi(3);
+        Self::new(
+            (95.047, 100.0, 108.883),
+            200.0 / PI * background_y / 100.0,
+            50.0,
+            2.0,
+            false,
+        )
+    }
+}
+
+impl ViewingConditions {
+    /// Create viewing conditions.
+    /// * white_point:(f32,f32,f32) - XYZ of the white, Y is 100
+    /// * adapting_luminance:f32 - the luminance of the adapting field in cd/m², usually 20% of the white luminance
+    /// * background_lstar:f32 - the CIE L* of the background(0~100)
+    /// * surround:f32 - 0 is dark, 1 is dim and 2 is average(0~2)
+    /// * discounting_illuminant:bool - whether the eye fully adapts to the illuminant
+    pub fn new(
+        white_point: (f32, f32, f32),
+        adapting_luminance: f32,
+        background_lstar: f32,
+        surround: f32,
+        discounting_illuminant: bool,
+    ) -> Self {
+        let background_lstar = background_lstar.clamp(0.1, 100.0);
+        let surround = surround.clamp(0.0, 2.0);
+        let (rw, gw, bw) = multiply_matrix(&XYZ_TO_CAM16RGB, white_point);
+        let f = 0.8 + surround / 10.0;
+        let c = if f >= 0.9 {
+            0.59 + (0.69 - 0.59) * ((f - 0.9) * 10.0)
+        } else {
+            0.525 + (0.59 - 0.525) * ((f - 0.8) * 10.0)
+        };
+        let d = if discounting_illuminant {
+            1.0
+        } else {
+            f * (1.0 - (1.0 / 3.6) * ((-adapting_luminance - 42.0) / 92.0).exp())
+        }
+        .clamp(0.0, 1.0);
+        let rgb_d = [
+            d * (100.0 / rw) + 1.0 - d,
+            d * (100.0 / gw) + 1.0 - d,
+            d * (100.0 / bw) + 1.0 - d,
+        ];
+        let k = 1.0 / (5.0 * adapting_luminance + 1.0);
+        let k4 = k.powi(4);
+        let k4f = 1.0 - k4;
+        let fl = k4 * adapting_luminance + 0.1 * k4f * k4f * (5.0 * adapting_luminance).cbrt();
+        let background_y = if background_lstar > 8.0 {
+            100.0 * ((background_lstar + 16.0) / 116.0).powi(3)
+        } else {
+            100.0 * background_lstar / (24389.0 / 27.0)
+        };
+        let n = background_y / white_point.1;
+        let z = 1.48 + n.sqrt();
+        let nbb = 0.725 / n.powf(0.2);
+        let adapt = |v: f32, d: f32| {
+            let af = (fl * d * v / 100.0).powf(0.42);
+            400.0 * af / (af + 27.13)
+        };
+        let rgb_a = [
+            adapt(rw, rgb_d[0]),
+            adapt(gw, rgb_d[1]),
+            adapt(bw, rgb_d[2]),
+        ];
+        let aw = (2.0 * rgb_a[0] + rgb_a[1] + 0.05 * rgb_a[2]) * nbb;
+        Self {
+            n,
+            aw,
+            nbb,
+            ncb: nbb,
+            c,
+            nc: f,
+            rgb_d,
+            fl,
+            fl_root: fl.powf(0.25),
+            z,
+        }
+    }
+
+    /// Returns the CAM16 lightness J, colorfulness M and hue(0~360) of the XYZ color, Y of white is 100.
+    pub(crate) fn xyz_to_jmh(&self, xyz: (f32, f32, f32)) -> (f32, f32, f32) {
+        let (r, g, b) = multiply_matrix(&XYZ_TO_CAM16RGB, xyz);
+        let adapt = |v: f32, d: f32| {
+            let v = v * d;
+            let af = (self.fl * v.abs() / 100.0).powf(0.42);
+            v.signum() * 400.0 * af / (af + 27.13)
+        };
+        let (ra, ga, ba) = (
+            adapt(r, self.rgb_d[0]),
+            adapt(g, self.rgb_d[1]),
+            adapt(b, self.rgb_d[2]),
+        );
+        let a = (11.0 * ra - 12.0 * ga + ba) / 11.0;
+        let b = (ra + ga - 2.0 * ba) / 9.0;
+        let u = (20.0 * ra + 20.0 * ga + 21.0 * ba) / 20.0;
+        let p2 = (40.0 * ra + 20.0 * ga + ba) / 20.0;
+        let hue = b.atan2(a).to_degrees().rem_euclid(360.0);
+        let ac = p2 * self.nbb;
+        let j = 100.0 * (ac / self.aw).max(0.0).powf(self.c * self.z);
+        let hue_prime = if hue < 20.14 { hue + 360.0 } else { hue };
+        let e_hue = 0.25 * ((hue_prime.to_radians() + 2.0).cos() + 3.8);
+        let p1 = 50000.0 / 13.0 * e_hue * self.nc * self.ncb;
+        let t = p1 * a.hypot(b) / (u + 0.305);
+        let alpha = t.powf(0.9) * (1.64 - 0.29_f32.powf(self.n)).powf(0.73);
+        let chroma = alpha * (j / 100.0).sqrt();
+        (j, chroma * self.fl_root, hue)
+    }
+
+    /// Returns the XYZ color, Y of white is 100, of the CAM16 lightness J, colorfulness M and hue(0~360).
+    pub(crate) fn jmh_to_xyz(&self, j: f32, m: f32, h: f32) -> (f32, f32, f32) {
+        let chroma = m / self.fl_root;
+        let alpha = if chroma == 0.0 || j == 0.0 {
+            0.0
+        } else {
+            chroma / (j / 100.0).sqrt()
+        };
+        let t = (alpha / (1.64 - 0.29_f32.powf(self.n)).powf(0.73)).powf(1.0 / 0.9);
+        let h_rad = h.to_radians();
+        let e_hue = 0.25 * ((h_rad + 2.0).cos() + 3.8);
+        let ac = self.aw * (j / 100.0).powf(1.0 / self.c / self.z);
+        let p1 = e_hue * (50000.0 / 13.0) * self.nc * self.ncb;
+        let p2 = ac / self.nbb;
+        let (h_sin, h_cos) = h_rad.sin_cos();
+        let gamma = 23.0 * (p2 + 0.305) * t / (23.0 * p1 + 11.0 * t * h_cos + 108.0 * t * h_sin);
+        let a = gamma * h_cos;
+        let b = gamma * h_sin;
+        let ra = (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0;
+        let ga = (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0;
+        let ba = (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0;
+        let unadapt = |v: f32, d: f32| {
+            let base = (27.13 * v.abs() / (400.0 - v.abs())).max(0.0);
+            v.signum() * (100.0 / self.fl) * base.powf(1.0 / 0.42) / d
+        };
+        let rgb = (
+            unadapt(ra, self.rgb_d[0]),
+            unadapt(ga, self.rgb_d[1]),
+            unadapt(ba, self.rgb_d[2]),
+        );
+        multiply_matrix(&CAM16RGB_TO_XYZ, rgb)
+    }
+}
+
+/// Cam16 is a color in CAM16-UCS, the uniform color space of the CAM16 color appearance model, which is also used by Material You.
+/// It can be created from a tuple (j,a,b) or converted from RGB, RGBA and Hex.
+/// * j:f32 - lightness J*(0~100)
+/// * a:f32 - the red-green coordinate a*
+/// * b:f32 - the yellow-blue coordinate b*
+///
+/// `From` and `Into` use the default `ViewingConditions`, `from_rgb_with` and `to_rgb_with` accept any others.
+/// Colors out of the sRGB gamut are clamped when converted to RGB.
+/// ### example
+/// ```rust
+/// use easy_color::{Cam16, RGB};
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// let red:Cam16 = rgb.into();
+/// assert_eq!(red.to_string(), "cam16ucs(59.59,43.30,22.45)");
+///
+/// let rgb:RGB = (255,8,0).try_into().unwrap();
+/// assert!(red.delta_e_cam16(rgb) < 1.0);
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Cam16 {
+    pub(crate) j: f32,
+    pub(crate) a: f32,
+    pub(crate) b: f32,
+}
+
+impl TryFrom<(f32, f32, f32)> for Cam16 {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=100.0).contains(&value.0) || !value.1.is_finite() || !value.2.is_finite() {
+            Err(ColorError::ValueErr { input: format!("{:?}", value), reason: format!(
+                "Cam16: args ({},{},{}) value error, first value must between 0~100, others must be finite!",
+                value.0, value.1, value.2
+            ) })
+        } else {
+            Ok(Self {
+                j: value.0,
+                a: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Cam16 {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for Cam16 {
+    fn from(rgb: RGB) -> Self {
+        Self::from_rgb_with(rgb, &ViewingConditions::default())
+    }
+}
+
+impl From<RGBA> for Cam16 {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for Cam16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cam16ucs({:.2},{:.2},{:.2})", self.j, self.a, self.b)
+    }
+}
+
+impl Cam16 {
+    /// Convert a color into CAM16-UCS under the given viewing conditions.
+    pub fn from_rgb_with(rgb: impl Into<RGB>, conditions: &ViewingConditions) -> Self {
+        let RGB { r, g, b } = rgb.into();
+        let (x, y, z) = rgb_to_xyz(r, g, b);
+        let (j, m, h) = conditions.xyz_to_jmh((x * 100.0, y * 100.0, z * 100.0));
+        let j = 1.7 * j / (1.0 + 0.007 * j);
+        let m = (1.0 + 0.0228 * m).ln() / 0.0228;
+        let (sin, cos) = h.to_radians().sin_cos();
+        Self {
+            j,
+            a: m * cos,
+            b: m * sin,
+        }
+    }
+
+    /// Convert the color into RGB under the given viewing conditions.
+    pub fn to_rgb_with(&self, conditions: &ViewingConditions) -> RGB {
+        let j = self.j / (1.0 - (self.j - 100.0) * 0.007);
+        let m = ((self.colorfulness() * 0.0228).exp() - 1.0) / 0.0228;
+        let (x, y, z) = conditions.jmh_to_xyz(j, m, self.hue());
+        let (r, g, b) = xyz_to_rgb(x / 100.0, y / 100.0, z / 100.0);
+        RGB { r, g, b }
+    }
+
+    pub fn j(&self) -> f32 {
+        self.j
+    }
+
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+
+    /// Returns the UCS colorfulness M*, the distance to the neutral axis.
+    pub fn colorfulness(&self) -> f32 {
+        self.a.hypot(self.b)
+    }
+
+    /// Returns the hue(0~360).
+    pub fn hue(&self) -> f32 {
+        self.b.atan2(self.a).to_degrees().rem_euclid(360.0)
+    }
+
+    /// Returns the color difference ΔE' of CAM16-UCS, a value about 1 is a just noticeable difference.
+    /// * other - any struct that impl into Cam16
+    pub fn delta_e_cam16(&self, other: impl Into<Self>) -> f32 {
+        let other: Cam16 = other.into();
+        let de =
+            ((self.j - other.j).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+                .sqrt();
+        1.41 * de.powf(0.63)
+    }
+}
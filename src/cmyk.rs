@@ -1,13 +1,21 @@
-use crate::common::{calc_rgb_with_alpha, rgb_to_cmyk};
-use crate::{ColorError, Hex, HSL, HSLA, HSV, RGB, RGBA};
+use crate::common::{
+    calc_rgb_with_alpha, color_function_args, format_trimmed, parse_cmyk_channels,
+    parse_device_cmyk, parse_err, rgb_to_cmyk, Components,
+};
+use crate::{ColorError, Hex, OkLch, CMYKA, HSL, HSLA, HSV, HWB, LCH, RGB, RGBA};
 use rand::Rng;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
-/// CMYK can be parsed from a string in the format "cmyk(c,m,y,k)" or from a tuple (c,m,y,k).
+/// CMYK can be parsed from a string in the format "cmyk(c,m,y,k)", the CSS format "device-cmyk(c m y k)" or from a tuple (c,m,y,k).
 /// * c:u8 - cyan value(0~100)
 /// * m:u8 - magenta value(0~100)
 /// * y:u8 - yellow value(0~100)
 /// * k:u8 - black value(0~100)
+///
+/// The values of "cmyk()" are integers(0~100), percentages like "78%" or numbers(0~1) like "0.78",
+/// one form is used for all values and the values out of range are an error.
+/// The values of "device-cmyk()" are numbers(0~1) or percentages separated by spaces or commas, out of range values are clamped.
 /// ### example
 /// ```rust
 /// use easy_color::{Hex, CMYK};
@@ -15,6 +23,15 @@ use std::fmt::{Display, Formatter};
 /// cmyk.set_cyan(100);
 /// assert_eq!(cmyk.to_string(), "cmyk(100,34,53,38)");
 ///
+/// let cmyk:CMYK = "cmyk(78%, 0%, 30%, 23%)".try_into().unwrap();
+/// assert_eq!(cmyk, "cmyk(0.78, 0, 0.3, 0.23)".try_into().unwrap());
+///
+/// let cmyk:CMYK = "device-cmyk(0.78 0 0.3 0.23)".try_into().unwrap();
+/// assert_eq!(cmyk.to_string(), "cmyk(78,0,30,23)");
+/// assert_eq!(cmyk.to_percent_string(), "cmyk(78%, 0%, 30%, 23%)");
+/// assert_eq!(cmyk.to_plate_string(), "C78 M0 Y30 K23");
+/// assert_eq!(cmyk.to_fraction_string(), "device-cmyk(0.78 0 0.3 0.23)");
+///
 /// let cmyk:CMYK = (100,34,53,38).try_into().unwrap();
 /// let hex:Hex = cmyk.into();
 /// assert_eq!(hex.to_string(), "#00684A");
@@ -30,24 +47,41 @@ pub struct CMYK {
 impl TryFrom<&str> for CMYK {
     type Error = ColorError;
     fn try_from(cmyk_str: &str) -> Result<Self, Self::Error> {
-        let mut color = cmyk_str.trim().to_lowercase();
-        if color.starts_with("cmyk(") && color.ends_with(')') {
-            color = color.replace("cmyk(", "").replace(')', "");
-            let tmp = color.split(',').collect::<Vec<_>>();
-            if tmp.len() == 4 {
-                let val = tmp
-                    .iter()
-                    .filter_map(|s| s.parse::<u8>().ok())
-                    .collect::<Vec<_>>();
-                if val.len() == 4 {
-                    return (val[0], val[1], val[2], val[3]).try_into();
-                }
+        let color = cmyk_str.trim();
+        if let Some(args) = color_function_args(color, &["device-cmyk"]) {
+            let (c, m, y, k) = parse_device_cmyk(args).map_err(|e| parse_err(cmyk_str, e))?;
+            return Ok(Self { c, m, y, k });
+        }
+        if let Some(args) = color_function_args(color, &["cmyk"]) {
+            let tmp: Components = args.split(',').map(|s| s.trim()).collect();
+            let [c, m, y, k] = parse_cmyk_channels(&tmp).map_err(|e| parse_err(cmyk_str, e))?;
+            if [c, m, y, k].iter().any(|v| !(0.0..=100.0).contains(v)) {
+                return Err(ColorError::ValueErr {
+                    input: cmyk_str.to_string(),
+                    reason: format!(
+                        "CMYK: args ({},{},{},{}) value error. all value must between 0~100",
+                        c, m, y, k
+                    ),
+                });
             }
+            return Ok(Self {
+                c: c as u8,
+                m: m as u8,
+                y: y as u8,
+                k: k as u8,
+            });
         }
-        Err(ColorError::FormatErr(format!(
-            "CMYK: {} format error!",
-            cmyk_str
-        )))
+        Err(ColorError::FormatErr {
+            input: cmyk_str.to_string(),
+            reason: format!("CMYK: {} format error!", cmyk_str),
+        })
+    }
+}
+
+impl FromStr for CMYK {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
     }
 }
 
@@ -59,10 +93,13 @@ impl TryFrom<(u8, u8, u8, u8)> for CMYK {
             || !(0..=100).contains(&value.2)
             || !(0..=100).contains(&value.3)
         {
-            Err(ColorError::ValueErr(format!(
-                "CMYK: args ({},{},{},{}) value error. all value must between 0~100",
-                value.0, value.1, value.2, value.3
-            )))
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "CMYK: args ({},{},{},{}) value error. all value must between 0~100",
+                    value.0, value.1, value.2, value.3
+                ),
+            })
         } else {
             Ok(Self {
                 c: value.0,
@@ -122,6 +159,46 @@ impl From<HSV> for CMYK {
     }
 }
 
+impl From<HWB> for CMYK {
+    fn from(hwb: HWB) -> Self {
+        let rgb: RGB = hwb.into();
+        rgb.into()
+    }
+}
+
+impl From<LCH> for CMYK {
+    fn from(lch: LCH) -> Self {
+        let rgb: RGB = lch.into();
+        rgb.into()
+    }
+}
+
+impl From<OkLch> for CMYK {
+    fn from(oklch: OkLch) -> Self {
+        let rgb: RGB = oklch.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYKA> for CMYK {
+    fn from(cmyka: CMYKA) -> Self {
+        let rgba: RGBA = cmyka.into();
+        rgba.into()
+    }
+}
+
+impl From<CMYK> for (u8, u8, u8, u8) {
+    fn from(cmyk: CMYK) -> Self {
+        cmyk.as_tuple()
+    }
+}
+
+impl From<CMYK> for [u8; 4] {
+    fn from(cmyk: CMYK) -> Self {
+        cmyk.to_array()
+    }
+}
+
 impl Display for CMYK {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "cmyk({},{},{},{})", self.c, self.m, self.y, self.k)
@@ -163,6 +240,32 @@ impl CMYK {
         self
     }
 
+    /// Returns (c, m, y, k), `try_from` of the tuple returns the same color.
+    pub fn as_tuple(&self) -> (u8, u8, u8, u8) {
+        (self.c, self.m, self.y, self.k)
+    }
+
+    /// Returns [c, m, y, k]
+    pub fn to_array(&self) -> [u8; 4] {
+        [self.c, self.m, self.y, self.k]
+    }
+
+    /// Returns the values with the percent sign, like "cmyk(78%, 0%, 30%, 23%)"
+    pub fn to_percent_string(&self) -> String {
+        format!("cmyk({}%, {}%, {}%, {}%)", self.c, self.m, self.y, self.k)
+    }
+
+    /// Returns the values as the plates of a print shop, like "C78 M0 Y30 K23"
+    pub fn to_plate_string(&self) -> String {
+        format!("C{} M{} Y{} K{}", self.c, self.m, self.y, self.k)
+    }
+
+    /// Returns the CSS format with the values as numbers(0~1), like "device-cmyk(0.78 0 0.3 0.23)"
+    pub fn to_fraction_string(&self) -> String {
+        let [c, m, y, k] = self.to_array().map(|v| format_trimmed(v as f32 / 100.0, 2));
+        format!("device-cmyk({} {} {} {})", c, m, y, k)
+    }
+
     pub fn random() -> Self {
         let mut rng = rand::thread_rng();
         let c = rng.gen_range(0..=100) as u8;
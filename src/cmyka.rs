@@ -0,0 +1,190 @@
+use crate::common::{cmyk_to_rgb, format_alpha, rgb_to_cmyk};
+use crate::{ColorError, Hex, CMYK, HSL, HSLA, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+/// CMYKA can be parsed from a string in the format "cmyka(c,m,y,k,a)" or from a tuple (c,m,y,k,a).
+/// * c:u8 - cyan value(0~100)
+/// * m:u8 - magenta value(0~100)
+/// * y:u8 - yellow value(0~100)
+/// * k:u8 - black value(0~100)
+/// * a:f32 - alpha(0~1)
+///
+/// Unlike CMYK, which composites a transparent color onto white, the inks are computed from the color itself
+/// and the alpha is carried along, so converting back to RGBA returns the same alpha.
+/// ### example
+/// ```rust
+/// use easy_color::{CMYKA, RGBA};
+/// let mut cmyka:CMYKA = "cmyka(78,0,30,23,0.5)".try_into().unwrap();
+/// cmyka.set_cyan(100);
+/// assert_eq!(cmyka.to_string(), "cmyka(100,0,30,23,0.50)");
+///
+/// let rgba:RGBA = (255,0,0,0.3).try_into().unwrap();
+/// let cmyka:CMYKA = rgba.into();
+/// assert_eq!(cmyka.to_string(), "cmyka(0,100,100,0,0.30)");
+/// let rgba:RGBA = cmyka.into();
+/// assert_eq!(rgba.to_string(), "rgba(255,0,0,0.30)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct CMYKA {
+    pub(crate) cmyk: CMYK,
+    pub(crate) a: f32,
+}
+
+impl TryFrom<&str> for CMYKA {
+    type Error = ColorError;
+    fn try_from(cmyka_str: &str) -> Result<Self, Self::Error> {
+        let mut color = cmyka_str.trim().to_lowercase();
+        if color.starts_with("cmyka(") && color.ends_with(')') {
+            let mut val = vec![];
+            let mut alpha = None;
+            color = color.replace("cmyka(", "").replace(')', "");
+            let tmp = color.split(',').collect::<Vec<_>>();
+            if tmp.len() == 5 {
+                for (idx, s) in tmp.iter().enumerate() {
+                    if idx == 4 {
+                        alpha = s.trim().parse::<f32>().ok();
+                    } else if let Ok(v) = s.trim().parse::<u8>() {
+                        val.push(v);
+                    }
+                }
+            }
+            if let Some(alpha) = alpha {
+                if val.len() == 4 {
+                    return (val[0], val[1], val[2], val[3], alpha).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr {
+            input: cmyka_str.to_string(),
+            reason: format!("CMYKA: {} format error!", cmyka_str),
+        })
+    }
+}
+
+impl FromStr for CMYKA {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(u8, u8, u8, u8, f32)> for CMYKA {
+    type Error = ColorError;
+    fn try_from(value: (u8, u8, u8, u8, f32)) -> Result<Self, Self::Error> {
+        if !(0..=100).contains(&value.0)
+            || !(0..=100).contains(&value.1)
+            || !(0..=100).contains(&value.2)
+            || !(0..=100).contains(&value.3)
+            || !(0.0..=1.0).contains(&value.4)
+        {
+            Err(ColorError::ValueErr { input: format!("{:?}", value), reason: format!("CMYKA: args ({},{},{},{},{}) value error. the first four value must between 0~100, and last one must between 0~1", value.0, value.1, value.2, value.3, value.4) })
+        } else {
+            let cmyk = CMYK {
+                c: value.0,
+                m: value.1,
+                y: value.2,
+                k: value.3,
+            };
+            Ok(Self { cmyk, a: value.4 })
+        }
+    }
+}
+
+impl From<Hex> for CMYKA {
+    fn from(hex: Hex) -> Self {
+        let rgba: RGBA = hex.into();
+        rgba.into()
+    }
+}
+
+impl From<RGB> for CMYKA {
+    fn from(rgb: RGB) -> Self {
+        let cmyk: CMYK = rgb.into();
+        cmyk.into()
+    }
+}
+
+impl From<RGBA> for CMYKA {
+    fn from(rgba: RGBA) -> Self {
+        let RGBA { rgb, a } = rgba;
+        let RGB { r, g, b } = rgb;
+        let (c, m, y, k) = rgb_to_cmyk(r, g, b);
+        let cmyk = CMYK { c, m, y, k };
+        Self { cmyk, a }
+    }
+}
+
+impl From<HSL> for CMYKA {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for CMYKA {
+    fn from(hsla: HSLA) -> Self {
+        let rgba: RGBA = hsla.into();
+        rgba.into()
+    }
+}
+
+impl From<CMYK> for CMYKA {
+    fn from(cmyk: CMYK) -> Self {
+        Self { cmyk, a: 1.0 }
+    }
+}
+
+impl Deref for CMYKA {
+    type Target = CMYK;
+    fn deref(&self) -> &Self::Target {
+        &self.cmyk
+    }
+}
+
+impl DerefMut for CMYKA {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cmyk
+    }
+}
+
+impl Display for CMYKA {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let CMYK { c, m, y, k } = self.cmyk;
+        write!(
+            f,
+            "cmyka({},{},{},{},{})",
+            c,
+            m,
+            y,
+            k,
+            format_alpha(f, self.a)
+        )
+    }
+}
+
+impl CMYKA {
+    pub fn alpha(&self) -> f32 {
+        self.a
+    }
+
+    pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
+        self.a = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the red, green, blue and alpha of the color, without compositing the alpha.
+    pub(crate) fn to_rgba_tuple(self) -> (u8, u8, u8, f32) {
+        let CMYK { c, m, y, k } = self.cmyk;
+        let (r, g, b) = cmyk_to_rgb(c, m, y, k);
+        (r, g, b, self.a)
+    }
+
+    /// Generate CMYKA, value is random
+    pub fn random() -> Self {
+        let cmyk = CMYK::random();
+        let a = (rand::random::<f32>() * 100.0_f32).round() / 100.0;
+        Self { cmyk, a }
+    }
+}
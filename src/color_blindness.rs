@@ -0,0 +1,80 @@
+use crate::LinearRGB;
+
+/// The color vision deficiency for `simulate`, with the matrices of Machado, Oliveira and Fernandes(2009) in linear light.
+/// * Protanopia, Deuteranopia, Tritanopia - no L, M or S cones, the matrices of the severity 1
+/// * Protanomaly, Deuteranomaly, Tritanomaly - weak L, M or S cones with a severity(0~1),
+///   the matrix is interpolated between the identity and the one of the dichromacy
+/// * Achromatopsia - no color vision, the relative luminance is kept
+/// ### example
+/// ```rust
+/// use easy_color::{ColorBlindness, Hex, Simulate};
+/// let hex:Hex = "#FF0000".try_into().unwrap();
+/// assert_eq!(hex.simulate(ColorBlindness::Protanopia).to_string(), "#6D5F00");
+/// assert_eq!(hex.simulate(ColorBlindness::Protanomaly(0.0)), hex);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ColorBlindness {
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+    Protanomaly(f32),
+    Deuteranomaly(f32),
+    Tritanomaly(f32),
+    Achromatopsia,
+}
+
+const PROTANOPIA: [[f32; 3]; 3] = [
+    [0.152_286, 1.052_583, -0.204_868],
+    [0.114_503, 0.786_281, 0.099_216],
+    [-0.003_882, -0.048_116, 1.051_998],
+];
+
+const DEUTERANOPIA: [[f32; 3]; 3] = [
+    [0.367_322, 0.860_646, -0.227_968],
+    [0.280_085, 0.672_501, 0.047_413],
+    [-0.011_820, 0.042_940, 0.968_881],
+];
+
+const TRITANOPIA: [[f32; 3]; 3] = [
+    [1.255_528, -0.076_749, -0.178_779],
+    [-0.078_411, 0.930_809, 0.147_602],
+    [0.004_733, 0.691_367, 0.303_900],
+];
+
+/// Returns the matrix moved from the identity towards the dichromacy by the severity, NaN is 0
+fn with_severity(matrix: &[[f32; 3]; 3], severity: f32) -> [[f32; 3]; 3] {
+    let severity = if severity.is_nan() {
+        0.0
+    } else {
+        severity.clamp(0.0, 1.0)
+    };
+    std::array::from_fn(|i| {
+        std::array::from_fn(|j| {
+            let identity = if i == j { 1.0 } else { 0.0 };
+            identity + (matrix[i][j] - identity) * severity
+        })
+    })
+}
+
+impl ColorBlindness {
+    /// Returns the matrix of the deficiency for linear light sRGB
+    pub(crate) fn matrix(&self) -> [[f32; 3]; 3] {
+        match *self {
+            ColorBlindness::Protanopia => PROTANOPIA,
+            ColorBlindness::Deuteranopia => DEUTERANOPIA,
+            ColorBlindness::Tritanopia => TRITANOPIA,
+            ColorBlindness::Protanomaly(severity) => with_severity(&PROTANOPIA, severity),
+            ColorBlindness::Deuteranomaly(severity) => with_severity(&DEUTERANOPIA, severity),
+            ColorBlindness::Tritanomaly(severity) => with_severity(&TRITANOPIA, severity),
+            ColorBlindness::Achromatopsia => [[0.2126, 0.7152, 0.0722]; 3],
+        }
+    }
+
+    /// Returns the color as it is seen with the deficiency, the channels are clamped between 0~1
+    pub(crate) fn apply(&self, linear: LinearRGB) -> LinearRGB {
+        let m = self.matrix();
+        let LinearRGB { r, g, b } = linear;
+        let [r, g, b] = m.map(|row| (row[0] * r + row[1] * g + row[2] * b).clamp(0.0, 1.0));
+        LinearRGB { r, g, b }
+    }
+}
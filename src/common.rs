@@ -1,7 +1,65 @@
+use crate::ParseOptions;
+
 #[derive(Debug, Clone)]
 pub enum ColorError {
-    FormatErr(String),
-    ValueErr(String),
+    /// The string is not in a format that can be parsed.
+    /// * input - the string or value that was parsed
+    /// * reason - the error message
+    FormatErr { input: String, reason: String },
+    /// The values are out of the range of the color.
+    /// * input - the string or value that was parsed
+    /// * reason - the error message
+    ValueErr { input: String, reason: String },
+    /// The string is in a known format, but its content is wrong.
+    /// * input - the string that was parsed
+    /// * component - the index of the wrong component, starting at 0, None when the error is not about a single component
+    /// * reason - what is wrong, like "expected 3 components, found 2" or "invalid number 'abc'"
+    ParseErr {
+        input: String,
+        component: Option<usize>,
+        reason: String,
+    },
+}
+
+impl std::fmt::Display for ColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorError::FormatErr { reason, .. } | ColorError::ValueErr { reason, .. } => {
+                write!(f, "{}", reason)
+            }
+            ColorError::ParseErr {
+                input,
+                component: Some(idx),
+                reason,
+            } => write!(f, "'{}' component {} error: {}", input, idx, reason),
+            ColorError::ParseErr {
+                input,
+                component: None,
+                reason,
+            } => write!(f, "'{}' format error: {}", input, reason),
+        }
+    }
+}
+
+impl std::error::Error for ColorError {}
+
+impl ColorError {
+    /// Returns the string that failed to parse, or the debug form of the value that was out of range.
+    /// The error owns the input, so it can be kept for reporting after the string is dropped.
+    /// ### example
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let input = String::from("rgb(43,196)");
+    /// let err = RGB::try_from(input).unwrap_err();
+    /// assert_eq!(err.input(), Some("rgb(43,196)"));
+    /// ```
+    pub fn input(&self) -> Option<&str> {
+        match self {
+            ColorError::FormatErr { input, .. }
+            | ColorError::ValueErr { input, .. }
+            | ColorError::ParseErr { input, .. } => Some(input),
+        }
+    }
 }
 
 pub fn calc_rgb_with_alpha(v: u8, alpha: f32) -> f32 {
@@ -38,14 +96,17 @@ pub fn calc_rgb_to_hsl(r: f32, g: f32, b: f32) -> (u32, u32, u32) {
     } else {
         delta / (1.0 - (2.0 * l - 1.0).abs())
     };
+    // a hue just below 360 rounds up to it, which is 0
     (
-        h.round() as u32,
+        h.round() as u32 % 360,
         (s * 100.0).round() as u32,
         (l * 100.0).round() as u32,
     )
 }
 
+/// The hue is wrapped, so 360 is the same as 0 instead of falling out of every sector
 pub fn hsl_to_rgb(h: u32, s: u32, l: u32) -> (u8, u8, u8) {
+    let h = h % 360;
     let s = s as f32 / 100.0;
     let l = l as f32 / 100.0;
     let c = (1.0 - (l * 2.0 - 1.0).abs()) * s;
@@ -67,10 +128,10 @@ pub fn hsl_to_rgb(h: u32, s: u32, l: u32) -> (u8, u8, u8) {
 }
 
 pub fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (u32, u32, u32) {
-    let r = r as f32 / 255.0;
-    let g = g as f32 / 255.0;
-    let b = b as f32 / 255.0;
+    calc_rgb_to_hsv(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0)
+}
 
+pub fn calc_rgb_to_hsv(r: f32, g: f32, b: f32) -> (u32, u32, u32) {
     let c_max = r.max(g).max(b);
     let c_min = r.min(g).min(b);
     let delta = c_max - c_min;
@@ -93,13 +154,15 @@ pub fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (u32, u32, u32) {
 
     let v = c_max;
     (
-        h.round() as u32,
+        h.round() as u32 % 360,
         (s * 100.0).round() as u32,
         (v * 100.0).round() as u32,
     )
 }
 
+/// The hue is wrapped like `hsl_to_rgb`
 pub fn hsv_to_rgb(h: u32, s: u32, v: u32) -> (u8, u8, u8) {
+    let h = h % 360;
     let s = s as f32 / 100.0;
     let v = v as f32 / 100.0;
     let c = v * s;
@@ -155,13 +218,1177 @@ pub fn cmyk_to_rgb(c: u8, m: u8, y: u8, k: u8) -> (u8, u8, u8) {
     (r, g, b)
 }
 
-pub fn process_hex(hex_str: &str, chunk_size: usize) -> Vec<u8> {
-    hex_str
-        .chars()
-        .collect::<Vec<_>>()
-        .chunks(chunk_size)
-        .map(|c| c.iter().collect::<String>())
-        .map(|v| u8::from_str_radix(&v.repeat(2)[0..2], 16))
-        .filter_map(|v| v.ok())
-        .collect::<Vec<_>>()
+/// Convert the i32 channels into u8, the error names the first channel out of 0~255
+pub fn checked_channels(name: &str, value: (i32, i32, i32)) -> Result<(u8, u8, u8), ColorError> {
+    let channels = [("red", value.0), ("green", value.1), ("blue", value.2)];
+    let mut val = [0; 3];
+    for (idx, (channel, v)) in channels.iter().enumerate() {
+        val[idx] = u8::try_from(*v).map_err(|_| ColorError::ValueErr {
+            input: format!("{:?}", value),
+            reason: format!(
+                "{}: args ({},{},{}) value error, {} is {} but must between 0~255!",
+                name, value.0, value.1, value.2, channel, v
+            ),
+        })?;
+    }
+    Ok((val[0], val[1], val[2]))
+}
+
+/// Parse the 3, 4, 6 or 8 hex digits of a Hex color into [r, g, b, a], the alpha is 255 when omitted
+/// and each digit of the shorthand forms is doubled
+pub fn process_hex(hex_str: &str) -> Option<[u8; 4]> {
+    let digits = hex_str.as_bytes();
+    let digit = |idx: usize| (digits[idx] as char).to_digit(16).map(|v| v as u8);
+    if !digits.iter().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let mut val = [255; 4];
+    match digits.len() {
+        len @ (3 | 4) => {
+            for (idx, v) in val.iter_mut().take(len).enumerate() {
+                *v = digit(idx)? * 17;
+            }
+        }
+        len @ (6 | 8) => {
+            for (idx, v) in val.iter_mut().take(len / 2).enumerate() {
+                *v = digit(idx * 2)? * 16 + digit(idx * 2 + 1)?;
+            }
+        }
+        _ => return None,
+    }
+    Some(val)
+}
+
+/// Parse the 12 or 16 hex digits of a deep color Hex into 16 bit [r, g, b, a], the alpha is 65535 when omitted.
+/// Every channel has 4 digits, so the 8 digits of a RGBA Hex are never read as the start of a longer string.
+pub fn process_hex16(hex_str: &str) -> Option<[u16; 4]> {
+    if !matches!(hex_str.len(), 12 | 16) || !hex_str.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let mut val = [u16::MAX; 4];
+    for (idx, v) in val.iter_mut().take(hex_str.len() / 4).enumerate() {
+        *v = u16::from_str_radix(&hex_str[idx * 4..idx * 4 + 4], 16).ok()?;
+    }
+    Some(val)
+}
+
+pub fn rgb_to_hwb(r: u8, g: u8, b: u8) -> (u32, u32, u32) {
+    let (h, _, _) = rgb_to_hsv(r, g, b);
+    let w = r.min(g).min(b) as f32 / 255.0;
+    let bl = 1.0 - r.max(g).max(b) as f32 / 255.0;
+    (h, (w * 100.0).round() as u32, (bl * 100.0).round() as u32)
+}
+
+pub fn hwb_to_rgb(h: u32, w: u32, b: u32) -> (u8, u8, u8) {
+    let w = w as f32 / 100.0;
+    let b = b as f32 / 100.0;
+    // like browsers do, whiteness and blackness are normalized to a gray when they add up to 100% or more
+    if w + b >= 1.0 {
+        let gray = (w / (w + b) * 255.0).round() as u8;
+        return (gray, gray, gray);
+    }
+    let (r, g, bl) = hue_to_rgb(h);
+    let calc = |v: f32| ((v * (1.0 - w - b) + w) * 255.0).round() as u8;
+    (calc(r), calc(g), calc(bl))
+}
+
+/// Returns the fully saturated color of the hue, every channel is between 0~1.
+pub fn hue_to_rgb(h: u32) -> (f32, f32, f32) {
+    let h = (h % 360) as f32 / 60.0;
+    let x = 1.0 - (h % 2.0 - 1.0).abs();
+    match h as u32 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    }
+}
+
+/// sRGB to CIE XYZ(D65) matrix, taken from the CSS Color 4 spec.
+pub const SRGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.412_390_8, 0.357_584_33, 0.180_480_8],
+    [0.212_639, 0.715_168_65, 0.072_192_32],
+    [0.019_330_818, 0.119_194_78, 0.950_532_14],
+];
+
+/// CIE XYZ(D65) to sRGB matrix, taken from the CSS Color 4 spec.
+pub const XYZ_TO_SRGB: [[f32; 3]; 3] = [
+    [3.240_97, -1.537_383_2, -0.498_610_76],
+    [-0.969_243_6, 1.875_967_5, 0.041_555_06],
+    [0.055_630_08, -0.203_976_96, 1.056_971_5],
+];
+
+/// linear Display P3 to CIE XYZ(D65) matrix, taken from the CSS Color 4 spec.
+pub const P3_TO_XYZ: [[f32; 3]; 3] = [
+    [0.486_570_95, 0.265_667_7, 0.198_217_29],
+    [0.228_974_56, 0.691_738_5, 0.079_286_91],
+    [0.0, 0.045_113_38, 1.043_944_4],
+];
+
+/// CIE XYZ(D65) to linear Display P3 matrix, taken from the CSS Color 4 spec.
+pub const XYZ_TO_P3: [[f32; 3]; 3] = [
+    [2.493_497, -0.931_383_6, -0.402_710_78],
+    [-0.829_489, 1.762_664_1, 0.023_624_686],
+    [0.035_845_83, -0.076_172_39, 0.956_884_5],
+];
+
+/// linear Rec.2020 to CIE XYZ(D65) matrix, taken from the CSS Color 4 spec.
+pub const REC2020_TO_XYZ: [[f32; 3]; 3] = [
+    [0.636_958, 0.144_616_9, 0.168_880_98],
+    [0.262_700_2, 0.677_998_1, 0.059_301_716],
+    [0.0, 0.028_072_693, 1.060_985_1],
+];
+
+/// CIE XYZ(D65) to linear Rec.2020 matrix, taken from the CSS Color 4 spec.
+pub const XYZ_TO_REC2020: [[f32; 3]; 3] = [
+    [1.716_651_2, -0.355_670_8, -0.253_366_3],
+    [-0.666_684_3, 1.616_481_2, 0.015_768_545],
+    [0.017_639_857, -0.042_770_613, 0.942_103_1],
+];
+
+/// linear Adobe RGB (1998) to CIE XYZ(D65) matrix, taken from the CSS Color 4 spec.
+pub const ADOBE_RGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.576_669, 0.185_558_24, 0.188_228_65],
+    [0.297_344_98, 0.627_363_6, 0.075_291_46],
+    [0.027_031_36, 0.070_688_85, 0.991_337_5],
+];
+
+/// CIE XYZ(D65) to linear Adobe RGB (1998) matrix, taken from the CSS Color 4 spec.
+pub const XYZ_TO_ADOBE_RGB: [[f32; 3]; 3] = [
+    [2.041_588, -0.565_007, -0.344_731_35],
+    [-0.969_243_6, 1.875_967_5, 0.041_555_06],
+    [0.013_444_28, -0.118_362_39, 1.015_175],
+];
+
+/// the gamma of Adobe RGB (1998), the "2.2" of the spec is exactly 563/256
+pub const ADOBE_RGB_GAMMA: f32 = 563.0 / 256.0;
+
+/// linear ProPhoto RGB to CIE XYZ(D50) matrix, taken from the CSS Color 4 spec.
+pub const PROPHOTO_RGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.797_766_6, 0.135_181_3, 0.031_347_734],
+    [0.288_074_83, 0.711_835_24, 0.000_089_936_94],
+    [0.0, 0.0, 0.825_104_6],
+];
+
+/// CIE XYZ(D50) to linear ProPhoto RGB matrix, taken from the CSS Color 4 spec.
+pub const XYZ_TO_PROPHOTO_RGB: [[f32; 3]; 3] = [
+    [1.345_786_9, -0.255_572_1, -0.051_101_865],
+    [-0.544_630_7, 1.508_247_8, 0.020_527_447],
+    [0.0, 0.0, 1.211_967_5],
+];
+
+/// Bradford chromatic adaptation from D65 to D50.
+pub const D65_TO_D50: [[f32; 3]; 3] = [
+    [1.047_929_8, 0.022_946_794, -0.050_192_23],
+    [0.029_627_815, 0.990_434_5, -0.017_073_825],
+    [-0.009_243_058, 0.015_055_145, 0.751_874_3],
+];
+
+/// Bradford chromatic adaptation from D50 to D65.
+pub const D50_TO_D65: [[f32; 3]; 3] = [
+    [0.955_473_4, -0.023_098_537, 0.063_259_31],
+    [-0.028_369_706, 1.009_995_4, 0.021_041_399],
+    [0.012_314_002, -0.020_507_697, 1.330_366],
+];
+
+/// D65 reference white, derived from the chromaticity (0.3127, 0.3290) like CSS does.
+pub const D65_WHITE: [f32; 3] = [0.950_455_9, 1.0, 1.089_057_8];
+
+/// D50 reference white, the white point of CIE Lab / LCh as used by CSS.
+pub const D50_WHITE: [f32; 3] = [0.964_295_7, 1.0, 0.825_104_6];
+
+/// below this Lab chroma a color is treated as achromatic and gets a hue of 0
+pub const LAB_ACHROMATIC_CHROMA: f32 = 0.02;
+
+/// below this Oklab chroma a color is treated as achromatic and gets a hue of 0
+pub const OKLAB_ACHROMATIC_CHROMA: f32 = 0.000_2;
+
+pub fn multiply_matrix(m: &[[f32; 3]; 3], v: (f32, f32, f32)) -> (f32, f32, f32) {
+    (
+        m[0][0] * v.0 + m[0][1] * v.1 + m[0][2] * v.2,
+        m[1][0] * v.0 + m[1][1] * v.1 + m[1][2] * v.2,
+        m[2][0] * v.0 + m[2][1] * v.1 + m[2][2] * v.2,
+    )
+}
+
+/// gamma decode a sRGB channel(0~1) into linear light
+pub fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// gamma encode a linear light channel(0~1) into sRGB
+pub fn linear_to_srgb(v: f32) -> f32 {
+    if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// decode a Rec.2020 channel(0~1) into linear light
+pub fn rec2020_to_linear(v: f32) -> f32 {
+    let alpha = 1.099_296_8;
+    let beta = 0.018_053_968;
+    if v < beta * 4.5 {
+        v / 4.5
+    } else {
+        ((v + alpha - 1.0) / alpha).powf(1.0 / 0.45)
+    }
+}
+
+/// encode a linear light channel(0~1) into Rec.2020
+pub fn linear_to_rec2020(v: f32) -> f32 {
+    let alpha = 1.099_296_8;
+    let beta = 0.018_053_968;
+    if v > beta {
+        alpha * v.powf(0.45) - (alpha - 1.0)
+    } else {
+        v * 4.5
+    }
+}
+
+/// decode a ProPhoto RGB channel(0~1) into linear light with the ROMM curve
+pub fn prophoto_to_linear(v: f32) -> f32 {
+    if v <= 16.0 / 512.0 {
+        v / 16.0
+    } else {
+        v.powf(1.8)
+    }
+}
+
+/// encode a linear light channel(0~1) into ProPhoto RGB with the ROMM curve
+pub fn linear_to_prophoto(v: f32) -> f32 {
+    if v >= 1.0 / 512.0 {
+        v.powf(1.0 / 1.8)
+    } else {
+        v * 16.0
+    }
+}
+
+/// convert a 0~1 channel into u8, values out of range are clamped
+pub fn unit_to_u8(v: f32) -> u8 {
+    (v.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Returns CIE XYZ(D65) of the rgb color, Y of white is 1.0
+pub fn rgb_to_xyz(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    multiply_matrix(&SRGB_TO_XYZ, rgb_to_linear_srgb(r, g, b))
+}
+
+/// Convert CIE XYZ(D65) into rgb, colors out of the sRGB gamut are clamped
+pub fn xyz_to_rgb(x: f32, y: f32, z: f32) -> (u8, u8, u8) {
+    let (r, g, b) = multiply_matrix(&XYZ_TO_SRGB, (x, y, z));
+    linear_srgb_to_rgb(r, g, b)
+}
+
+/// Returns the linear light channels(0~1) of the rgb color
+pub fn rgb_to_linear_srgb(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    (
+        srgb_to_linear(r as f32 / 255.0),
+        srgb_to_linear(g as f32 / 255.0),
+        srgb_to_linear(b as f32 / 255.0),
+    )
+}
+
+/// Convert linear light sRGB channels into rgb, channels out of 0~1 are clamped
+pub fn linear_srgb_to_rgb(r: f32, g: f32, b: f32) -> (u8, u8, u8) {
+    (
+        unit_to_u8(linear_to_srgb(r.max(0.0))),
+        unit_to_u8(linear_to_srgb(g.max(0.0))),
+        unit_to_u8(linear_to_srgb(b.max(0.0))),
+    )
+}
+
+/// Check whether all the channels are between 0~1, allowing a small error from the matrices
+pub fn is_in_unit_range(v: (f32, f32, f32)) -> bool {
+    let epsilon = 1e-4;
+    [v.0, v.1, v.2]
+        .iter()
+        .all(|c| (-epsilon..=1.0 + epsilon).contains(c))
+}
+
+/// Strip the ASCII prefix ignoring the case, like `strip_prefix` of a lowercase string
+pub fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &s[prefix.len()..])
+}
+
+/// Strip the ASCII suffix ignoring the case, like `strip_suffix` of a lowercase string
+pub fn strip_suffix_ignore_case<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    let idx = s.len().checked_sub(suffix.len())?;
+    let tail = s.get(idx..)?;
+    tail.eq_ignore_ascii_case(suffix).then(|| &s[..idx])
+}
+
+/// Returns the arguments of a CSS color function if the color is one of the given functions,
+/// like "43 196 138" for "rgb(43 196 138)", the function name is case insensitive
+pub fn color_function_args<'a>(color: &'a str, names: &[&str]) -> Option<&'a str> {
+    names.iter().find_map(|name| {
+        strip_prefix_ignore_case(color, name)?
+            .strip_prefix('(')?
+            .strip_suffix(')')
+    })
+}
+
+/// The error of one component of a color string, the index of the component(starting at 0) and the reason
+pub type ComponentErr = (Option<usize>, String);
+
+/// Build the `ColorError::ParseErr` of the input from a `ComponentErr`
+pub fn parse_err(input: &str, err: ComponentErr) -> ColorError {
+    let (component, reason) = err;
+    ColorError::ParseErr {
+        input: input.to_string(),
+        component,
+        reason,
+    }
+}
+
+/// The components of a color function, split without allocating.
+/// Only the first 4 are kept, `len` is the number of components found, so a wrong count can still be reported.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Components<'a> {
+    items: [&'a str; 4],
+    len: usize,
+}
+
+impl<'a> Components<'a> {
+    fn push(&mut self, s: &'a str) {
+        if let Some(item) = self.items.get_mut(self.len) {
+            *item = s;
+        }
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<&'a str> {
+        self.len = self.len.checked_sub(1)?;
+        self.items.get(self.len).copied()
+    }
+
+    /// The number of components found
+    pub fn count(&self) -> usize {
+        self.len
+    }
+}
+
+impl<'a> std::ops::Deref for Components<'a> {
+    type Target = [&'a str];
+    fn deref(&self) -> &Self::Target {
+        &self.items[..self.len.min(4)]
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Components<'a> {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        let mut components = Self::default();
+        iter.into_iter().for_each(|s| components.push(s));
+        components
+    }
+}
+
+/// Split the arguments of a CSS color function into the channels and the optional alpha.
+/// Both the legacy "43, 196, 138, 0.5" and the modern "43 196 138 / 0.5" syntax are accepted,
+/// mixing commas and the slash is rejected like browsers do.
+pub fn split_color_args(args: &str) -> Result<(Components<'_>, Option<&str>), ComponentErr> {
+    if args.contains(',') {
+        if args.contains('/') {
+            return Err((None, "commas and '/' can not be mixed".to_string()));
+        }
+        let mut channels = Components::default();
+        for (idx, s) in args.split(',').map(|s| s.trim()).enumerate() {
+            if s.is_empty() {
+                return Err((Some(idx), "empty component".to_string()));
+            }
+            channels.push(s);
+        }
+        let alpha = if channels.count() == 4 {
+            channels.pop()
+        } else {
+            None
+        };
+        return Ok((channels, alpha));
+    }
+    let (channels, alpha) = match args.split_once('/') {
+        Some((channels, alpha)) => {
+            let alpha = alpha.trim();
+            if alpha.is_empty() {
+                return Err((Some(3), "missing alpha after '/'".to_string()));
+            }
+            if alpha.contains(|c: char| c == '/' || c.is_whitespace()) {
+                return Err((
+                    Some(3),
+                    format!("expected a single alpha, found '{}'", alpha),
+                ));
+            }
+            (channels, Some(alpha))
+        }
+        None => (args, None),
+    };
+    Ok((channels.split_whitespace().collect(), alpha))
+}
+
+/// Returns an error if there are not 3 channels
+fn expect_three(channels: &Components) -> Result<(), ComponentErr> {
+    if channels.count() != 3 {
+        return Err((
+            None,
+            format!("expected 3 components, found {}", channels.count()),
+        ));
+    }
+    Ok(())
+}
+
+/// Parse the red, green and blue of a CSS rgb() function, the channels are either all numbers(0~255)
+/// or all percentages, mixing them is rejected like browsers do.
+/// The values are rounded to the nearest integer, percentages are scaled to 0~255 and clamped,
+/// numbers are clamped only with `clamp_out_of_range`.
+pub fn parse_rgb_channels(
+    channels: &Components,
+    options: &ParseOptions,
+) -> Result<(u8, u8, u8), ComponentErr> {
+    expect_three(channels)?;
+    let percent = channels.iter().filter(|s| s.ends_with('%')).count();
+    if percent != 0 && percent != 3 {
+        return Err((None, "percentages and numbers can not be mixed".to_string()));
+    }
+    let mut val = [0; 3];
+    for (idx, s) in channels.iter().enumerate() {
+        let v = parse_number(s.trim_end_matches('%'))
+            .filter(|v| v.is_finite())
+            .ok_or_else(|| (Some(idx), format!("invalid number '{}'", s)))?;
+        let v = if percent == 3 {
+            (v * 2.55).round().clamp(0.0, 255.0)
+        } else if options.clamp_out_of_range {
+            v.round().clamp(0.0, 255.0)
+        } else {
+            v.round()
+        };
+        if !(0.0..=255.0).contains(&v) {
+            return Err((Some(idx), format!("value {} out of range 0..=255", s)));
+        }
+        val[idx] = v as u8;
+    }
+    Ok((val[0], val[1], val[2]))
+}
+
+/// Parse a number like `str::parse::<f32>`, integers take a faster path with the same result
+pub fn parse_number(s: &str) -> Option<f32> {
+    match s.parse::<u16>() {
+        Ok(v) => Some(v as f32),
+        Err(_) => s.parse::<f32>().ok(),
+    }
+}
+
+/// Parse a CSS alpha, a number or a percentage, percentages over 100% are clamped to 1 like browsers do
+pub fn parse_alpha(alpha: &str) -> Option<f32> {
+    match alpha.strip_suffix('%') {
+        Some(v) => parse_number(v).map(|v| (v / 100.0).min(1.0)),
+        None => parse_number(alpha),
+    }
+}
+
+/// Parse the optional alpha component at the index, a missing alpha is 1
+pub fn parse_alpha_component(
+    alpha: Option<&str>,
+    idx: usize,
+    options: &ParseOptions,
+) -> Result<f32, ComponentErr> {
+    let Some(s) = alpha else {
+        return Ok(1.0);
+    };
+    let v = parse_alpha(s)
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| (Some(idx), format!("invalid number '{}'", s)))?;
+    if options.clamp_out_of_range {
+        return Ok(v.clamp(0.0, 1.0));
+    }
+    if !(0.0..=1.0).contains(&v) {
+        return Err((Some(idx), format!("value {} out of range 0..=1", s)));
+    }
+    Ok(v)
+}
+
+/// Format a number with at most `precision` decimals and without the trailing zeros, so 1.0 is "1" and 0.50 is "0.5"
+pub fn format_trimmed(v: f32, precision: usize) -> String {
+    let s = format!("{:.*}", precision, v);
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        s
+    }
+}
+
+/// Format the alpha of a `Display` impl, with 2 decimals unless the formatter has a precision like `{:.3}`,
+/// the alternate flag `{:#}` drops the trailing zeros, so 1.0 is "1"
+pub fn format_alpha(f: &std::fmt::Formatter<'_>, a: f32) -> String {
+    let precision = f.precision().unwrap_or(2);
+    if f.alternate() {
+        format_trimmed(a, precision)
+    } else {
+        format!("{:.*}", precision, a)
+    }
+}
+
+/// Wrap a hue in degrees into 0~360, 360 itself becomes 0
+pub fn wrap_hue(hue: i32) -> u32 {
+    hue.rem_euclid(360) as u32
+}
+
+/// Returns the degrees(0~180) between the hues the short way around, 350 and 10 are 20 apart
+pub fn hue_distance(h1: u32, h2: u32) -> u32 {
+    let d = h1.abs_diff(h2) % 360;
+    d.min(360 - d)
+}
+
+/// Parse a CSS hue into degrees, the `deg`, `rad`, `grad` and `turn` units are accepted and a bare number is in degrees.
+/// The hue is an angle, so it is wrapped into 0~360, fractional degrees are rounded.
+pub fn parse_hue(hue: &str) -> Option<u32> {
+    let units = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / std::f32::consts::PI),
+        ("turn", 360.0),
+    ];
+    let (v, scale) = units
+        .iter()
+        .find_map(|(unit, scale)| strip_suffix_ignore_case(hue, unit).map(|v| (v, *scale)))
+        .unwrap_or((hue, 1.0));
+    let degree = (parse_number(v).filter(|v| v.is_finite())? * scale)
+        .rem_euclid(360.0)
+        .round();
+    Some(degree as u32 % 360)
+}
+
+/// Parse the channels of a CSS hsl() like function, a hue followed by two percentages(0~100).
+/// * the hue is an angle, so it never takes the `%` sign
+/// * the saturation, lightness and value take an optional `%` sign, it can be omitted only with `allow_percent_omission`
+pub fn parse_hue_channels(
+    channels: &Components,
+    options: &ParseOptions,
+) -> Result<(u32, u32, u32), ComponentErr> {
+    expect_three(channels)?;
+    if channels[0].ends_with('%') {
+        return Err((
+            Some(0),
+            format!(
+                "a hue is an angle and can not be a percentage, found '{}'",
+                channels[0]
+            ),
+        ));
+    }
+    let h = parse_hue(channels[0])
+        .ok_or_else(|| (Some(0), format!("invalid hue '{}'", channels[0])))?;
+    let mut val = [0; 2];
+    for idx in 1..3 {
+        let s = channels[idx];
+        let number = match s.strip_suffix('%') {
+            Some(number) => number,
+            None if options.allow_percent_omission => s,
+            None => return Err((Some(idx), format!("expected a percentage, found '{}'", s))),
+        };
+        let v = parse_number(number)
+            .filter(|v| v.is_finite())
+            .ok_or_else(|| (Some(idx), format!("invalid number '{}'", s)))?
+            .round();
+        let v = if options.clamp_out_of_range {
+            v.clamp(0.0, 100.0)
+        } else if (0.0..=100.0).contains(&v) {
+            v
+        } else {
+            return Err((Some(idx), format!("value {} out of range 0..=100", s)));
+        };
+        val[idx - 1] = v as u32;
+    }
+    Ok((h, val[0], val[1]))
+}
+
+/// Parse the arguments of a CSS `device-cmyk()` function, like "0.78 0 0.3 0.23" or "78%, 0%, 30%, 23%",
+/// the values are numbers(0~1) or percentages, values out of range are clamped like the CSS spec says.
+/// Returns the cyan, magenta, yellow and black as percentages(0~100).
+pub fn parse_device_cmyk(args: &str) -> Result<(u8, u8, u8, u8), ComponentErr> {
+    if args.contains('/') {
+        return Err((Some(4), "CMYK has no alpha".to_string()));
+    }
+    let channels: Components = if args.contains(',') {
+        args.split(',').map(|s| s.trim()).collect()
+    } else {
+        args.split_whitespace().collect()
+    };
+    if channels.count() != 4 {
+        return Err((
+            None,
+            format!("expected 4 components, found {}", channels.count()),
+        ));
+    }
+    let mut val = [0; 4];
+    for (idx, s) in channels.iter().enumerate() {
+        let v = match s.strip_suffix('%') {
+            Some(number) => parse_number(number).map(|v| v / 100.0),
+            None => parse_number(s),
+        }
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| (Some(idx), format!("invalid number '{}'", s)))?;
+        val[idx] = (v.clamp(0.0, 1.0) * 100.0).round() as u8;
+    }
+    Ok((val[0], val[1], val[2], val[3]))
+}
+
+/// Parse the components of a "cmyk()" string, which are all integers(0~100), all percentages like "78%"
+/// or all numbers(0~1) like "0.78" when any component has a decimal point, mixing the forms is an error.
+/// Returns the cyan, magenta, yellow and black as percentages, the range is checked by the caller.
+pub fn parse_cmyk_channels(channels: &Components) -> Result<[f32; 4], ComponentErr> {
+    if channels.count() != 4 {
+        return Err((
+            None,
+            format!("expected 4 components, found {}", channels.count()),
+        ));
+    }
+    let percent = channels.iter().any(|s| s.ends_with('%'));
+    let unit = !percent && channels.iter().any(|s| s.contains('.'));
+    let mut val = [0.0; 4];
+    for (idx, s) in channels.iter().enumerate() {
+        let v = match (s.strip_suffix('%'), percent) {
+            (Some(number), true) => parse_number(number),
+            (None, false) if unit => parse_number(s).map(|v| v * 100.0),
+            (None, false) => s.parse::<u8>().ok().map(f32::from),
+            _ => {
+                return Err((
+                    Some(idx),
+                    format!(
+                        "'{}' mixes percentages and numbers, use one form for all components",
+                        s
+                    ),
+                ))
+            }
+        }
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| (Some(idx), format!("invalid number '{}'", s)))?;
+        if unit && !(0.0..=100.0).contains(&v) {
+            return Err((
+                Some(idx),
+                format!(
+                    "value {} out of range 0..=1, as another component has a decimal point",
+                    s
+                ),
+            ));
+        }
+        val[idx] = v.round();
+    }
+    Ok(val)
+}
+
+/// Parse the argument of a CSS `gray()` function, a level(0~255) or a percentage,
+/// values out of range are clamped like the CSS spec says.
+pub fn parse_gray_level(arg: &str) -> Result<u8, ComponentErr> {
+    let arg = arg.trim();
+    if arg.contains(|c: char| c == ',' || c == '/' || c.is_whitespace()) {
+        return Err((None, format!("expected 1 component, found '{}'", arg)));
+    }
+    let v = match arg.strip_suffix('%') {
+        Some(number) => parse_number(number).map(|v| v * 2.55),
+        None => parse_number(arg),
+    }
+    .filter(|v| v.is_finite())
+    .ok_or_else(|| (Some(0), format!("invalid number '{}'", arg)))?;
+    Ok(v.round().clamp(0.0, 255.0) as u8)
+}
+
+/// The color spaces of the CSS `color()` function that RGB and RGBA can be parsed from
+pub const COLOR_SPACES: [&str; 6] = [
+    "srgb",
+    "srgb-linear",
+    "display-p3",
+    "rec2020",
+    "a98-rgb",
+    "prophoto-rgb",
+];
+
+/// The color space, channels and the optional alpha of a CSS `color()` function
+pub type ColorFunctionArgs<'a> = (&'a str, (f32, f32, f32), Option<&'a str>);
+
+/// Parse the arguments of a CSS `color()` function, like "display-p3 0.17 0.77 0.54 / 0.5",
+/// into the color space, the channels(0~1, a percentage is divided by 100) and the optional alpha.
+/// An unknown color space is reported with the list of `COLOR_SPACES`.
+pub fn parse_color_function_args<'a>(
+    args: &'a str,
+    options: &ParseOptions,
+) -> Result<ColorFunctionArgs<'a>, ComponentErr> {
+    let args = args.trim_start();
+    let (space, args) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+    let Some(space) = COLOR_SPACES.iter().find(|s| s.eq_ignore_ascii_case(space)) else {
+        return Err((
+            None,
+            format!(
+                "unknown color space '{}', the supported color spaces are {}",
+                space,
+                COLOR_SPACES.join(", ")
+            ),
+        ));
+    };
+    if args.contains(',') {
+        return Err((
+            None,
+            "the channels of color() can not be separated by commas".to_string(),
+        ));
+    }
+    let (channels, alpha) = split_color_args(args)?;
+    expect_three(&channels)?;
+    let mut val = [0.0; 3];
+    for (idx, s) in channels.iter().enumerate() {
+        let v = match s.strip_suffix('%') {
+            Some(number) => parse_number(number).map(|v| v / 100.0),
+            None => parse_number(s),
+        }
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| (Some(idx), format!("invalid number '{}'", s)))?;
+        val[idx] = if options.clamp_out_of_range {
+            v.clamp(0.0, 1.0)
+        } else if (0.0..=1.0).contains(&v) {
+            v
+        } else {
+            return Err((Some(idx), format!("value {} out of range 0..=1", s)));
+        };
+    }
+    Ok((*space, (val[0], val[1], val[2]), alpha))
+}
+
+/// Parse a CSS `color()` function in the given color space, like "color(display-p3 1 0.5 0)", for the types without alpha.
+/// * name - the name of the type in the error, like "P3"
+///
+/// Another color space or an alpha is a `ParseErr`, the channels can be numbers or percentages.
+pub fn parse_color_space(
+    color_str: &str,
+    space: &str,
+    name: &str,
+) -> Result<(f32, f32, f32), ColorError> {
+    let Some(args) = color_function_args(color_str.trim(), &["color"]) else {
+        return Err(ColorError::FormatErr {
+            input: color_str.to_string(),
+            reason: format!("{}: {} format error!", name, color_str),
+        });
+    };
+    let (found, channels, alpha) = parse_color_function_args(args, &ParseOptions::default())
+        .map_err(|e| parse_err(color_str, e))?;
+    if found != space {
+        return Err(parse_err(
+            color_str,
+            (
+                None,
+                format!(
+                    "{} is in the color space '{}', found '{}'",
+                    name, space, found
+                ),
+            ),
+        ));
+    }
+    if alpha.is_some() {
+        return Err(parse_err(
+            color_str,
+            (
+                Some(3),
+                format!("{} has no alpha, parse it into RGBA instead", name),
+            ),
+        ));
+    }
+    Ok(channels)
+}
+
+/// Returns CIE Lab(D50) of the rgb color
+pub fn rgb_to_lab(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let xyz = multiply_matrix(&D65_TO_D50, rgb_to_xyz(r, g, b));
+    let epsilon = 216.0 / 24389.0;
+    let kappa = 24389.0 / 27.0;
+    let f = |v: f32| {
+        if v > epsilon {
+            v.cbrt()
+        } else {
+            (kappa * v + 16.0) / 116.0
+        }
+    };
+    let fx = f(xyz.0 / D50_WHITE[0]);
+    let fy = f(xyz.1 / D50_WHITE[1]);
+    let fz = f(xyz.2 / D50_WHITE[2]);
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+/// Convert CIE Lab(D50) into rgb, colors out of the sRGB gamut are clamped
+pub fn lab_to_rgb(l: f32, a: f32, b: f32) -> (u8, u8, u8) {
+    let epsilon = 216.0 / 24389.0;
+    let kappa = 24389.0 / 27.0;
+    let fy = (l + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = fy - b / 200.0;
+    let f_inv = |v: f32| {
+        if v.powi(3) > epsilon {
+            v.powi(3)
+        } else {
+            (116.0 * v - 16.0) / kappa
+        }
+    };
+    let y = if l > kappa * epsilon {
+        fy.powi(3)
+    } else {
+        l / kappa
+    };
+    let xyz = (
+        f_inv(fx) * D50_WHITE[0],
+        y * D50_WHITE[1],
+        f_inv(fz) * D50_WHITE[2],
+    );
+    let (x, y, z) = multiply_matrix(&D50_TO_D65, xyz);
+    xyz_to_rgb(x, y, z)
+}
+
+/// Convert rectangular a/b coordinates into chroma and hue(0~360),
+/// colors whose chroma is below `achromatic` get a hue of 0.
+pub fn ab_to_chroma_hue(a: f32, b: f32, achromatic: f32) -> (f32, f32) {
+    let c = (a * a + b * b).sqrt();
+    if c < achromatic {
+        return (c, 0.0);
+    }
+    (c, b.atan2(a).to_degrees().rem_euclid(360.0))
+}
+
+/// Convert chroma and hue(in degrees) into rectangular a/b coordinates
+pub fn chroma_hue_to_ab(c: f32, h: f32) -> (f32, f32) {
+    let h = h.to_radians();
+    (c * h.cos(), c * h.sin())
+}
+
+/// Returns Oklab of the rgb color
+pub fn rgb_to_oklab(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let r = srgb_to_linear(r as f32 / 255.0);
+    let g = srgb_to_linear(g as f32 / 255.0);
+    let b = srgb_to_linear(b as f32 / 255.0);
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+    (
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    )
+}
+
+/// Convert Oklab into linear light sRGB, the channels may be out of 0~1
+pub fn oklab_to_linear_rgb(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    (
+        4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_,
+        -1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_,
+        -0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_,
+    )
+}
+
+/// Convert Oklch into rgb. When the color is out of the sRGB gamut, the chroma is reduced
+/// until it fits, so the lightness and hue are kept instead of clipping every channel.
+pub fn oklch_to_rgb(l: f32, c: f32, h: f32) -> (u8, u8, u8) {
+    let l = l.clamp(0.0, 1.0);
+    let to_linear = |c: f32| {
+        let (a, b) = chroma_hue_to_ab(c, h);
+        oklab_to_linear_rgb(l, a, b)
+    };
+    let in_gamut = |(r, g, b): (f32, f32, f32)| {
+        let range = -0.000_1..=1.000_1;
+        range.contains(&r) && range.contains(&g) && range.contains(&b)
+    };
+    let mut rgb = to_linear(c);
+    if !in_gamut(rgb) {
+        let (mut low, mut high) = (0.0, c);
+        for _ in 0..24 {
+            let mid = (low + high) / 2.0;
+            if in_gamut(to_linear(mid)) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        rgb = to_linear(low);
+    }
+    (
+        unit_to_u8(linear_to_srgb(rgb.0.max(0.0))),
+        unit_to_u8(linear_to_srgb(rgb.1.max(0.0))),
+        unit_to_u8(linear_to_srgb(rgb.2.max(0.0))),
+    )
+}
+
+pub fn rgb_to_hsi(r: u8, g: u8, b: u8) -> (u32, u32, u32) {
+    let r = r as f32 / 255.0;
+    let g = g as f32 / 255.0;
+    let b = b as f32 / 255.0;
+    let i = (r + g + b) / 3.0;
+    let s = if i == 0.0 {
+        0.0
+    } else {
+        1.0 - r.min(g).min(b) / i
+    };
+    let den = ((r - g).powi(2) + (r - b) * (g - b)).sqrt();
+    let mut h = if den == 0.0 {
+        0.0
+    } else {
+        ((0.5 * ((r - g) + (r - b))) / den)
+            .clamp(-1.0, 1.0)
+            .acos()
+            .to_degrees()
+    };
+    if b > g {
+        h = 360.0 - h;
+    }
+    (
+        h.round() as u32 % 360,
+        (s * 100.0).round() as u32,
+        (i * 100.0).round() as u32,
+    )
+}
+
+pub fn hsi_to_rgb(h: u32, s: u32, i: u32) -> (u8, u8, u8) {
+    let h = (h % 360) as f32;
+    let s = s as f32 / 100.0;
+    let i = i as f32 / 100.0;
+    // every 120 degrees sector has its own formula, `h` is relative to the start of the sector
+    let calc = |h: f32| {
+        let a = i * (1.0 - s);
+        let b = i * (1.0 + s * h.to_radians().cos() / (60.0 - h).to_radians().cos());
+        (a, b, 3.0 * i - (a + b))
+    };
+    let (r, g, b) = if h < 120.0 {
+        let (b, r, g) = calc(h);
+        (r, g, b)
+    } else if h < 240.0 {
+        let (r, g, b) = calc(h - 120.0);
+        (r, g, b)
+    } else {
+        let (g, b, r) = calc(h - 240.0);
+        (r, g, b)
+    };
+    (unit_to_u8(r), unit_to_u8(g), unit_to_u8(b))
+}
+
+/// sRGB to CIE XYZ matrix of the HSLuv reference implementation
+const HSLUV_M_INV: [[f64; 3]; 3] = [
+    [0.41239079926595, 0.35758433938387, 0.18048078840183],
+    [0.21263900587151, 0.71516867876775, 0.072192315360733],
+    [0.019330818715591, 0.11919477979462, 0.95053215224966],
+];
+
+/// CIE XYZ to sRGB matrix of the HSLuv reference implementation
+const HSLUV_M: [[f64; 3]; 3] = [
+    [3.240969941904521, -1.537383177570093, -0.498610760293],
+    [-0.96924363628087, 1.87596750150772, 0.041555057407175],
+    [0.055630079696993, -0.20397695888897, 1.056971514242878],
+];
+
+const HSLUV_REF_U: f64 = 0.19783000664283;
+const HSLUV_REF_V: f64 = 0.46831999493879;
+const HSLUV_KAPPA: f64 = 903.2962962;
+const HSLUV_EPSILON: f64 = 0.0088564516;
+
+/// Returns the longest chroma of the sRGB gamut for the CIE LCh(uv) lightness and hue(radians)
+fn hsluv_max_chroma(l: f64, h: f64) -> f64 {
+    let sub1 = (l + 16.0).powi(3) / 1_560_896.0;
+    let sub2 = if sub1 > HSLUV_EPSILON {
+        sub1
+    } else {
+        l / HSLUV_KAPPA
+    };
+    let mut min = f64::MAX;
+    for [m1, m2, m3] in HSLUV_M {
+        for t in [0.0, 1.0] {
+            let top1 = (284_517.0 * m1 - 94_839.0 * m3) * sub2;
+            let top2 =
+                (838_422.0 * m3 + 769_860.0 * m2 + 731_718.0 * m1) * l * sub2 - 769_860.0 * t * l;
+            let bottom = (632_260.0 * m3 - 126_452.0 * m2) * sub2 + 126_452.0 * t;
+            let length = (top2 / bottom) / (h.sin() - top1 / bottom * h.cos());
+            if length >= 0.0 {
+                min = min.min(length);
+            }
+        }
+    }
+    min
+}
+
+/// Returns HSLuv(h 0~360, s 0~100, l 0~100) of the rgb color, using the reference HSLuv algorithm
+pub fn rgb_to_hsluv(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let linear = |v: u8| {
+        let v = v as f64 / 255.0;
+        if v <= 0.04045 {
+            v / 12.92
+        } else {
+            ((v + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let rgb = [linear(r), linear(g), linear(b)];
+    let [x, y, z] = HSLUV_M_INV.map(|m| m[0] * rgb[0] + m[1] * rgb[1] + m[2] * rgb[2]);
+    let l = if y <= HSLUV_EPSILON {
+        y * HSLUV_KAPPA
+    } else {
+        116.0 * y.cbrt() - 16.0
+    };
+    if l < 1e-8 {
+        return (0.0, 0.0, 0.0);
+    }
+    let divider = x + 15.0 * y + 3.0 * z;
+    let u = 13.0 * l * (4.0 * x / divider - HSLUV_REF_U);
+    let v = 13.0 * l * (9.0 * y / divider - HSLUV_REF_V);
+    let c = (u * u + v * v).sqrt();
+    let h = if c < 1e-8 {
+        0.0
+    } else {
+        v.atan2(u).to_degrees().rem_euclid(360.0)
+    };
+    if l > 99.999_999_9 {
+        return (h as f32, 0.0, 100.0);
+    }
+    let s = c / hsluv_max_chroma(l, h.to_radians()) * 100.0;
+    (h as f32, s.min(100.0) as f32, l as f32)
+}
+
+/// Convert HSLuv(h 0~360, s 0~100, l 0~100) into rgb, using the reference HSLuv algorithm
+pub fn hsluv_to_rgb(h: f32, s: f32, l: f32) -> (u8, u8, u8) {
+    let (h, s, l) = (h as f64, s as f64, l as f64);
+    if l > 99.999_999_9 {
+        return (255, 255, 255);
+    }
+    if l < 1e-8 {
+        return (0, 0, 0);
+    }
+    let hrad = h.to_radians();
+    let c = hsluv_max_chroma(l, hrad) / 100.0 * s;
+    let (u, v) = (hrad.cos() * c, hrad.sin() * c);
+    let var_u = u / (13.0 * l) + HSLUV_REF_U;
+    let var_v = v / (13.0 * l) + HSLUV_REF_V;
+    let y = if l <= 8.0 {
+        l / HSLUV_KAPPA
+    } else {
+        ((l + 16.0) / 116.0).powi(3)
+    };
+    let x = -(9.0 * y * var_u) / ((var_u - 4.0) * var_v - var_u * var_v);
+    let z = (9.0 * y - 15.0 * var_v * y - var_v * x) / (3.0 * var_v);
+    let encode = |v: f64| {
+        let v = if v <= 0.0031308 {
+            v * 12.92
+        } else {
+            1.055 * v.powf(1.0 / 2.4) - 0.055
+        };
+        (v.clamp(0.0, 1.0) * 255.0).round() as u8
+    };
+    let [r, g, b] = HSLUV_M.map(|m| encode(m[0] * x + m[1] * y + m[2] * z));
+    (r, g, b)
+}
+
+/// Returns the CIEDE2000 color difference of two CIE Lab colors, computed in f64 for the accuracy of the hue terms
+pub fn delta_e2000(lab1: (f32, f32, f32), lab2: (f32, f32, f32)) -> f32 {
+    let (l1, a1, b1) = (lab1.0 as f64, lab1.1 as f64, lab1.2 as f64);
+    let (l2, a2, b2) = (lab2.0 as f64, lab2.1 as f64, lab2.2 as f64);
+    let pow25_7 = 25f64.powi(7);
+    let c_bar = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt());
+    let (a1, a2) = ((1.0 + g) * a1, (1.0 + g) * a2);
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+    let hue = |b: f64, a: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let (h1, h2) = (hue(b1, a1), hue(b2, a2));
+    let chroma_zero = c1 * c2 == 0.0;
+
+    let dl = l2 - l1;
+    let dc = c2 - c1;
+    let dh = if chroma_zero {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if chroma_zero {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+    let cos = |deg: f64| deg.to_radians().cos();
+    let t =
+        1.0 - 0.17 * cos(h_bar - 30.0) + 0.24 * cos(2.0 * h_bar) + 0.32 * cos(3.0 * h_bar + 6.0)
+            - 0.20 * cos(4.0 * h_bar - 63.0);
+    let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let rc = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt();
+    let sl = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let sc = 1.0 + 0.045 * c_bar;
+    let sh = 1.0 + 0.015 * c_bar * t;
+    let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+    let (l, c, h) = (dl / sl, dc / sc, dh / sh);
+    (l * l + c * c + h * h + rt * c * h).sqrt() as f32
+}
+
+/// Returns the squared "redmean" distance of two rgb colors, a cheap weighted euclidean distance close to the perceived difference
+pub fn redmean_distance_squared(c1: (u8, u8, u8), c2: (u8, u8, u8)) -> f32 {
+    let r_mean = (c1.0 as f32 + c2.0 as f32) / 2.0;
+    let dr = c1.0 as f32 - c2.0 as f32;
+    let dg = c1.1 as f32 - c2.1 as f32;
+    let db = c1.2 as f32 - c2.2 as f32;
+    (2.0 + r_mean / 256.0) * dr * dr + 4.0 * dg * dg + (2.0 + (255.0 - r_mean) / 256.0) * db * db
+}
+
+/// Tanner Helland's fit of the blackbody color, t is the temperature in kelvin
+pub fn kelvin_to_rgb(t: u32) -> (u8, u8, u8) {
+    let t = t as f32 / 100.0;
+    let r = if t <= 66.0 {
+        255.0
+    } else {
+        329.698_73 * (t - 60.0).powf(-0.133_204_76)
+    };
+    let g = if t <= 66.0 {
+        99.470_8 * t.ln() - 161.119_57
+    } else {
+        288.122_17 * (t - 60.0).powf(-0.075_514_85)
+    };
+    let b = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.517_73 * (t - 10.0).ln() - 305.044_8
+    };
+    let to_u8 = |v: f32| v.round().clamp(0.0, 255.0) as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+/// Krystek's approximation of the Planckian locus in CIE 1960 uv, t is the temperature in kelvin
+pub fn planckian_uv(t: f32) -> (f32, f32) {
+    let u = (0.860_117_76 + 1.541_182_5e-4 * t + 1.286_412e-7 * t * t)
+        / (1.0 + 8.424_202e-4 * t + 7.081_452e-7 * t * t);
+    let v = (0.317_398_73 + 4.228_062_5e-5 * t + 4.204_817e-8 * t * t)
+        / (1.0 - 2.897_418_2e-5 * t + 1.614_560_5e-7 * t * t);
+    (u, v)
+}
+
+/// CIE 1960 uv chromaticity of CIE XYZ, None for black
+pub fn xyz_to_uv(x: f32, y: f32, z: f32) -> Option<(f32, f32)> {
+    let d = x + 15.0 * y + 3.0 * z;
+    if d <= 0.0 {
+        None
+    } else {
+        Some((4.0 * x / d, 6.0 * y / d))
+    }
 }
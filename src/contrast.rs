@@ -0,0 +1,147 @@
+use crate::common::{oklab_to_linear_rgb, rgb_to_oklab};
+use crate::{LinearRGB, RGB};
+
+/// The color found by `RGB::ensure_contrast`, with the contrast ratio it has on the background.
+///
+/// The color is moved towards black or white in Oklab, by the smallest step that reaches the target ratio.
+/// It is moved away from the background first, and the other way when black or white is not enough.
+/// When neither reaches the target, the color is black or white, the one with the higher contrast, and `achieved` is false.
+/// ### example
+/// ```rust
+/// use easy_color::{Hex, RGB};
+/// let fg:RGB = Hex::try_from("#2BC48A").unwrap().into();
+/// let result = fg.ensure_contrast(Hex::try_from("#FFFFFF").unwrap(), 4.5);
+/// assert!(result.achieved());
+/// assert!(result.ratio() >= 4.5);
+/// assert_eq!(result.color().to_string(), "rgb(26,134,93)");
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EnsuredContrast {
+    pub(crate) color: RGB,
+    pub(crate) ratio: f32,
+    pub(crate) achieved: bool,
+}
+
+const BLACK: RGB = RGB { r: 0, g: 0, b: 0 };
+const WHITE: RGB = RGB {
+    r: 255,
+    g: 255,
+    b: 255,
+};
+
+/// Returns the color moved by t(0~1) towards the end in Oklab, 1 is the end itself
+fn move_towards(oklab: (f32, f32, f32), end: RGB, t: f32) -> RGB {
+    if t >= 1.0 {
+        return end;
+    }
+    let target = if end == BLACK { 0.0 } else { 1.0 };
+    let (l, a, b) = oklab;
+    let (r, g, b) = oklab_to_linear_rgb(l + (target - l) * t, a * (1.0 - t), b * (1.0 - t));
+    LinearRGB {
+        r: r.clamp(0.0, 1.0),
+        g: g.clamp(0.0, 1.0),
+        b: b.clamp(0.0, 1.0),
+    }
+    .into()
+}
+
+impl EnsuredContrast {
+    pub(crate) fn solve(fg: RGB, background: RGB, ratio: f32) -> Self {
+        let current = fg.contrast_ratio(background);
+        if current >= ratio {
+            return Self {
+                color: fg,
+                ratio: current,
+                achieved: true,
+            };
+        }
+        let ends = if fg.relative_luminance() <= background.relative_luminance() {
+            [BLACK, WHITE]
+        } else {
+            [WHITE, BLACK]
+        };
+        let oklab = rgb_to_oklab(fg.r, fg.g, fg.b);
+        for end in ends {
+            if end.contrast_ratio(background) < ratio {
+                continue;
+            }
+            // the smallest step whose rounded color reaches the ratio
+            let (mut low, mut high) = (0.0, 1.0);
+            for _ in 0..24 {
+                let t = (low + high) / 2.0;
+                if move_towards(oklab, end, t).contrast_ratio(background) >= ratio {
+                    high = t;
+                } else {
+                    low = t;
+                }
+            }
+            let color = move_towards(oklab, end, high);
+            return Self {
+                color,
+                ratio: color.contrast_ratio(background),
+                achieved: true,
+            };
+        }
+        let color = background.contrast_text();
+        Self {
+            color,
+            ratio: color.contrast_ratio(background),
+            achieved: false,
+        }
+    }
+
+    /// The color, or black or white when the target ratio is not reachable
+    pub fn color(&self) -> RGB {
+        self.color
+    }
+
+    /// The contrast ratio of the color on the background
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Returns true if the color reaches the target ratio
+    pub fn achieved(&self) -> bool {
+        self.achieved
+    }
+}
+
+/// The conformance level of WCAG 2.x for `is_readable_on`
+/// * AA - 4.5 for the normal text and 3.0 for the large text
+/// * AAA - 7.0 for the normal text and 4.5 for the large text
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum WcagLevel {
+    #[default]
+    AA,
+    AAA,
+}
+
+/// The size of the text for `is_readable_on`, large is at least 18pt, or 14pt and bold
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum TextSize {
+    #[default]
+    Normal,
+    Large,
+}
+
+impl WcagLevel {
+    /// Returns the minimum contrast ratio of the level for the text size
+    /// ```rust
+    /// use easy_color::{TextSize, WcagLevel};
+    /// assert_eq!(WcagLevel::AA.min_ratio(TextSize::Normal), 4.5);
+    /// assert_eq!(WcagLevel::AAA.min_ratio(TextSize::Large), 4.5);
+    /// ```
+    pub fn min_ratio(&self, size: TextSize) -> f32 {
+        match (self, size) {
+            (WcagLevel::AA, TextSize::Normal) => 4.5,
+            (WcagLevel::AA, TextSize::Large) => 3.0,
+            (WcagLevel::AAA, TextSize::Normal) => 7.0,
+            (WcagLevel::AAA, TextSize::Large) => 4.5,
+        }
+    }
+
+    /// Returns true if the contrast ratio meets the level for the text size, the minimum itself passes
+    pub fn is_met_by(&self, ratio: f32, size: TextSize) -> bool {
+        ratio >= self.min_ratio(size)
+    }
+}
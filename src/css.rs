@@ -0,0 +1,170 @@
+use crate::common::format_trimmed;
+use crate::{
+    AdobeRGB, Ansi256, AnyColor, Cam16, Gray, HSLuv, Hex, Kelvin, LinearRGB, OkLch, ProPhotoRGB,
+    RGBAf, RGBf, Rec2020, Xyy, YCbCr, CMYK, CMYKA, HSI, HSL, HSLA, HSV, HWB, LCH, LMS, P3, RGB,
+    RGB16, RGB565, RGBA, XYZ, YIQ,
+};
+
+/// Write a color into a stylesheet.
+/// * to_css - the legacy comma syntax, which is what `Display` writes for the CSS types
+/// * to_css_modern - the CSS Color 4 syntax, like "rgb(43 196 138 / 0.85)" and "hsl(157deg 64% 47%)",
+///   an alpha of 1 is omitted
+///
+/// Hex uses the shorthand when it is lossless, and the alpha needs the modern syntax, so the legacy output
+/// of a transparent Hex is "rgba()". Types without a CSS syntax, like HSV and CMYK, are written as RGB,
+/// or RGBA when they have an alpha.
+/// ### example
+/// ```rust
+/// use easy_color::{Hex, HSL, RGBA, ToCss};
+/// let rgba:RGBA = (43,196,138,0.85).try_into().unwrap();
+/// assert_eq!(rgba.to_css(), "rgba(43,196,138,0.85)");
+/// assert_eq!(rgba.to_css_modern(), "rgb(43 196 138 / 0.85)");
+///
+/// let hsl:HSL = (157,64,47).try_into().unwrap();
+/// assert_eq!(hsl.to_css_modern(), "hsl(157deg 64% 47%)");
+///
+/// let hex:Hex = "#FFAA0088".try_into().unwrap();
+/// assert_eq!(hex.to_css_modern(), "#FA08");
+/// ```
+pub trait ToCss {
+    fn to_css(&self) -> String;
+    fn to_css_modern(&self) -> String;
+}
+
+/// The " / a" of the modern syntax, empty when the color is opaque
+fn modern_alpha(a: f32) -> String {
+    if a == 1.0 {
+        String::new()
+    } else {
+        format!(" / {}", format_trimmed(a, 2))
+    }
+}
+
+impl ToCss for Hex {
+    fn to_css(&self) -> String {
+        if self.rgba.3 != 1.0 {
+            return RGBA::from(*self).to_css();
+        }
+        self.to_css_modern()
+    }
+
+    fn to_css_modern(&self) -> String {
+        self.to_short_string()
+    }
+}
+
+impl ToCss for RGB {
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_css_modern(&self) -> String {
+        format!("rgb({} {} {})", self.r, self.g, self.b)
+    }
+}
+
+impl ToCss for RGBA {
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_css_modern(&self) -> String {
+        format!(
+            "rgb({} {} {}{})",
+            self.r,
+            self.g,
+            self.b,
+            modern_alpha(self.a)
+        )
+    }
+}
+
+impl ToCss for HSL {
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_css_modern(&self) -> String {
+        format!("hsl({}deg {}% {}%)", self.h, self.s, self.l)
+    }
+}
+
+impl ToCss for HSLA {
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_css_modern(&self) -> String {
+        let HSL { h, s, l } = self.hsl;
+        format!("hsl({}deg {}% {}%{})", h, s, l, modern_alpha(self.a))
+    }
+}
+
+impl ToCss for HWB {
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_css_modern(&self) -> String {
+        format!("hwb({}deg {}% {}%)", self.h, self.w, self.b)
+    }
+}
+
+impl ToCss for RGBAf {
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_css_modern(&self) -> String {
+        let RGBf { r, g, b } = self.rgb;
+        format!(
+            "color(srgb {:.4} {:.4} {:.4}{})",
+            r,
+            g,
+            b,
+            modern_alpha(self.a)
+        )
+    }
+}
+
+/// The types whose `Display` is already in the modern syntax, which is the legacy syntax too
+macro_rules! impl_to_css_display {
+    ($($color:ty),*) => {
+        $(
+            impl ToCss for $color {
+                fn to_css(&self) -> String {
+                    self.to_string()
+                }
+
+                fn to_css_modern(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_css_display!(LCH, OkLch, RGBf, P3, Rec2020, AdobeRGB, ProPhotoRGB);
+
+/// The types without a CSS syntax, written as the color they convert into
+macro_rules! impl_to_css_via {
+    ($via:ty: $($color:ty),*) => {
+        $(
+            impl ToCss for $color {
+                fn to_css(&self) -> String {
+                    <$via>::from(*self).to_css()
+                }
+
+                fn to_css_modern(&self) -> String {
+                    <$via>::from(*self).to_css_modern()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_css_via!(
+    RGB: HSV, HSI, CMYK, HSLuv, Cam16, LMS, XYZ, YIQ, Gray, RGB16, RGB565, Ansi256, LinearRGB, Xyy,
+    YCbCr, Kelvin
+);
+impl_to_css_via!(RGBA: CMYKA, AnyColor);
@@ -0,0 +1,282 @@
+use crate::common::{rgb_to_lab, unit_to_u8};
+use crate::RGB;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt::{Display, Formatter};
+
+/// The upper bound of each filter value, hue-rotate is a percentage of a full turn and wraps around
+const MAX_VALUES: [f32; 6] = [100.0, 100.0, 7500.0, 100.0, 200.0, 200.0];
+
+/// A color with the channels(0~255) as floats, filtered like the CSS filter functions do
+#[derive(Debug, Clone, Copy)]
+struct FilterColor([f32; 3]);
+
+impl FilterColor {
+    fn multiply(&mut self, m: [f32; 9]) {
+        let [r, g, b] = self.0;
+        self.0 = [
+            r * m[0] + g * m[1] + b * m[2],
+            r * m[3] + g * m[4] + b * m[5],
+            r * m[6] + g * m[7] + b * m[8],
+        ]
+        .map(|v| v.clamp(0.0, 255.0));
+    }
+
+    fn linear(&mut self, slope: f32, intercept: f32) {
+        self.0 = self
+            .0
+            .map(|v| (v * slope + intercept * 255.0).clamp(0.0, 255.0));
+    }
+
+    fn invert(&mut self, v: f32) {
+        self.0 = self
+            .0
+            .map(|c| ((v + c / 255.0 * (1.0 - 2.0 * v)) * 255.0).clamp(0.0, 255.0));
+    }
+
+    fn sepia(&mut self, v: f32) {
+        let v = 1.0 - v;
+        self.multiply([
+            0.393 + 0.607 * v,
+            0.769 - 0.769 * v,
+            0.189 - 0.189 * v,
+            0.349 - 0.349 * v,
+            0.686 + 0.314 * v,
+            0.168 - 0.168 * v,
+            0.272 - 0.272 * v,
+            0.534 - 0.534 * v,
+            0.131 + 0.869 * v,
+        ]);
+    }
+
+    fn saturate(&mut self, v: f32) {
+        self.multiply([
+            0.213 + 0.787 * v,
+            0.715 - 0.715 * v,
+            0.072 - 0.072 * v,
+            0.213 - 0.213 * v,
+            0.715 + 0.285 * v,
+            0.072 - 0.072 * v,
+            0.213 - 0.213 * v,
+            0.715 - 0.715 * v,
+            0.072 + 0.928 * v,
+        ]);
+    }
+
+    fn hue_rotate(&mut self, degree: f32) {
+        let (sin, cos) = degree.to_radians().sin_cos();
+        self.multiply([
+            0.213 + cos * 0.787 - sin * 0.213,
+            0.715 - cos * 0.715 - sin * 0.715,
+            0.072 - cos * 0.072 + sin * 0.928,
+            0.213 - cos * 0.213 + sin * 0.143,
+            0.715 + cos * 0.285 + sin * 0.140,
+            0.072 - cos * 0.072 - sin * 0.283,
+            0.213 - cos * 0.213 - sin * 0.787,
+            0.715 - cos * 0.715 + sin * 0.715,
+            0.072 + cos * 0.928 + sin * 0.072,
+        ]);
+    }
+
+    /// Returns h, s and l, all of them scaled to 0~100
+    fn hsl(&self) -> [f32; 3] {
+        let [r, g, b] = self.0.map(|v| v / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        if max == min {
+            return [0.0, 0.0, l * 100.0];
+        }
+        let d = max - min;
+        let s = if l > 0.5 {
+            d / (2.0 - max - min)
+        } else {
+            d / (max + min)
+        };
+        let h = if max == r {
+            (g - b) / d + if g < b { 6.0 } else { 0.0 }
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        [h / 6.0 * 100.0, s * 100.0, l * 100.0]
+    }
+
+    /// Returns black filtered by invert, sepia, saturate, hue-rotate, brightness and contrast
+    fn filtered(values: &[f32; 6]) -> Self {
+        let mut color = Self([0.0; 3]);
+        color.invert(values[0] / 100.0);
+        color.sepia(values[1] / 100.0);
+        color.saturate(values[2] / 100.0);
+        color.hue_rotate(values[3] * 3.6);
+        let brightness = values[4] / 100.0;
+        color.linear(brightness, 0.0);
+        let contrast = values[5] / 100.0;
+        color.linear(contrast, -(0.5 * contrast) + 0.5);
+        color
+    }
+}
+
+/// The filter chain found by `RGB::to_css_filter`, which recolors a black element, like an SVG icon, into the target color.
+///
+/// It is approximate, the loss is the sum of the absolute differences of the RGB channels(0~255)
+/// and of the HSL values(0~100) of the filtered black and the target color, without the hue for grays, found by SPSA,
+/// a random gradient descent, so the same seed always returns the same filter.
+/// Below 1 the result is nearly perfect, below 5 it is close, and above 15 it should be retried with another seed.
+/// The delta-E(CIE76) of the rounded filter is below 5 for most colors.
+/// ### example
+/// ```rust
+/// use easy_color::RGB;
+/// let rgb:RGB = (43,196,138).try_into().unwrap();
+/// let filter = rgb.to_css_filter();
+/// assert!(filter.to_string().starts_with("invert("));
+/// assert!(filter.delta_e() < 5.0);
+/// assert_eq!(filter, rgb.to_css_filter());
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CssFilter {
+    pub(crate) values: [f32; 6],
+    pub(crate) loss: f32,
+    pub(crate) delta_e: f32,
+}
+
+impl Display for CssFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let [invert, sepia, saturate, hue, brightness, contrast] = self.values;
+        write!(
+            f,
+            "invert({}%) sepia({}%) saturate({}%) hue-rotate({}deg) brightness({}%) contrast({}%)",
+            invert,
+            sepia,
+            saturate,
+            (hue * 3.6).round(),
+            brightness,
+            contrast
+        )
+    }
+}
+
+/// The loss of the filter values against the target color, see `CssFilter`
+fn loss(values: &[f32; 6], target: &FilterColor, target_hsl: &[f32; 3]) -> f32 {
+    let color = FilterColor::filtered(values);
+    let hsl = color.hsl();
+    let rgb_loss: f32 = (0..3).map(|i| (color.0[i] - target.0[i]).abs()).sum();
+    // the hue of a gray target is meaningless, a nearly gray result would be punished for it
+    let from = if target_hsl[1] == 0.0 { 1 } else { 0 };
+    let hsl_loss: f32 = (from..3).map(|i| (hsl[i] - target_hsl[i]).abs()).sum();
+    rgb_loss + hsl_loss
+}
+
+/// Keep the value in its range, the hue-rotate wraps around
+fn fix(value: f32, idx: usize) -> f32 {
+    let max = MAX_VALUES[idx];
+    if idx == 3 {
+        value.rem_euclid(max)
+    } else {
+        value.clamp(0.0, max)
+    }
+}
+
+/// Simultaneous perturbation stochastic approximation, returns the best values and their loss
+fn spsa(
+    rng: &mut StdRng,
+    (big_a, a, c): (f32, [f32; 6], f32),
+    mut values: [f32; 6],
+    iters: usize,
+    target: (&FilterColor, &[f32; 3]),
+) -> ([f32; 6], f32) {
+    let (target, target_hsl) = target;
+    let gamma = 1.0 / 6.0;
+    let mut best = values;
+    let mut best_loss = f32::INFINITY;
+    for k in 0..iters {
+        let ck = c / ((k + 1) as f32).powf(gamma);
+        let deltas: [f32; 6] = std::array::from_fn(|_| if rng.gen_bool(0.5) { 1.0 } else { -1.0 });
+        let high: [f32; 6] = std::array::from_fn(|i| values[i] + ck * deltas[i]);
+        let low: [f32; 6] = std::array::from_fn(|i| values[i] - ck * deltas[i]);
+        let loss_diff = loss(&high, target, target_hsl) - loss(&low, target, target_hsl);
+        for i in 0..6 {
+            let g = loss_diff / (2.0 * ck) * deltas[i];
+            let ak = a[i] / (big_a + k as f32 + 1.0);
+            values[i] = fix(values[i] - ak * g, i);
+        }
+        let loss = loss(&values, target, target_hsl);
+        if loss < best_loss {
+            best = values;
+            best_loss = loss;
+        }
+    }
+    (best, best_loss)
+}
+
+impl CssFilter {
+    /// Search the filter values of the target color, a wide search from a fixed start retried up to 3 times,
+    /// then a narrow search around the best result.
+    pub(crate) fn solve(rgb: RGB, seed: u64) -> Self {
+        let target = FilterColor([rgb.r, rgb.g, rgb.b].map(|v| v as f32));
+        let target_hsl = target.hsl();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let wide_a = [60.0, 180.0, 18000.0, 600.0, 1.2, 1.2];
+        let mut wide = ([0.0; 6], f32::INFINITY);
+        for _ in 0..3 {
+            let start = [50.0, 20.0, 3750.0, 50.0, 100.0, 100.0];
+            let result = spsa(
+                &mut rng,
+                (5.0, wide_a, 15.0),
+                start,
+                1000,
+                (&target, &target_hsl),
+            );
+            if result.1 < wide.1 {
+                wide = result;
+            }
+            if wide.1 <= 25.0 {
+                break;
+            }
+        }
+        let big_a = wide.1;
+        let a1 = big_a + 1.0;
+        let narrow_a = [0.25 * a1, 0.25 * a1, a1, 0.25 * a1, 0.2 * a1, 0.2 * a1];
+        let (values, _) = spsa(
+            &mut rng,
+            (big_a, narrow_a, 2.0),
+            wide.0,
+            500,
+            (&target, &target_hsl),
+        );
+        // the string has whole numbers, so the loss and delta-E are of the rounded values
+        let hue = (values[3] * 3.6).round();
+        let mut values = values.map(|v| v.round());
+        values[3] = (hue / 3.6).rem_euclid(100.0);
+        let loss = loss(&values, &target, &target_hsl);
+        let mut filter = Self {
+            values,
+            loss,
+            delta_e: 0.0,
+        };
+        let (l1, a1, b1) = rgb_to_lab(rgb.r, rgb.g, rgb.b);
+        let RGB { r, g, b } = filter.color();
+        let (l2, a2, b2) = rgb_to_lab(r, g, b);
+        filter.delta_e = ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt();
+        filter
+    }
+
+    /// The loss of the filter, see `CssFilter`
+    pub fn loss(&self) -> f32 {
+        self.loss
+    }
+
+    /// The delta-E(CIE76) between the target color and the color the filter produces from black
+    pub fn delta_e(&self) -> f32 {
+        self.delta_e
+    }
+
+    /// Returns the color the filter produces from black
+    pub fn color(&self) -> RGB {
+        let [r, g, b] = FilterColor::filtered(&self.values)
+            .0
+            .map(|v| unit_to_u8(v / 255.0));
+        RGB { r, g, b }
+    }
+}
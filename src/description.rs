@@ -0,0 +1,191 @@
+use crate::common::rgb_to_hsl;
+use crate::RGB;
+use std::fmt::{Display, Formatter};
+
+/// The hue families of `ColorDescription`, by the hue in degrees.
+/// * Red - 345~360 and 0~15
+/// * Orange - 15~45
+/// * Yellow - 45~70
+/// * Green - 70~165
+/// * Cyan - 165~195
+/// * Blue - 195~255
+/// * Purple - 255~285
+/// * Magenta - 285~320
+/// * Pink - 320~345
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum HueFamily {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Purple,
+    Magenta,
+    Pink,
+}
+
+/// The lightness buckets of `ColorDescription`, by the lightness of HSL: below 20, 40, 60, 80 and up to 100
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LightnessLevel {
+    VeryDark,
+    Dark,
+    Medium,
+    Light,
+    VeryLight,
+}
+
+/// The saturation buckets of `ColorDescription`, by the saturation of HSL: below 25, 50, 75 and up to 100
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SaturationLevel {
+    Grayish,
+    Desaturated,
+    Moderate,
+    Vivid,
+}
+
+/// The colors without a hue family
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Neutral {
+    Black,
+    White,
+    Gray,
+}
+
+/// An approximate description of a color like "dark desaturated cyan" or "light vivid orange", for logs and accessibility text.
+/// It is computed from HSL, the medium lightness and the moderate saturation are left out of the text.
+/// * the colors with a lightness below 8 are "black" and above 96 are "white"
+/// * the colors whose channels differ by less than 10% are grays, like "dark gray", and have no hue family
+/// ### example
+/// ```rust
+/// use easy_color::{Describe, HueFamily, RGB};
+/// let rgb:RGB = (43,196,138).try_into().unwrap();
+/// let description = rgb.describe();
+/// assert_eq!(description.to_string(), "green");
+/// assert_eq!(description.hue_family(), Some(HueFamily::Green));
+///
+/// let rgb:RGB = (240,240,240).try_into().unwrap();
+/// assert_eq!(rgb.describe().to_string(), "very light gray");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ColorDescription {
+    pub(crate) hue_family: Option<HueFamily>,
+    pub(crate) lightness: LightnessLevel,
+    pub(crate) saturation: SaturationLevel,
+    neutral: Option<Neutral>,
+}
+
+impl From<RGB> for ColorDescription {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (h, s, l) = rgb_to_hsl(r, g, b);
+        let lightness = match l {
+            0..=19 => LightnessLevel::VeryDark,
+            20..=39 => LightnessLevel::Dark,
+            40..=59 => LightnessLevel::Medium,
+            60..=79 => LightnessLevel::Light,
+            _ => LightnessLevel::VeryLight,
+        };
+        let saturation = match s {
+            0..=24 => SaturationLevel::Grayish,
+            25..=49 => SaturationLevel::Desaturated,
+            50..=74 => SaturationLevel::Moderate,
+            _ => SaturationLevel::Vivid,
+        };
+        // the saturation of HSL is unreliable near black and white, so grays are found by the chroma
+        let chroma = r.max(g).max(b) - r.min(g).min(b);
+        let neutral = if l < 8 {
+            Some(Neutral::Black)
+        } else if l > 96 {
+            Some(Neutral::White)
+        } else if (chroma as f32) < 0.1 * 255.0 {
+            Some(Neutral::Gray)
+        } else {
+            None
+        };
+        let hue_family = neutral.is_none().then_some(match h % 360 {
+            15..=44 => HueFamily::Orange,
+            45..=69 => HueFamily::Yellow,
+            70..=164 => HueFamily::Green,
+            165..=194 => HueFamily::Cyan,
+            195..=254 => HueFamily::Blue,
+            255..=284 => HueFamily::Purple,
+            285..=319 => HueFamily::Magenta,
+            320..=344 => HueFamily::Pink,
+            _ => HueFamily::Red,
+        });
+        Self {
+            hue_family,
+            lightness,
+            saturation,
+            neutral,
+        }
+    }
+}
+
+impl Display for ColorDescription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let lightness = match self.lightness {
+            LightnessLevel::VeryDark => Some("very dark"),
+            LightnessLevel::Dark => Some("dark"),
+            LightnessLevel::Medium => None,
+            LightnessLevel::Light => Some("light"),
+            LightnessLevel::VeryLight => Some("very light"),
+        };
+        let saturation = match self.saturation {
+            SaturationLevel::Grayish => Some("grayish"),
+            SaturationLevel::Desaturated => Some("desaturated"),
+            SaturationLevel::Moderate => None,
+            SaturationLevel::Vivid => Some("vivid"),
+        };
+        let hue = match self.hue_family {
+            Some(HueFamily::Red) => "red",
+            Some(HueFamily::Orange) => "orange",
+            Some(HueFamily::Yellow) => "yellow",
+            Some(HueFamily::Green) => "green",
+            Some(HueFamily::Cyan) => "cyan",
+            Some(HueFamily::Blue) => "blue",
+            Some(HueFamily::Purple) => "purple",
+            Some(HueFamily::Magenta) => "magenta",
+            Some(HueFamily::Pink) => "pink",
+            None => match self.neutral {
+                Some(Neutral::Black) => "black",
+                Some(Neutral::White) => "white",
+                _ => "gray",
+            },
+        };
+        // the grays keep the lightness, black and white are a single word
+        let words = match self.neutral {
+            Some(Neutral::Gray) => [lightness, None, Some(hue)],
+            Some(_) => [None, None, Some(hue)],
+            None => [lightness, saturation, Some(hue)],
+        };
+        let text = words
+            .iter()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+        write!(f, "{}", text)
+    }
+}
+
+impl ColorDescription {
+    /// Returns the hue family, None for black, white and grays
+    pub fn hue_family(&self) -> Option<HueFamily> {
+        self.hue_family
+    }
+
+    pub fn lightness(&self) -> LightnessLevel {
+        self.lightness
+    }
+
+    pub fn saturation(&self) -> SaturationLevel {
+        self.saturation
+    }
+
+    /// Returns true for black, white and the grays
+    pub fn is_neutral(&self) -> bool {
+        self.hue_family.is_none()
+    }
+}
@@ -0,0 +1,94 @@
+use crate::common::{delta_e2000, rgb_to_lab};
+use crate::RGB;
+
+/// The measure of the difference of two colors for `nearest_in` and `PaletteMatcher`.
+/// * Redmean - the euclidean distance with the weights depending on the mean red, cheap and close to the perceived difference
+/// * WeightedRgb - the euclidean distance with the weights 2, 4 and 3 for red, green and blue
+/// * DeltaE2000 - the CIEDE2000 color difference, the most accurate and the slowest
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum DistanceMetric {
+    #[default]
+    Redmean,
+    WeightedRgb,
+    DeltaE2000,
+}
+
+impl DistanceMetric {
+    /// Returns the distance of the colors with the metric
+    pub fn distance(&self, c1: RGB, c2: RGB) -> f32 {
+        let (t1, t2) = ((c1.r, c1.g, c1.b), (c2.r, c2.g, c2.b));
+        match self {
+            DistanceMetric::Redmean => c1.distance_redmean(c2),
+            DistanceMetric::WeightedRgb => {
+                let dr = c1.r as f32 - c2.r as f32;
+                let dg = c1.g as f32 - c2.g as f32;
+                let db = c1.b as f32 - c2.b as f32;
+                (2.0 * dr * dr + 4.0 * dg * dg + 3.0 * db * db).sqrt()
+            }
+            DistanceMetric::DeltaE2000 => {
+                delta_e2000(rgb_to_lab(t1.0, t1.1, t1.2), rgb_to_lab(t2.0, t2.1, t2.2))
+            }
+        }
+    }
+}
+
+/// PaletteMatcher finds the nearest color of a palette, for many lookups against the same palette.
+/// The Lab values of the palette are computed once for DeltaE2000, and the first color wins a tie.
+/// ### example
+/// ```rust
+/// use easy_color::{DistanceMetric, Hex, PaletteMatcher, RGB};
+/// let palette = ["#000000", "#2BC48A", "#FFFFFF"].map(|s| Hex::try_from(s).unwrap());
+/// let matcher = PaletteMatcher::new(&palette, DistanceMetric::DeltaE2000);
+/// let (idx, color, _) = matcher.nearest(RGB::try_from((40, 180, 130)).unwrap()).unwrap();
+/// assert_eq!(idx, 1);
+/// assert_eq!(color.to_string(), "rgb(43,196,138)");
+/// assert!(PaletteMatcher::new::<RGB>(&[], DistanceMetric::Redmean).nearest(color).is_none());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct PaletteMatcher {
+    pub(crate) colors: Vec<RGB>,
+    pub(crate) labs: Vec<(f32, f32, f32)>,
+    pub(crate) metric: DistanceMetric,
+}
+
+impl PaletteMatcher {
+    pub fn new<C: Into<RGB> + Copy>(palette: &[C], metric: DistanceMetric) -> Self {
+        let colors: Vec<RGB> = palette.iter().map(|c| (*c).into()).collect();
+        let labs = if metric == DistanceMetric::DeltaE2000 {
+            colors.iter().map(|c| rgb_to_lab(c.r, c.g, c.b)).collect()
+        } else {
+            Vec::new()
+        };
+        Self {
+            colors,
+            labs,
+            metric,
+        }
+    }
+
+    pub fn colors(&self) -> &[RGB] {
+        &self.colors
+    }
+
+    pub fn metric(&self) -> DistanceMetric {
+        self.metric
+    }
+
+    /// Returns the index, the color and the distance of the nearest color, None when the palette is empty
+    pub fn nearest(&self, color: impl Into<RGB>) -> Option<(usize, RGB, f32)> {
+        let color: RGB = color.into();
+        let lab = (self.metric == DistanceMetric::DeltaE2000)
+            .then(|| rgb_to_lab(color.r, color.g, color.b));
+        let mut best: Option<(usize, RGB, f32)> = None;
+        for (idx, candidate) in self.colors.iter().enumerate() {
+            let distance = match lab {
+                Some(lab) => delta_e2000(lab, self.labs[idx]),
+                None => self.metric.distance(color, *candidate),
+            };
+            if best.is_none_or(|(_, _, d)| distance < d) {
+                best = Some((idx, *candidate, distance));
+            }
+        }
+        best
+    }
+}
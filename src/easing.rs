@@ -0,0 +1,82 @@
+/// The curve that remaps t(0~1) before interpolating, for `Gradient::sample_eased` and `lerp_eased`.
+/// * Linear - t is not changed
+/// * EaseIn - cubic-bezier(0.42, 0, 1, 1), like "ease-in" of CSS
+/// * EaseOut - cubic-bezier(0, 0, 0.58, 1), like "ease-out" of CSS
+/// * EaseInOut - cubic-bezier(0.42, 0, 0.58, 1), like "ease-in-out" of CSS
+/// * CubicBezier(x1, y1, x2, y2) - the control points of a CSS "cubic-bezier()", the x values are clamped between 0~1
+///
+/// 0 and 1 always return 0 and 1, the y values of CubicBezier may overshoot them in between.
+/// ### example
+/// ```rust
+/// use easy_color::Easing;
+/// assert_eq!(Easing::Linear.ease(0.25), 0.25);
+/// assert!(Easing::EaseIn.ease(0.25) < 0.25);
+/// assert!(Easing::EaseOut.ease(0.25) > 0.25);
+/// assert_eq!(Easing::EaseInOut.ease(1.0), 1.0);
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum Easing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier(f32, f32, f32, f32),
+}
+
+/// The value of the cubic bezier from 0 over p1 and p2 to 1 at t, and its derivative
+fn bezier(p1: f32, p2: f32, t: f32) -> (f32, f32) {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+    (((a * t + b) * t + c) * t, (3.0 * a * t + 2.0 * b) * t + c)
+}
+
+/// Returns the y of the curve at x, the bezier t of x is found by Newton's method with a bisection fallback
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    let (x1, x2) = (x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+    let mut t = x;
+    for _ in 0..8 {
+        let (value, slope) = bezier(x1, x2, t);
+        let diff = value - x;
+        if diff.abs() < 1e-6 {
+            return bezier(y1, y2, t).0;
+        }
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        t -= diff / slope;
+    }
+    let (mut low, mut high) = (0.0, 1.0);
+    t = x;
+    for _ in 0..32 {
+        let value = bezier(x1, x2, t).0;
+        if (value - x).abs() < 1e-6 {
+            break;
+        }
+        if value < x {
+            low = t;
+        } else {
+            high = t;
+        }
+        t = (low + high) / 2.0;
+    }
+    bezier(y1, y2, t).0
+}
+
+impl Easing {
+    /// Returns t remapped by the curve, t is clamped between 0~1 and NaN is 0
+    pub fn ease(&self, t: f32) -> f32 {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        if t == 0.0 || t == 1.0 {
+            return t;
+        }
+        match *self {
+            Easing::Linear => t,
+            Easing::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Easing::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Easing::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, t),
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use crate::common::{format_trimmed, parse_err, parse_hue, strip_prefix_ignore_case};
+use crate::{parse, ColorError, Easing, Hex, RGBA};
+
+/// A color of a gradient with its optional position, a percentage(0~100) along the gradient line.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ColorStop {
+    pub(crate) color: RGBA,
+    pub(crate) position: Option<f32>,
+}
+
+impl ColorStop {
+    pub fn color(&self) -> RGBA {
+        self.color
+    }
+
+    pub fn position(&self) -> Option<f32> {
+        self.position
+    }
+}
+
+/// Gradient is a list of color stops, which can be read from and written to a CSS "linear-gradient()".
+/// The stops without a position are placed like CSS does, see `positions`,
+/// and `to_css` writes every position so the result is unambiguous.
+/// It can also be built from (position, color) stops with `from_stops` and sampled as a color scale with `sample` and `colors`.
+/// ### example
+/// ```rust
+/// use easy_color::Gradient;
+/// let gradient = Gradient::from_css("linear-gradient(to right, #2bc48a, rgba(255,125,55,0.5))").unwrap();
+/// assert_eq!(gradient.direction(), Some("to right"));
+/// assert_eq!(gradient.positions(), vec![0.0, 100.0]);
+/// assert_eq!(
+///     gradient.to_css("90deg"),
+///     "linear-gradient(90deg, #2BC48A 0%, rgba(255,125,55,0.50) 100%)"
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Gradient {
+    pub(crate) stops: Vec<ColorStop>,
+    pub(crate) direction: Option<String>,
+}
+
+/// Split the arguments of a CSS function at the commas outside of parentheses
+fn split_args(args: &str) -> Vec<&str> {
+    let mut depth = 0usize;
+    args.split(|c: char| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        depth == 0 && c == ','
+    })
+    .map(|arg| arg.trim())
+    .collect()
+}
+
+impl Gradient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// The angle or direction of the parsed "linear-gradient()", like "90deg" or "to right"
+    pub fn direction(&self) -> Option<&str> {
+        self.direction.as_deref()
+    }
+
+    pub fn len(&self) -> usize {
+        self.stops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stops.is_empty()
+    }
+
+    /// Append a color with an optional position(0~100)
+    pub fn push<C: Into<RGBA>>(&mut self, color: C, position: Option<f32>) -> &mut Self {
+        self.stops.push(ColorStop {
+            color: color.into(),
+            position,
+        });
+        self
+    }
+
+    /// Build a gradient of (position, color) stops, the positions are 0~1 and the stops are sorted by them,
+    /// the stops with the same position keep their order, so the later one starts a hard edge.
+    /// ```rust
+    /// use easy_color::{Gradient, Hex, RGBA};
+    /// let red:RGBA = (255,0,0,1.0).try_into().unwrap();
+    /// let gradient = Gradient::from_stops([(1.0, red), (0.0, Hex::try_from("#2BC48A").unwrap().into())]);
+    /// assert_eq!(gradient.positions(), vec![0.0, 100.0]);
+    /// ```
+    pub fn from_stops<C: Into<RGBA>>(stops: impl IntoIterator<Item = (f32, C)>) -> Self {
+        let mut gradient = Self::new();
+        for (position, color) in stops {
+            gradient.add_stop(position, color);
+        }
+        gradient
+    }
+
+    /// Insert a color at the position(0~1) after the stops at or before it, NaN is 0
+    pub fn add_stop<C: Into<RGBA>>(&mut self, position: f32, color: C) -> &mut Self {
+        let position = if position.is_nan() {
+            0.0
+        } else {
+            position.clamp(0.0, 1.0) * 100.0
+        };
+        let idx = self
+            .positions()
+            .iter()
+            .rposition(|&p| p <= position)
+            .map_or(0, |idx| idx + 1);
+        self.stops.insert(
+            idx,
+            ColorStop {
+                color: color.into(),
+                position: Some(position),
+            },
+        );
+        self
+    }
+
+    /// Returns the color at t(0~1) along the gradient, interpolated linearly between the stops around it.
+    /// t is clamped, so the colors before the first stop and after the last one are the end colors.
+    /// At a hard edge the later stop wins, and an empty gradient is transparent black.
+    /// ```rust
+    /// use easy_color::{Gradient, RGBA};
+    /// let black:RGBA = (0,0,0,1.0).try_into().unwrap();
+    /// let white:RGBA = (255,255,255,1.0).try_into().unwrap();
+    /// let gradient = Gradient::from_stops([(0.0, black), (1.0, white)]);
+    /// assert_eq!(gradient.sample(0.5).to_string(), "rgba(128,128,128,1.00)");
+    /// assert_eq!(gradient.sample(2.0), white);
+    /// ```
+    pub fn sample(&self, t: f32) -> RGBA {
+        let t = if t.is_nan() {
+            0.0
+        } else {
+            t.clamp(0.0, 1.0) * 100.0
+        };
+        let positions = self.positions();
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first.color, last.color),
+            _ => return RGBA::default(),
+        };
+        if t < positions[0] {
+            return first;
+        }
+        // the last stop at or before t, then the segment to the next stop
+        let idx = positions.iter().rposition(|&p| p <= t).unwrap_or(0);
+        match positions.get(idx + 1) {
+            Some(&end) => {
+                let start = positions[idx];
+                let color = self.stops[idx].color;
+                color.lerp(self.stops[idx + 1].color, (t - start) / (end - start))
+            }
+            None => last,
+        }
+    }
+
+    /// `sample` with t remapped by the easing curve
+    pub fn sample_eased(&self, t: f32, easing: Easing) -> RGBA {
+        self.sample(easing.ease(t))
+    }
+
+    /// Returns n colors sampled evenly from 0 to 1, 1 color is the start of the gradient
+    pub fn colors(&self, n: usize) -> Vec<RGBA> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![self.sample(0.0)],
+            _ => (0..n)
+                .map(|i| self.sample(i as f32 / (n - 1) as f32))
+                .collect(),
+        }
+    }
+
+    /// Returns the position of every stop, the missing ones are filled in like CSS does:
+    /// * the first stop is at 0 and the last one at 100 when they have no position
+    /// * a position before the one of a previous stop is moved to it
+    /// * the stops without a position are spread evenly between their neighbours
+    pub fn positions(&self) -> Vec<f32> {
+        let mut positions: Vec<Option<f32>> = self.stops.iter().map(|s| s.position).collect();
+        let len = positions.len();
+        if let Some(first) = positions.first_mut() {
+            first.get_or_insert(0.0);
+        }
+        if let Some(last) = positions.last_mut() {
+            last.get_or_insert(100.0);
+        }
+        let mut max = f32::MIN;
+        for position in positions.iter_mut().flatten() {
+            max = max.max(*position);
+            *position = max;
+        }
+        let mut start = 0;
+        for idx in 1..len {
+            if let Some(end) = positions[idx] {
+                let from = positions[start].unwrap_or(end);
+                let steps = (idx - start) as f32;
+                for (step, position) in positions[start + 1..idx].iter_mut().enumerate() {
+                    *position = Some(from + (end - from) * (step + 1) as f32 / steps);
+                }
+                start = idx;
+            }
+        }
+        positions.into_iter().flatten().collect()
+    }
+
+    /// Parse a CSS "linear-gradient()" with an optional angle or direction, followed by at least two color stops.
+    /// Each stop is a color `parse` accepts with an optional percentage, like "#2bc48a 50%".
+    pub fn from_css(css: &str) -> Result<Self, ColorError> {
+        let args = strip_prefix_ignore_case(css.trim(), "linear-gradient(")
+            .and_then(|args| args.strip_suffix(')'))
+            .ok_or_else(|| ColorError::FormatErr {
+                input: css.to_string(),
+                reason: format!("'{}' is not a linear-gradient()!", css),
+            })?;
+        let mut args = split_args(args);
+        let first = args[0];
+        let direction = (strip_prefix_ignore_case(first, "to ").is_some()
+            || parse_hue(first).is_some())
+        .then(|| first.to_string());
+        let offset = direction.is_some() as usize;
+        if direction.is_some() {
+            args.remove(0);
+        }
+        if args.len() < 2 {
+            return Err(parse_err(
+                css,
+                (None, "a gradient needs at least 2 color stops".to_string()),
+            ));
+        }
+        let mut gradient = Self {
+            stops: Vec::with_capacity(args.len()),
+            direction,
+        };
+        for (idx, arg) in args.into_iter().enumerate() {
+            let (color, position) = match arg.rsplit_once(char::is_whitespace) {
+                Some((color, position)) if position.ends_with('%') => {
+                    let position = position[..position.len() - 1]
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|v| v.is_finite())
+                        .ok_or_else(|| {
+                            parse_err(
+                                css,
+                                (
+                                    Some(idx + offset),
+                                    format!("invalid position '{}'", position),
+                                ),
+                            )
+                        })?;
+                    (color.trim(), Some(position))
+                }
+                _ => (arg, None),
+            };
+            let color = parse(color).map_err(|err| {
+                parse_err(
+                    css,
+                    (
+                        Some(idx + offset),
+                        format!("stop '{}' is not a color, {}", arg, err),
+                    ),
+                )
+            })?;
+            gradient.push(color, position);
+        }
+        Ok(gradient)
+    }
+
+    /// Returns the CSS "linear-gradient()" with the angle or direction, like "90deg" or "to right",
+    /// the colors are Hex, or RGBA when they are transparent, and every stop has its position.
+    pub fn to_css(&self, direction: &str) -> String {
+        let stops = self
+            .stops
+            .iter()
+            .zip(self.positions())
+            .map(|(stop, position)| {
+                let color = if stop.color.alpha() == 1.0 {
+                    Hex::from(stop.color).to_string()
+                } else {
+                    stop.color.to_string()
+                };
+                format!("{} {}%", color, format_trimmed(position, 2))
+            })
+            .collect::<Vec<_>>();
+        format!("linear-gradient({}, {})", direction, stops.join(", "))
+    }
+}
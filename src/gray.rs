@@ -0,0 +1,117 @@
+use crate::{ColorError, Hex, Standard, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Gray is a single channel neutral color.
+/// It can be parsed from a string in the format "gray(v)" or "gray(p%)", from a u8 or converted from RGB, RGBA and Hex.
+/// * v:u8 - gray level(0~255)
+///
+/// `From` uses the BT.601 luma coefficients, the same ones used by `grayscale`, `from_rgb` accepts any `Standard`.
+/// Converting back to RGB expands the level into all the three channels.
+/// ### example
+/// ```rust
+/// use easy_color::{Gray, IntoHex, RGB, Standard};
+/// let gray:Gray = "gray(50%)".try_into().unwrap();
+/// assert_eq!(gray.to_string(), "gray(128)");
+/// assert_eq!(gray.to_hex().to_string(), "#808080");
+///
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// assert_eq!(Gray::from(rgb).value(), 76);
+/// assert_eq!(Gray::from_rgb(rgb, Standard::Bt709).value(), 54);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Gray {
+    pub(crate) v: u8,
+}
+
+impl TryFrom<&str> for Gray {
+    type Error = ColorError;
+    fn try_from(gray_str: &str) -> Result<Self, Self::Error> {
+        let color = gray_str.trim().to_lowercase();
+        if color.starts_with("gray(") && color.ends_with(')') {
+            let v = color.replace("gray(", "").replace(')', "");
+            let v = v.trim();
+            let v = match v.strip_suffix('%') {
+                Some(p) => p
+                    .trim()
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|p| (0.0..=100.0).contains(p))
+                    .map(|p| (p * 2.55).round() as u8),
+                None => v.parse::<u8>().ok(),
+            };
+            if let Some(v) = v {
+                return Ok(Self { v });
+            }
+        }
+        Err(ColorError::FormatErr {
+            input: gray_str.to_string(),
+            reason: format!("Gray: {} format error!", gray_str),
+        })
+    }
+}
+
+impl FromStr for Gray {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl From<u8> for Gray {
+    fn from(v: u8) -> Self {
+        Self { v }
+    }
+}
+
+impl From<Hex> for Gray {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for Gray {
+    fn from(rgb: RGB) -> Self {
+        Self::from_rgb(rgb, Standard::Bt601)
+    }
+}
+
+impl From<RGBA> for Gray {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for Gray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "gray({})", self.v)
+    }
+}
+
+impl Gray {
+    /// Convert a color into gray with the luma coefficients of the given standard.
+    pub fn from_rgb(rgb: impl Into<RGB>, standard: Standard) -> Self {
+        let RGB { r, g, b } = rgb.into();
+        let (kr, kb) = standard.coefficients();
+        let v = kr * r as f32 + (1.0 - kr - kb) * g as f32 + kb * b as f32;
+        Self {
+            v: v.round().clamp(0.0, 255.0) as u8,
+        }
+    }
+
+    pub fn value(&self) -> u8 {
+        self.v
+    }
+
+    pub fn set_value(&mut self, value: u8) -> &mut Self {
+        self.v = value;
+        self
+    }
+
+    /// Returns the gray level as a fraction(0~1)
+    pub fn level(&self) -> f32 {
+        self.v as f32 / 255.0
+    }
+}
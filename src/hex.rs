@@ -1,6 +1,12 @@
-use crate::common::process_hex;
-use crate::{ColorError, CMYK, HSL, HSLA, HSV, RGB, RGBA};
-use std::fmt::{Display, Formatter};
+use crate::common::{process_hex, process_hex16, strip_prefix_ignore_case, unit_to_u8};
+use crate::named::named_color;
+use crate::{
+    AdobeRGB, Ansi256, Cam16, ColorError, Gray, HSLuv, Kelvin, OkLch, ParseOptions, ProPhotoRGB,
+    RGBAf, RGBf, Rec2020, CMYK, CMYKA, HSI, HSL, HSLA, HSV, HWB, LCH, LMS, P3, RGB, RGB16, RGB565,
+    RGBA, XYZ, YIQ,
+};
+use std::fmt::{Display, Formatter, LowerHex, UpperHex};
+use std::str::FromStr;
 /// Parse a hexadecimal string into a `Hex` object, which can be converted into `RGB`, `RGBA`, `HSL`, `HSLA`, `HSV`, and `CMYK` objects.
 ///  ### example
 ///  ```rust
@@ -8,6 +14,11 @@ use std::fmt::{Display, Formatter};
 ///  let _hex:Hex = "#FAC".try_into().unwrap();
 ///  let _hex:Hex = "#FFDFAC".try_into().unwrap();
 ///  let _hex:Hex = "#FFDFACDC".try_into().unwrap(); // hex with transparency
+///  let _hex:Hex = "#FA08".try_into().unwrap(); // shorthand with transparency, the same as "#FFAA0088"
+///  let _hex:Hex = "#2B2BC4C48A8A".try_into().unwrap(); // 16 bit channels are rounded to 8 bit, the same as "#2BC48A"
+///  let _hex:Hex = "#2B2BC4C48A8A8000".try_into().unwrap(); // 16 bit channels with transparency
+///  let _hex:Hex = "0xFFDFAC".try_into().unwrap(); // the '#' can be replaced by '0x' or omitted
+///  let _hex:Hex = "#FFDFAC".parse().unwrap(); // FromStr is implemented too
 ///  ```
 ///
 /// Convert hex to other types, such as:
@@ -20,10 +31,10 @@ use std::fmt::{Display, Formatter};
 /// rgba.set_alpha(0.5);
 /// let hex:Hex = rgba.into();
 /// let hex_str = hex.to_hex_alpha();
-/// assert_eq!(hex_str, "#FFDFAC7F");
+/// assert_eq!(hex_str, "#FFDFAC80");
 ///
 /// let hex_str2 = hex.to_alpha_hex();
-/// assert_eq!(hex_str2, "#7FFFDFAC");
+/// assert_eq!(hex_str2, "#80FFDFAC");
 ///
 /// ```
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
@@ -31,43 +42,51 @@ pub struct Hex {
     pub(crate) rgba: (u8, u8, u8, f32),
 }
 
+/// The prefix of `Hex::format`
+/// * Hash - "#2BC48A", for CSS
+/// * ZeroX - "0x2BC48A", for C and Rust literals
+/// * None - "2BC48A"
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum HexPrefix {
+    #[default]
+    Hash,
+    ZeroX,
+    None,
+}
+
+/// The case of the digits of `Hex::format`
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum HexCase {
+    #[default]
+    Upper,
+    Lower,
+}
+
+/// Where `Hex::format` writes the alpha byte
+/// * Auto - last and only when the color is transparent, like `to_string()`
+/// * Last - always last, like `to_hex_alpha()`
+/// * First - always first, like `to_alpha_hex()`
+/// * Omit - never, only the rgb digits are written
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum AlphaPosition {
+    #[default]
+    Auto,
+    Last,
+    First,
+    Omit,
+}
+
 impl TryFrom<&str> for Hex {
     type Error = ColorError;
     fn try_from(hex_str: &str) -> Result<Self, Self::Error> {
-        let color = hex_str.trim().to_lowercase();
-        if color.starts_with('#') {
-            let tmp = color.replace('#', "");
-            let len = tmp.len();
-            if len == 3 {
-                let val = process_hex(tmp.as_str(), 1);
-                if val.len() == 3 {
-                    return Ok(Self {
-                        rgba: (val[0], val[1], val[2], 1.0),
-                    });
-                }
-            }
-            if len == 6 {
-                let val = process_hex(tmp.as_str(), 2);
-                if val.len() == 3 {
-                    return Ok(Self {
-                        rgba: (val[0], val[1], val[2], 1.0),
-                    });
-                }
-            }
-
-            if len == 8 {
-                let val = process_hex(tmp.as_str(), 2);
-                if val.len() == 4 {
-                    return Ok(Self {
-                        rgba: (val[0], val[1], val[2], val[3] as f32 / 255.0),
-                    });
-                }
-            }
-        }
-        Err(ColorError::FormatErr(format!(
-            "'{}' format error!",
-            hex_str
-        )))
+        Self::parse_with(hex_str, &ParseOptions::default())
+    }
+}
+
+impl FromStr for Hex {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
     }
 }
 
@@ -110,25 +129,247 @@ impl From<CMYK> for Hex {
     }
 }
 
+impl From<HWB> for Hex {
+    fn from(hwb: HWB) -> Self {
+        let rgb: RGB = hwb.into();
+        rgb.into()
+    }
+}
+
+impl From<LCH> for Hex {
+    fn from(lch: LCH) -> Self {
+        let rgb: RGB = lch.into();
+        rgb.into()
+    }
+}
+
+impl From<OkLch> for Hex {
+    fn from(oklch: OkLch) -> Self {
+        let rgba: RGBA = oklch.into();
+        rgba.into()
+    }
+}
+
+impl From<XYZ> for Hex {
+    fn from(xyz: XYZ) -> Self {
+        let rgb: RGB = xyz.into();
+        rgb.into()
+    }
+}
+
+impl From<YIQ> for Hex {
+    fn from(yiq: YIQ) -> Self {
+        let rgb: RGB = yiq.into();
+        rgb.into()
+    }
+}
+
+impl From<HSI> for Hex {
+    fn from(hsi: HSI) -> Self {
+        let rgb: RGB = hsi.into();
+        rgb.into()
+    }
+}
+
+impl From<P3> for Hex {
+    fn from(p3: P3) -> Self {
+        let rgb: RGB = p3.into();
+        rgb.into()
+    }
+}
+
+impl From<Rec2020> for Hex {
+    fn from(rec2020: Rec2020) -> Self {
+        let rgb: RGB = rec2020.into();
+        rgb.into()
+    }
+}
+
+impl From<AdobeRGB> for Hex {
+    fn from(adobe: AdobeRGB) -> Self {
+        let rgb: RGB = adobe.into();
+        rgb.into()
+    }
+}
+
+impl From<ProPhotoRGB> for Hex {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let rgb: RGB = prophoto.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLuv> for Hex {
+    fn from(hsluv: HSLuv) -> Self {
+        let rgb: RGB = hsluv.into();
+        rgb.into()
+    }
+}
+
+impl From<Cam16> for Hex {
+    fn from(cam16: Cam16) -> Self {
+        let rgb: RGB = cam16.into();
+        rgb.into()
+    }
+}
+
+impl From<LMS> for Hex {
+    fn from(lms: LMS) -> Self {
+        let rgb: RGB = lms.into();
+        rgb.into()
+    }
+}
+
+impl From<Gray> for Hex {
+    fn from(gray: Gray) -> Self {
+        let rgb: RGB = gray.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB16> for Hex {
+    fn from(rgb16: RGB16) -> Self {
+        let rgb: RGB = rgb16.into();
+        rgb.into()
+    }
+}
+
+impl From<RGBf> for Hex {
+    fn from(rgbf: RGBf) -> Self {
+        let rgb: RGB = rgbf.into();
+        rgb.into()
+    }
+}
+
+impl From<RGBAf> for Hex {
+    fn from(rgbaf: RGBAf) -> Self {
+        let rgba: RGBA = rgbaf.into();
+        rgba.into()
+    }
+}
+
+impl From<RGB565> for Hex {
+    fn from(rgb565: RGB565) -> Self {
+        let rgb: RGB = rgb565.into();
+        rgb.into()
+    }
+}
+
+impl From<Ansi256> for Hex {
+    fn from(ansi: Ansi256) -> Self {
+        let rgb: RGB = ansi.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYKA> for Hex {
+    fn from(cmyka: CMYKA) -> Self {
+        Self {
+            rgba: cmyka.to_rgba_tuple(),
+        }
+    }
+}
+
+impl From<Kelvin> for Hex {
+    fn from(kelvin: Kelvin) -> Self {
+        let rgb: RGB = kelvin.into();
+        rgb.into()
+    }
+}
+
+impl TryFrom<u32> for Hex {
+    type Error = ColorError;
+    /// Create from a packed 0xRRGGBB value, a larger value is an error since it is ambiguous,
+    /// use `RGBA::from_u32` or `RGBA::from_argb_u32` for packed values with alpha.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value > 0xFF_FFFF {
+            return Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "Hex: {:#X} value error, value must between 0~0xFFFFFF!",
+                    value
+                ),
+            });
+        }
+        Ok(RGB::from_u32(value).into())
+    }
+}
+
+/// The alpha is rounded to the nearest byte, 0.5 is written as "80"
 impl Display for Hex {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let (r, g, b, a) = self.rgba;
         if self.rgba.3 != 1.0 {
-            write!(f, "#{:02X}{:02X}{:02X}{:02X}", r, g, b, (a * 255.0) as u8)
+            write!(f, "#{:02X}{:02X}{:02X}{:02X}", r, g, b, unit_to_u8(a))
         } else {
             write!(f, "#{:02X}{:02X}{:02X}", r, g, b)
         }
     }
 }
 
+/// `{:x}` and `{:X}` write the digits of `to_string()` without the '#', so the alpha is only written when the color is transparent
+impl LowerHex for Hex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let digits = self.to_string()[1..].to_lowercase();
+        f.pad_integral(true, "0x", &digits)
+    }
+}
+
+impl UpperHex for Hex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(true, "0x", &self.to_string()[1..])
+    }
+}
+
 impl Hex {
+    /// Parse a string like `TryFrom<&str>` does, with the given options.
+    /// `TryFrom<&str>` is the same as this with `ParseOptions::default()`.
+    pub fn parse_with(hex_str: &str, options: &ParseOptions) -> Result<Self, ColorError> {
+        let Some(color) = options.normalize(hex_str) else {
+            return Err(ColorError::FormatErr {
+                input: hex_str.to_string(),
+                reason: format!("'{}' format error, the syntax is not exact!", hex_str),
+            });
+        };
+        if options.allow_named_colors && color.eq_ignore_ascii_case("transparent") {
+            return Ok(RGBA::TRANSPARENT.into());
+        }
+        if let Some((r, g, b)) = named_color(color).filter(|_| options.allow_named_colors) {
+            return Ok(Self {
+                rgba: (r, g, b, 1.0),
+            });
+        }
+        // the body can be prefixed with '#' or '0x', or have no prefix at all like in config files,
+        // only '#' is the exact syntax
+        let tmp = match color.strip_prefix('#') {
+            Some(tmp) => Some(tmp),
+            None if options.require_exact_syntax => None,
+            None => Some(strip_prefix_ignore_case(color, "0x").unwrap_or(color)),
+        };
+        if let Some([r, g, b, a]) = tmp.and_then(process_hex) {
+            return Ok(Self {
+                rgba: (r, g, b, a as f32 / 255.0),
+            });
+        }
+        if let Some([r, g, b, a]) = tmp.and_then(process_hex16) {
+            let RGB { r, g, b } = RGB16 { r, g, b }.into();
+            return Ok(Self {
+                rgba: (r, g, b, a as f32 / 65535.0),
+            });
+        }
+        Err(ColorError::FormatErr {
+            input: hex_str.to_string(),
+            reason: format!("'{}' format error!", hex_str),
+        })
+    }
+
     /// Returns a Hex string with transparency, where the last two characters represent the transparency in hexadecimal.
     /// ```rust
     /// use easy_color::{RGBA, Hex};
     /// let rgba:RGBA = "rgba(255,125,55, 0.85)".try_into().unwrap();
     /// let hex:Hex = rgba.into();
-    /// assert_eq!(hex.to_hex_alpha(), "#FF7D37D8");
-    /// assert_eq!(hex.to_string(), "#FF7D37D8"); //Returns a Hex string with transparency, where the last two characters represent the transparency in hexadecimal.
+    /// assert_eq!(hex.to_hex_alpha(), "#FF7D37D9");
+    /// assert_eq!(hex.to_string(), "#FF7D37D9"); //Returns a Hex string with transparency, where the last two characters represent the transparency in hexadecimal.
     ///
     /// // when alpha value is 1
     /// let rgba:RGBA = "rgba(255,125,55, 1)".try_into().unwrap();
@@ -137,8 +378,7 @@ impl Hex {
     /// assert_eq!(hex.to_string(), "#FF7D37");
     /// ```
     pub fn to_hex_alpha(&self) -> String {
-        let (r, g, b, a) = self.rgba;
-        format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, (a * 255.0) as u8)
+        self.format(HexPrefix::Hash, HexCase::Upper, AlphaPosition::Last)
     }
 
     /// Returns a Hex string with transparency, where the last two characters represent the transparency in hexadecimal.
@@ -147,11 +387,88 @@ impl Hex {
     /// use easy_color::{RGBA, Hex};
     /// let rgba:RGBA = "rgba(255,125,55, 0.85)".try_into().unwrap();
     /// let hex:Hex = rgba.into();
-    /// assert_eq!(hex.to_alpha_hex(), "#D8FF7D37");
+    /// assert_eq!(hex.to_alpha_hex(), "#D9FF7D37");
     /// ```
     pub fn to_alpha_hex(&self) -> String {
+        self.format(HexPrefix::Hash, HexCase::Upper, AlphaPosition::First)
+    }
+
+    /// Composite the color over the opaque background, see `RGBA::flatten_onto`
+    pub fn flatten_onto(&self, background: impl Into<RGB>) -> RGB {
+        RGBA::from(*self).flatten_onto(background)
+    }
+
+    /// Returns the Hex string with the given prefix, case and position of the alpha byte, the alpha is rounded to the nearest byte.
+    /// ```rust
+    /// use easy_color::{AlphaPosition, Hex, HexCase, HexPrefix};
+    /// let hex:Hex = "#2BC48A".try_into().unwrap();
+    /// assert_eq!(hex.format(HexPrefix::ZeroX, HexCase::Upper, AlphaPosition::Auto), "0x2BC48A");
+    /// assert_eq!(hex.format(HexPrefix::None, HexCase::Lower, AlphaPosition::Last), "2bc48aff");
+    ///
+    /// let hex:Hex = "#2BC48A80".try_into().unwrap();
+    /// assert_eq!(hex.format(HexPrefix::ZeroX, HexCase::Lower, AlphaPosition::First), "0x802bc48a");
+    /// assert_eq!(hex.format(HexPrefix::Hash, HexCase::Upper, AlphaPosition::Omit), "#2BC48A");
+    /// ```
+    pub fn format(
+        &self,
+        prefix: HexPrefix,
+        case: HexCase,
+        alpha_position: AlphaPosition,
+    ) -> String {
+        let (r, g, b, a) = self.rgba;
+        let a = unit_to_u8(a);
+        let bytes = match alpha_position {
+            AlphaPosition::Auto => self.bytes(),
+            AlphaPosition::Last => vec![r, g, b, a],
+            AlphaPosition::First => vec![a, r, g, b],
+            AlphaPosition::Omit => vec![r, g, b],
+        };
+        let prefix = match prefix {
+            HexPrefix::Hash => "#",
+            HexPrefix::ZeroX => "0x",
+            HexPrefix::None => "",
+        };
+        bytes.iter().fold(prefix.to_string(), |s, v| match case {
+            HexCase::Upper => format!("{}{:02X}", s, v),
+            HexCase::Lower => format!("{}{:02x}", s, v),
+        })
+    }
+
+    /// The bytes `to_string()` writes, the alpha is only included when the color is transparent
+    fn bytes(&self) -> Vec<u8> {
         let (r, g, b, a) = self.rgba;
-        format!("#{:02X}{:02X}{:02X}{:02X}", (a * 255.0) as u8, r, g, b)
+        if a != 1.0 {
+            vec![r, g, b, unit_to_u8(a)]
+        } else {
+            vec![r, g, b]
+        }
+    }
+
+    /// Returns true when every byte of `to_string()` has two equal digits, like "#FFAA00" or "#FFAA0088",
+    /// so the color can be written with the shorthand without losing anything.
+    pub fn is_compressible(&self) -> bool {
+        self.bytes().iter().all(|v| v % 17 == 0)
+    }
+
+    /// Returns the shorthand string like "#FA0" or "#FA08" when the color is compressible,
+    /// otherwise the same string as `to_string()`.
+    /// ```rust
+    /// use easy_color::Hex;
+    /// let hex:Hex = "#FFAA0088".try_into().unwrap();
+    /// assert!(hex.is_compressible());
+    /// assert_eq!(hex.to_short_string(), "#FA08");
+    ///
+    /// let hex:Hex = "#FFAA0080".try_into().unwrap();
+    /// assert!(!hex.is_compressible());
+    /// assert_eq!(hex.to_short_string(), "#FFAA0080");
+    /// ```
+    pub fn to_short_string(&self) -> String {
+        if !self.is_compressible() {
+            return self.to_string();
+        }
+        self.bytes()
+            .iter()
+            .fold(String::from("#"), |s, v| format!("{}{:X}", s, v / 17))
     }
 
     pub fn random() -> Self {
@@ -0,0 +1,149 @@
+use crate::common::rgb_to_hsi;
+use crate::{ColorError, Hex, RGB, RGBA};
+use rand::Rng;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// HSI can be parsed from a string in the format "hsi(h, s%, i%)" or from a tuple (h,s,i).
+/// * h:u32 - Hue(0~360)
+/// * s:u32 - saturation(0~100)
+/// * i:u32 - intensity(0~100), the average of the red, green and blue channels
+///
+/// Unlike HSL and HSV, the saturation is `1 - min(r,g,b) / i`, which is the definition used by computer vision code.
+/// Some HSI values are out of the RGB gamut, their channels are clamped when converted back to RGB.
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, HSI};
+/// let mut hsi:HSI = "hsi(120,50%,40%)".try_into().unwrap();
+/// let rgb:RGB = hsi.into();
+/// assert_eq!(rgb.to_string(), "rgb(51,204,51)");
+///
+/// hsi.set_hue(240);
+/// let rgb:RGB = hsi.into();
+/// assert_eq!(rgb.to_string(), "rgb(51,51,204)");
+///
+/// let rgb:RGB = (255,255,0).try_into().unwrap();
+/// let hsi:HSI = rgb.into();
+/// assert_eq!(hsi.to_string(), "hsi(60,100%,67%)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HSI {
+    pub(crate) h: u32,
+    pub(crate) s: u32,
+    pub(crate) i: u32,
+}
+
+impl TryFrom<&str> for HSI {
+    type Error = ColorError;
+    fn try_from(hsi_str: &str) -> Result<Self, Self::Error> {
+        let mut color = hsi_str.trim().to_lowercase();
+        if color.starts_with("hsi(") && color.ends_with(')') {
+            color = color.replace("hsi(", "").replace(')', "");
+            let tmp = color.split(',').collect::<Vec<_>>();
+            if tmp.len() == 3 {
+                let val = tmp
+                    .iter()
+                    .map(|s| s.trim().trim_end_matches('%').parse::<u32>())
+                    .filter_map(|v| v.ok())
+                    .collect::<Vec<_>>();
+                if val.len() == 3 {
+                    return (val[0], val[1], val[2]).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr {
+            input: hsi_str.to_string(),
+            reason: format!("HSI: {} format error!", hsi_str),
+        })
+    }
+}
+
+impl FromStr for HSI {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(u32, u32, u32)> for HSI {
+    type Error = ColorError;
+    fn try_from(value: (u32, u32, u32)) -> Result<Self, Self::Error> {
+        if !(0..=360).contains(&value.0)
+            || !(0..=100).contains(&value.1)
+            || !(0..=100).contains(&value.2)
+        {
+            Err(ColorError::ValueErr { input: format!("{:?}", value), reason: format!("HSI: args ({},{},{}) value error, first value must between 0~360, others must between 0~100!", value.0, value.1, value.2) })
+        } else {
+            Ok(Self {
+                h: value.0,
+                s: value.1,
+                i: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for HSI {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for HSI {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (h, s, i) = rgb_to_hsi(r, g, b);
+        Self { h, s, i }
+    }
+}
+
+impl From<RGBA> for HSI {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for HSI {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "hsi({},{}%,{}%)", self.h, self.s, self.i)
+    }
+}
+
+impl HSI {
+    pub fn hue(&self) -> u32 {
+        self.h
+    }
+
+    pub fn set_hue(&mut self, hue: u32) -> &mut Self {
+        self.h = hue.min(360);
+        self
+    }
+
+    pub fn saturation(&self) -> u32 {
+        self.s
+    }
+
+    pub fn set_saturation(&mut self, saturation: u32) -> &mut Self {
+        self.s = saturation.min(100);
+        self
+    }
+
+    pub fn intensity(&self) -> u32 {
+        self.i
+    }
+
+    pub fn set_intensity(&mut self, intensity: u32) -> &mut Self {
+        self.i = intensity.min(100);
+        self
+    }
+
+    pub fn random() -> Self {
+        let mut rng = rand::thread_rng();
+        let h = rng.gen_range(0..=360) as u32;
+        let s = rng.gen_range(0..=100) as u32;
+        let i = rng.gen_range(0..=100) as u32;
+        Self { h, s, i }
+    }
+}
@@ -1,12 +1,20 @@
-use crate::common::rgb_to_hsl;
-use crate::{ColorError, Hex, CMYK, HSLA, HSV, RGB, RGBA};
+use crate::common::{
+    calc_rgb_to_hsl, color_function_args, hue_distance, parse_err, parse_hue_channels, rgb_to_hsl,
+    split_color_args, wrap_hue,
+};
+use crate::{ColorError, Hex, OkLch, ParseOptions, CMYK, HSLA, HSV, HWB, LCH, RGB, RGB16, RGBA};
 use rand::Rng;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 /// HSL can be parsed from a string in the format "hsl(h, s%, l%)" or from a tuple (h,s,l).
-/// * h:u32 - Hue(0~360)
+/// * h:u32 - Hue(0~360), in the string form it can have a `deg`, `rad`, `grad` or `turn` unit
 /// * s:u32 - saturation(0~100)
 /// * l:u32 - lightness(0~100)
+///
+/// In the string form the hue never takes `%`, the saturation and lightness accept an optional `%`,
+/// so "hsl(157, 64, 47)" is the same as "hsl(157, 64%, 47%)", and "hsl(157%, 64%, 47%)" is an error.
+/// `to_string()` writes the legacy syntax, `to_css_modern()` of `ToCss` writes "hsl(157deg 64% 47%)".
 /// ### example
 /// ```rust
 /// use easy_color::{RGB, HSL};
@@ -27,25 +35,14 @@ pub struct HSL {
 impl TryFrom<&str> for HSL {
     type Error = ColorError;
     fn try_from(hsl_str: &str) -> Result<Self, Self::Error> {
-        let mut color = hsl_str.trim().to_lowercase();
-        if color.starts_with("hsl(") && color.ends_with(')') {
-            color = color.replace("hsl(", "").replace(')', "");
-            let tmp = color.split(',').collect::<Vec<_>>();
-            if tmp.len() == 3 {
-                let val = tmp
-                    .iter()
-                    .map(|s| s.trim().trim_end_matches('%').parse::<u32>())
-                    .filter_map(|v| v.ok())
-                    .collect::<Vec<_>>();
-                if val.len() == 3 {
-                    return (val[0], val[1], val[2]).try_into();
-                }
-            }
-        }
-        Err(ColorError::FormatErr(format!(
-            "HSL: {} format error!",
-            hsl_str
-        )))
+        Self::parse_with(hsl_str, &ParseOptions::default())
+    }
+}
+
+impl FromStr for HSL {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
     }
 }
 
@@ -56,7 +53,7 @@ impl TryFrom<(u32, u32, u32)> for HSL {
             || !(0..=100).contains(&value.1)
             || !(0..=100).contains(&value.2)
         {
-            Err(ColorError::ValueErr(format!("HSL: args ({},{},{}) value error, first value must between 0~360, others must between 0~100!", value.0, value.1, value.2)))
+            Err(ColorError::ValueErr { input: format!("{:?}", value), reason: format!("HSL: args ({},{},{}) value error, first value must between 0~360, others must between 0~100!", value.0, value.1, value.2) })
         } else {
             Ok(Self {
                 h: value.0,
@@ -90,6 +87,10 @@ impl From<RGBA> for HSL {
 
 impl From<HSLA> for HSL {
     fn from(hsla: HSLA) -> Self {
+        // an opaque color has nothing to composite, so it skips the rounding through RGB
+        if hsla.a == 1.0 {
+            return hsla.hsl;
+        }
         let rgba: RGBA = hsla.into();
         rgba.into()
     }
@@ -109,6 +110,47 @@ impl From<CMYK> for HSL {
     }
 }
 
+impl From<HWB> for HSL {
+    fn from(hwb: HWB) -> Self {
+        let rgb: RGB = hwb.into();
+        rgb.into()
+    }
+}
+
+impl From<LCH> for HSL {
+    fn from(lch: LCH) -> Self {
+        let rgb: RGB = lch.into();
+        rgb.into()
+    }
+}
+
+impl From<OkLch> for HSL {
+    fn from(oklch: OkLch) -> Self {
+        let rgb: RGB = oklch.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB16> for HSL {
+    fn from(rgb16: RGB16) -> Self {
+        let (r, g, b) = rgb16.to_unit();
+        let (h, s, l) = calc_rgb_to_hsl(r, g, b);
+        Self { h, s, l }
+    }
+}
+
+impl From<HSL> for (u32, u32, u32) {
+    fn from(hsl: HSL) -> Self {
+        hsl.as_tuple()
+    }
+}
+
+impl From<HSL> for [u32; 3] {
+    fn from(hsl: HSL) -> Self {
+        hsl.to_array()
+    }
+}
+
 impl Display for HSL {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "hsl({},{}%,{}%)", self.h, self.s, self.l)
@@ -116,6 +158,47 @@ impl Display for HSL {
 }
 
 impl HSL {
+    /// Parse a string like `TryFrom<&str>` does, with the given options.
+    /// `TryFrom<&str>` is the same as this with `ParseOptions::default()`.
+    pub fn parse_with(hsl_str: &str, options: &ParseOptions) -> Result<Self, ColorError> {
+        let Some(color) = options.normalize(hsl_str) else {
+            return Err(ColorError::FormatErr {
+                input: hsl_str.to_string(),
+                reason: format!("'{}' format error, the syntax is not exact!", hsl_str),
+            });
+        };
+        if let Some(args) = color_function_args(color, &["hsl", "hsla"]) {
+            let (tmp, alpha) = split_color_args(args).map_err(|e| parse_err(hsl_str, e))?;
+            if alpha.is_some() {
+                return Err(parse_err(
+                    hsl_str,
+                    (
+                        Some(3),
+                        "HSL has no alpha, parse it into HSLA instead".to_string(),
+                    ),
+                ));
+            }
+            let (h, s, l) = parse_hue_channels(&tmp, options).map_err(|e| parse_err(hsl_str, e))?;
+            return Ok(Self { h, s, l });
+        }
+        Err(ColorError::FormatErr {
+            input: hsl_str.to_string(),
+            reason: format!("HSL: {} format error!", hsl_str),
+        })
+    }
+
+    /// Create from a hue that is wrapped into 0~360, like CSS does, so 480 is 120 and -120 is 240.
+    /// The saturation and lightness must still be between 0~100.
+    /// ```rust
+    /// use easy_color::HSL;
+    /// let color = HSL::new_wrapping(-120, 64, 47).unwrap();
+    /// assert_eq!(color.hue(), 240);
+    /// assert!(HSL::new_wrapping(480, 101, 47).is_err());
+    /// ```
+    pub fn new_wrapping(hue: i32, saturation: u32, lightness: u32) -> Result<Self, ColorError> {
+        (wrap_hue(hue), saturation, lightness).try_into()
+    }
+
     pub fn hue(&self) -> u32 {
         self.h
     }
@@ -143,6 +226,20 @@ impl HSL {
         self
     }
 
+    /// Returns true if no value differs by more than the tolerance, the hue is compared the short way around 0/360
+    /// ```rust
+    /// use easy_color::HSL;
+    /// let hsl:HSL = (359,64,47).try_into().unwrap();
+    /// assert!(hsl.approx_eq(HSL::try_from((1,65,47)).unwrap(), 2));
+    /// assert!(!hsl.approx_eq(HSL::try_from((1,65,47)).unwrap(), 1));
+    /// ```
+    pub fn approx_eq(&self, other: impl Into<Self>, tolerance: u32) -> bool {
+        let other: HSL = other.into();
+        hue_distance(self.h, other.h) <= tolerance
+            && self.s.abs_diff(other.s) <= tolerance
+            && self.l.abs_diff(other.l) <= tolerance
+    }
+
     /// Darkens the color by the given ratio.
     ///
     /// # Arguments
@@ -158,7 +255,7 @@ impl HSL {
     /// assert_eq!(color.to_string(), "hsl(120,100%,40%)");
     /// ```
     pub fn darken(&mut self, ratio: f32) -> &mut Self {
-        self.l = (self.l - (self.l as f32 * ratio) as u32).max(0).min(100);
+        self.l = (self.l - (self.l as f32 * ratio) as u32).min(100);
         self
     }
 
@@ -177,7 +274,7 @@ impl HSL {
     /// assert_eq!(color.to_string(), "hsl(120,100%,60%)");
     /// ```
     pub fn lighten(&mut self, ratio: f32) -> &mut Self {
-        self.l = (self.l + (self.l as f32 * ratio) as u32).max(0).min(100);
+        self.l = (self.l + (self.l as f32 * ratio) as u32).min(100);
         self
     }
 
@@ -202,6 +299,23 @@ impl HSL {
         self
     }
 
+    /// Returns (h, s, l), `try_from` of the tuple returns the same color.
+    /// ```rust
+    /// use easy_color::HSL;
+    /// let hsl:HSL = "hsl(157,64%,47%)".try_into().unwrap();
+    /// let (h, s, l) = hsl.as_tuple();
+    /// assert_eq!((h, s, l), (157, 64, 47));
+    /// assert_eq!(hsl.to_array(), [157, 64, 47]);
+    /// ```
+    pub fn as_tuple(&self) -> (u32, u32, u32) {
+        (self.h, self.s, self.l)
+    }
+
+    /// Returns [h, s, l]
+    pub fn to_array(&self) -> [u32; 3] {
+        [self.h, self.s, self.l]
+    }
+
     pub fn random() -> Self {
         let mut rng = rand::thread_rng();
         let h = rng.gen_range(0..=360) as u32;
@@ -1,13 +1,20 @@
-use crate::common::{rgb_to_hsl, rgba_to_hsla};
-use crate::{ColorError, Hex, CMYK, HSL, HSV, RGB, RGBA};
+use crate::common::{
+    color_function_args, format_alpha, parse_alpha_component, parse_err, parse_hue_channels,
+    rgb_to_hsl, rgba_to_hsla, split_color_args, wrap_hue,
+};
+use crate::{ColorError, Hex, OkLch, ParseOptions, CMYK, CMYKA, HSL, HSV, HWB, LCH, RGB, RGBA};
 use std::fmt::{Display, Formatter};
 use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
 
 /// HSLA can be parsed from a string in the format "hsla(h, s%, l%, a)" or from a tuple (h,s,l,a).
 /// * h:u32 - Hue(0~360)
 /// * s:u32 - saturation(0~100)
 /// * l:u32 - lightness(0~100)
 /// * a:f32 - alpha(0~1)
+///
+/// Like HSL, the hue never takes `%` and the `%` of the saturation and lightness is optional.
+/// `to_css_modern()` of `ToCss` writes "hsl(157deg 64% 47% / 0.85)", without the alpha when it is 1.
 /// ### example
 /// ```rust
 /// use easy_color::{RGBA, HSLA};
@@ -28,31 +35,14 @@ pub struct HSLA {
 impl TryFrom<&str> for HSLA {
     type Error = ColorError;
     fn try_from(hsla_str: &str) -> Result<Self, Self::Error> {
-        let mut color = hsla_str.trim().to_lowercase();
-        if color.starts_with("hsla(") && color.ends_with(')') {
-            let mut val = vec![];
-            let mut alpha = None;
-            color = color.replace("hsla(", "").replace(')', "");
-            let tmp = color.split(',').collect::<Vec<_>>();
-            if tmp.len() == 4 {
-                for (idx, s) in tmp.iter().enumerate() {
-                    if idx == 3 {
-                        alpha = s.trim().parse::<f32>().ok();
-                    } else if let Ok(v) = s.trim().trim_end_matches('%').parse::<u32>() {
-                        val.push(v);
-                    }
-                }
-            }
-            if let Some(alpha) = alpha {
-                if val.len() == 3 {
-                    return (val[0], val[1], val[2], alpha).try_into();
-                }
-            }
-        }
-        Err(ColorError::FormatErr(format!(
-            "HSLA: {} format error!",
-            hsla_str
-        )))
+        Self::parse_with(hsla_str, &ParseOptions::default())
+    }
+}
+
+impl FromStr for HSLA {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
     }
 }
 
@@ -64,7 +54,7 @@ impl TryFrom<(u32, u32, u32, f32)> for HSLA {
             || !(0..=100).contains(&value.2)
             || !(0.0..=1.0).contains(&value.3)
         {
-            Err(ColorError::ValueErr(format!("HSLA: args ({},{},{},{}) value error. first value must between 0~360, second and third must between 0~100, and last one must between 0~1", value.0, value.1, value.2, value.3)))
+            Err(ColorError::ValueErr { input: format!("{:?}", value), reason: format!("HSLA: args ({},{},{},{}) value error. first value must between 0~360, second and third must between 0~100, and last one must between 0~1", value.0, value.1, value.2, value.3) })
         } else {
             let hsl = HSL {
                 h: value.0,
@@ -134,23 +124,114 @@ impl DerefMut for HSLA {
     }
 }
 
+impl From<HWB> for HSLA {
+    fn from(hwb: HWB) -> Self {
+        let rgb: RGB = hwb.into();
+        rgb.into()
+    }
+}
+
+impl From<LCH> for HSLA {
+    fn from(lch: LCH) -> Self {
+        let rgb: RGB = lch.into();
+        rgb.into()
+    }
+}
+
+impl From<OkLch> for HSLA {
+    fn from(oklch: OkLch) -> Self {
+        let rgba: RGBA = oklch.into();
+        rgba.into()
+    }
+}
+
+impl From<CMYKA> for HSLA {
+    fn from(cmyka: CMYKA) -> Self {
+        let rgba: RGBA = cmyka.into();
+        rgba.into()
+    }
+}
+
+impl From<HSLA> for (u32, u32, u32, f32) {
+    fn from(hsla: HSLA) -> Self {
+        hsla.as_tuple()
+    }
+}
+
 impl Display for HSLA {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let HSL { h, s, l } = self.hsl;
-        write!(f, "hsla({},{}%,{}%,{:.2})", h, s, l, self.a)
+        write!(f, "hsla({},{}%,{}%,{})", h, s, l, format_alpha(f, self.a))
     }
 }
 impl HSLA {
+    /// Parse a string like `TryFrom<&str>` does, with the given options.
+    /// `TryFrom<&str>` is the same as this with `ParseOptions::default()`.
+    pub fn parse_with(hsla_str: &str, options: &ParseOptions) -> Result<Self, ColorError> {
+        let Some(color) = options.normalize(hsla_str) else {
+            return Err(ColorError::FormatErr {
+                input: hsla_str.to_string(),
+                reason: format!("'{}' format error, the syntax is not exact!", hsla_str),
+            });
+        };
+        if options.allow_named_colors && color.eq_ignore_ascii_case("transparent") {
+            return Ok(Self::TRANSPARENT);
+        }
+        if let Some(args) = color_function_args(color, &["hsl", "hsla"]) {
+            let (h, s, l, a) = split_color_args(args)
+                .and_then(|(tmp, alpha)| {
+                    let (h, s, l) = parse_hue_channels(&tmp, options)?;
+                    Ok((h, s, l, parse_alpha_component(alpha, 3, options)?))
+                })
+                .map_err(|e| parse_err(hsla_str, e))?;
+            let hsl = HSL { h, s, l };
+            return Ok(Self { hsl, a });
+        }
+        Err(ColorError::FormatErr {
+            input: hsla_str.to_string(),
+            reason: format!("HSLA: {} format error!", hsla_str),
+        })
+    }
+
+    /// The fully transparent black, which is what the CSS "transparent" keyword means
+    pub const TRANSPARENT: Self = Self {
+        hsl: HSL { h: 0, s: 0, l: 0 },
+        a: 0.0,
+    };
+
+    /// Create from a hue that is wrapped into 0~360, like CSS does, so 480 is 120 and -120 is 240.
+    /// The saturation and lightness must still be between 0~100, and the alpha between 0~1.
+    pub fn new_wrapping(
+        hue: i32,
+        saturation: u32,
+        lightness: u32,
+        alpha: f32,
+    ) -> Result<Self, ColorError> {
+        (wrap_hue(hue), saturation, lightness, alpha).try_into()
+    }
+
     pub fn alpha(&self) -> f32 {
         self.a
     }
 
     pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
-        self.a = alpha.max(0.0).min(1.0);
+        self.a = alpha.clamp(0.0, 1.0);
         self
     }
 
+    /// Composite the color over the opaque background, see `RGBA::flatten_onto`
+    pub fn flatten_onto(&self, background: impl Into<RGB>) -> RGB {
+        RGBA::from(*self).flatten_onto(background)
+    }
+
     /// Generate HSLA, value is random
+    /// Returns (h, s, l, a), `try_from` of the tuple returns the same color.
+    /// HSLA has no `to_array` as the alpha is not an integer, `to_array` through Deref returns the HSL channels.
+    pub fn as_tuple(&self) -> (u32, u32, u32, f32) {
+        let HSL { h, s, l } = self.hsl;
+        (h, s, l, self.a)
+    }
+
     pub fn random() -> Self {
         let hsl = HSL::random();
         let a = (rand::random::<f32>() * 100.0_f32).round() / 100.0;
@@ -0,0 +1,147 @@
+use crate::common::rgb_to_hsluv;
+use crate::{ColorError, Hex, RGB, RGBA};
+use rand::Rng;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// HSLuv is a human friendly alternative to HSL, built on CIE LCh(uv), where colors with the same lightness look equally bright.
+/// It can be parsed from a string in the format "hsluv(h, s%, l%)" or from a tuple (h,s,l).
+/// * h:f32 - Hue(0~360)
+/// * s:f32 - saturation(0~100), 100 is the most saturated color of the hue and lightness in the sRGB gamut
+/// * l:f32 - lightness(0~100)
+///
+/// The conversion follows the reference HSLuv implementation, see <https://www.hsluv.org>.
+/// ### example
+/// ```rust
+/// use easy_color::{HSLuv, IntoHex, RGB};
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// let hsluv:HSLuv = rgb.into();
+/// assert_eq!(hsluv.to_string(), "hsluv(12.18,100.00%,53.24%)");
+///
+/// let mut hsluv:HSLuv = "hsluv(257.68, 84.58%, 45.03%)".try_into().unwrap();
+/// assert_eq!(hsluv.to_hex().to_string(), "#3366CC");
+/// hsluv.set_hue(36.21);
+/// assert_eq!(hsluv.to_hex().to_string(), "#9A5B27");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HSLuv {
+    pub(crate) h: f32,
+    pub(crate) s: f32,
+    pub(crate) l: f32,
+}
+
+impl TryFrom<&str> for HSLuv {
+    type Error = ColorError;
+    fn try_from(hsluv_str: &str) -> Result<Self, Self::Error> {
+        let mut color = hsluv_str.trim().to_lowercase();
+        if color.starts_with("hsluv(") && color.ends_with(')') {
+            color = color.replace("hsluv(", "").replace(')', "");
+            let tmp = color.split(',').collect::<Vec<_>>();
+            if tmp.len() == 3 {
+                let val = tmp
+                    .iter()
+                    .map(|s| s.trim().trim_end_matches('%').parse::<f32>())
+                    .filter_map(|v| v.ok())
+                    .collect::<Vec<_>>();
+                if val.len() == 3 {
+                    return (val[0], val[1], val[2]).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr {
+            input: hsluv_str.to_string(),
+            reason: format!("HSLuv: {} format error!", hsluv_str),
+        })
+    }
+}
+
+impl FromStr for HSLuv {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for HSLuv {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=360.0).contains(&value.0)
+            || !(0.0..=100.0).contains(&value.1)
+            || !(0.0..=100.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr { input: format!("{:?}", value), reason: format!("HSLuv: args ({},{},{}) value error, first value must between 0~360, others must between 0~100!", value.0, value.1, value.2) })
+        } else {
+            Ok(Self {
+                h: value.0,
+                s: value.1,
+                l: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for HSLuv {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for HSLuv {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (h, s, l) = rgb_to_hsluv(r, g, b);
+        Self { h, s, l }
+    }
+}
+
+impl From<RGBA> for HSLuv {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for HSLuv {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "hsluv({:.2},{:.2}%,{:.2}%)", self.h, self.s, self.l)
+    }
+}
+
+impl HSLuv {
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    /// Set the hue, the value wraps around 360, so 400 becomes 40 and -30 becomes 330.
+    pub fn set_hue(&mut self, hue: f32) -> &mut Self {
+        self.h = hue.rem_euclid(360.0);
+        self
+    }
+
+    pub fn saturation(&self) -> f32 {
+        self.s
+    }
+
+    pub fn set_saturation(&mut self, saturation: f32) -> &mut Self {
+        self.s = saturation.clamp(0.0, 100.0);
+        self
+    }
+
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 100.0);
+        self
+    }
+
+    pub fn random() -> Self {
+        let mut rng = rand::thread_rng();
+        let h = rng.gen_range(0.0..=360.0);
+        let s = rng.gen_range(0.0..=100.0);
+        let l = rng.gen_range(0.0..=100.0);
+        Self { h, s, l }
+    }
+}
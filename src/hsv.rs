@@ -1,12 +1,18 @@
-use crate::common::{calc_rgb_with_alpha, rgb_to_hsv};
-use crate::{ColorError, Hex, CMYK, HSL, HSLA, RGB, RGBA};
+use crate::common::{
+    calc_rgb_to_hsv, calc_rgb_with_alpha, color_function_args, hue_distance, parse_err,
+    parse_hue_channels, rgb_to_hsv, split_color_args, wrap_hue,
+};
+use crate::{ColorError, Hex, OkLch, ParseOptions, CMYK, HSL, HSLA, HWB, LCH, RGB, RGB16, RGBA};
 use rand::Rng;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
-/// HSV can be parsed from a string in the format "hsl(h, s%, v%)" or from a tuple (h,s,v).
+/// HSV can be parsed from a string in the format "hsv(h, s%, v%)", the Photoshop alias "hsb(h, s%, b%)" or from a tuple (h,s,v).
 /// * h:u32 - Hue(0~360)
 /// * s:u32 - saturation(0~100)
 /// * v:u32 - Value(0~100)
+///
+/// Like HSL, the hue never takes `%` and the `%` of the saturation and value is optional.
 /// ### example
 /// ```rust
 /// use easy_color::{RGB, HSV};
@@ -28,25 +34,14 @@ pub struct HSV {
 impl TryFrom<&str> for HSV {
     type Error = ColorError;
     fn try_from(hsv_str: &str) -> Result<Self, Self::Error> {
-        let mut color = hsv_str.trim().to_lowercase();
-        if color.starts_with("hsv(") && color.ends_with(')') {
-            color = color.replace("hsv(", "").replace(")", "");
-            let tmp = color.split(',').collect::<Vec<_>>();
-            if tmp.len() == 3 {
-                let val = tmp
-                    .iter()
-                    .map(|s| s.trim().trim_end_matches('%').parse::<u32>())
-                    .filter_map(|v| v.ok())
-                    .collect::<Vec<_>>();
-                if val.len() == 3 {
-                    return (val[0], val[1], val[2]).try_into();
-                }
-            }
-        }
-        Err(ColorError::FormatErr(format!(
-            "HSV:{} format error!",
-            hsv_str
-        )))
+        Self::parse_with(hsv_str, &ParseOptions::default())
+    }
+}
+
+impl FromStr for HSV {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
     }
 }
 
@@ -57,7 +52,7 @@ impl TryFrom<(u32, u32, u32)> for HSV {
             || !(0..=100).contains(&value.1)
             || !(0..=100).contains(&value.2)
         {
-            Err(ColorError::ValueErr(format!("HSV: args ({},{},{}) value error. the first value must between 0~360, others must between 0~1.", value.0, value.1, value.2)))
+            Err(ColorError::ValueErr { input: format!("{:?}", value), reason: format!("HSV: args ({},{},{}) value error. the first value must between 0~360, others must between 0~1.", value.0, value.1, value.2) })
         } else {
             Ok(Self {
                 h: value.0,
@@ -116,12 +111,114 @@ impl From<CMYK> for HSV {
     }
 }
 
+impl From<HWB> for HSV {
+    fn from(hwb: HWB) -> Self {
+        let rgb: RGB = hwb.into();
+        rgb.into()
+    }
+}
+
+impl From<LCH> for HSV {
+    fn from(lch: LCH) -> Self {
+        let rgb: RGB = lch.into();
+        rgb.into()
+    }
+}
+
+impl From<OkLch> for HSV {
+    fn from(oklch: OkLch) -> Self {
+        let rgb: RGB = oklch.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB16> for HSV {
+    fn from(rgb16: RGB16) -> Self {
+        let (r, g, b) = rgb16.to_unit();
+        let (h, s, v) = calc_rgb_to_hsv(r, g, b);
+        Self { h, s, v }
+    }
+}
+
+impl From<HSV> for (u32, u32, u32) {
+    fn from(hsv: HSV) -> Self {
+        hsv.as_tuple()
+    }
+}
+
+impl From<HSV> for [u32; 3] {
+    fn from(hsv: HSV) -> Self {
+        hsv.to_array()
+    }
+}
+
 impl Display for HSV {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "hsv({},{}%,{}%)", self.h, self.s, self.v)
     }
 }
 impl HSV {
+    /// Parse a string like `TryFrom<&str>` does, with the given options.
+    /// `TryFrom<&str>` is the same as this with `ParseOptions::default()`.
+    pub fn parse_with(hsv_str: &str, options: &ParseOptions) -> Result<Self, ColorError> {
+        let Some(color) = options.normalize(hsv_str) else {
+            return Err(ColorError::FormatErr {
+                input: hsv_str.to_string(),
+                reason: format!("'{}' format error, the syntax is not exact!", hsv_str),
+            });
+        };
+        if let Some(args) = color_function_args(color, &["hsv", "hsb"]) {
+            let (tmp, alpha) = split_color_args(args).map_err(|e| parse_err(hsv_str, e))?;
+            if alpha.is_some() {
+                return Err(parse_err(
+                    hsv_str,
+                    (Some(3), "HSV has no alpha channel".to_string()),
+                ));
+            }
+            let (h, s, v) = parse_hue_channels(&tmp, options).map_err(|e| parse_err(hsv_str, e))?;
+            return Ok(Self { h, s, v });
+        }
+        Err(ColorError::FormatErr {
+            input: hsv_str.to_string(),
+            reason: format!("HSV:{} format error!", hsv_str),
+        })
+    }
+
+    /// Returns the string in the format "hsb(h,s%,b%)", which is how Photoshop and many designers call HSV.
+    /// ```rust
+    /// use easy_color::HSV;
+    /// let hsv:HSV = "hsb(262,85%,79%)".try_into().unwrap();
+    /// assert_eq!(hsv.to_string(), "hsv(262,85%,79%)");
+    /// assert_eq!(hsv.to_hsb_string(), "hsb(262,85%,79%)");
+    /// ```
+    pub fn to_hsb_string(&self) -> String {
+        format!("hsb({},{}%,{}%)", self.h, self.s, self.v)
+    }
+
+    /// Returns the string in the space separated syntax of the modern CSS functions, like "hsv(157deg 78% 77%)".
+    /// CSS has no hsv(), so this is for design tokens and tools, `ToCss` writes HSV as rgb().
+    /// ```rust
+    /// use easy_color::HSV;
+    /// let hsv:HSV = "hsv(157,78%,77%)".try_into().unwrap();
+    /// assert_eq!(hsv.to_modern_string(), "hsv(157deg 78% 77%)");
+    /// assert_eq!(HSV::try_from(hsv.to_modern_string().as_str()).unwrap(), hsv);
+    /// ```
+    pub fn to_modern_string(&self) -> String {
+        format!("hsv({}deg {}% {}%)", self.h, self.s, self.v)
+    }
+
+    /// Create from a hue that is wrapped into 0~360, like CSS does, so 480 is 120 and -120 is 240.
+    /// The saturation and value must still be between 0~100.
+    /// ```rust
+    /// use easy_color::HSV;
+    /// let color = HSV::new_wrapping(-120, 64, 47).unwrap();
+    /// assert_eq!(color.hue(), 240);
+    /// assert!(HSV::new_wrapping(480, 101, 47).is_err());
+    /// ```
+    pub fn new_wrapping(hue: i32, saturation: u32, value: u32) -> Result<Self, ColorError> {
+        (wrap_hue(hue), saturation, value).try_into()
+    }
+
     pub fn hue(&self) -> u32 {
         self.h
     }
@@ -149,6 +246,24 @@ impl HSV {
         self
     }
 
+    /// Returns true if no value differs by more than the tolerance, the hue is compared the short way around 0/360
+    pub fn approx_eq(&self, other: impl Into<Self>, tolerance: u32) -> bool {
+        let other: HSV = other.into();
+        hue_distance(self.h, other.h) <= tolerance
+            && self.s.abs_diff(other.s) <= tolerance
+            && self.v.abs_diff(other.v) <= tolerance
+    }
+
+    /// Returns (h, s, v), `try_from` of the tuple returns the same color.
+    pub fn as_tuple(&self) -> (u32, u32, u32) {
+        (self.h, self.s, self.v)
+    }
+
+    /// Returns [h, s, v]
+    pub fn to_array(&self) -> [u32; 3] {
+        [self.h, self.s, self.v]
+    }
+
     pub fn random() -> Self {
         let mut rng = rand::thread_rng();
         let h = rng.gen_range(0..=360) as u32;
@@ -0,0 +1,255 @@
+use crate::common::{
+    color_function_args, parse_err, parse_hue_channels, rgb_to_hwb, split_color_args,
+};
+use crate::{ColorError, Hex, OkLch, ParseOptions, CMYK, HSL, HSLA, HSV, LCH, RGB, RGBA};
+use rand::Rng;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// HWB can be parsed from a string in the format "hwb(h w% b%)", the hue takes an angle unit like "120deg", or from a tuple (h,w,b).
+/// * h:u32 - Hue(0~360)
+/// * w:u32 - whiteness(0~100)
+/// * b:u32 - blackness(0~100)
+///
+/// When whiteness and blackness add up to 100 or more, the color is a gray whose value is `w / (w + b)`,
+/// which is the same normalization browsers do.
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, HWB};
+/// let mut hwb:HWB = "hwb(157 17% 23%)".try_into().unwrap();
+/// let rgb:RGB = hwb.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+///
+/// hwb.set_whiteness(100);
+/// assert_eq!(hwb.to_string(), "hwb(157 100% 23%)");
+///
+/// let hwb:HWB = (0,60,60).try_into().unwrap();
+/// let rgb:RGB = hwb.into();
+/// assert_eq!(rgb.to_string(), "rgb(128,128,128)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HWB {
+    pub(crate) h: u32,
+    pub(crate) w: u32,
+    pub(crate) b: u32,
+}
+
+impl TryFrom<&str> for HWB {
+    type Error = ColorError;
+    fn try_from(hwb_str: &str) -> Result<Self, Self::Error> {
+        Self::parse_with(hwb_str, &ParseOptions::default())
+    }
+}
+
+impl FromStr for HWB {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(u32, u32, u32)> for HWB {
+    type Error = ColorError;
+    fn try_from(value: (u32, u32, u32)) -> Result<Self, Self::Error> {
+        if !(0..=360).contains(&value.0)
+            || !(0..=100).contains(&value.1)
+            || !(0..=100).contains(&value.2)
+        {
+            Err(ColorError::ValueErr { input: format!("{:?}", value), reason: format!("HWB: args ({},{},{}) value error, first value must between 0~360, others must between 0~100!", value.0, value.1, value.2) })
+        } else {
+            Ok(Self {
+                h: value.0,
+                w: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for HWB {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for HWB {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (h, w, b) = rgb_to_hwb(r, g, b);
+        Self { h, w, b }
+    }
+}
+
+impl From<RGBA> for HWB {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<HSL> for HWB {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for HWB {
+    fn from(hsla: HSLA) -> Self {
+        let rgb: RGB = hsla.into();
+        rgb.into()
+    }
+}
+
+impl From<HSV> for HWB {
+    fn from(hsv: HSV) -> Self {
+        let rgb: RGB = hsv.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYK> for HWB {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl From<LCH> for HWB {
+    fn from(lch: LCH) -> Self {
+        let rgb: RGB = lch.into();
+        rgb.into()
+    }
+}
+
+impl From<OkLch> for HWB {
+    fn from(oklch: OkLch) -> Self {
+        let rgb: RGB = oklch.into();
+        rgb.into()
+    }
+}
+
+/// Move the value towards 100 by the ratio(0~1), and reduce the other value when they add up to more than 100
+fn shift_towards_100(value: u32, other: u32, ratio: f32) -> (u32, u32) {
+    let ratio = if ratio.is_nan() {
+        0.0
+    } else {
+        ratio.clamp(0.0, 1.0)
+    };
+    let value = value.min(100);
+    let value = value + ((100 - value) as f32 * ratio).round() as u32;
+    (value, other.min(100 - value))
+}
+
+impl Display for HWB {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "hwb({} {}% {}%)", self.h, self.w, self.b)
+    }
+}
+
+impl HWB {
+    /// Parse a string like `TryFrom<&str>` does, with the given options.
+    /// `TryFrom<&str>` is the same as this with `ParseOptions::default()`.
+    pub fn parse_with(hwb_str: &str, options: &ParseOptions) -> Result<Self, ColorError> {
+        let Some(color) = options.normalize(hwb_str) else {
+            return Err(ColorError::FormatErr {
+                input: hwb_str.to_string(),
+                reason: format!("'{}' format error, the syntax is not exact!", hwb_str),
+            });
+        };
+        if let Some(args) = color_function_args(color, &["hwb"]) {
+            let (tmp, alpha) = split_color_args(args).map_err(|e| parse_err(hwb_str, e))?;
+            if alpha.is_some() {
+                return Err(parse_err(
+                    hwb_str,
+                    (Some(3), "HWB has no alpha channel".to_string()),
+                ));
+            }
+            let (h, w, b) = parse_hue_channels(&tmp, options).map_err(|e| parse_err(hwb_str, e))?;
+            return Ok(Self { h, w, b });
+        }
+        Err(ColorError::FormatErr {
+            input: hwb_str.to_string(),
+            reason: format!("HWB: {} format error!", hwb_str),
+        })
+    }
+
+    pub fn hue(&self) -> u32 {
+        self.h
+    }
+
+    pub fn set_hue(&mut self, hue: u32) -> &mut Self {
+        self.h = hue.min(360);
+        self
+    }
+
+    pub fn whiteness(&self) -> u32 {
+        self.w
+    }
+
+    pub fn set_whiteness(&mut self, whiteness: u32) -> &mut Self {
+        self.w = whiteness.min(100);
+        self
+    }
+
+    pub fn blackness(&self) -> u32 {
+        self.b
+    }
+
+    pub fn set_blackness(&mut self, blackness: u32) -> &mut Self {
+        self.b = blackness.min(100);
+        self
+    }
+
+    /// Whitens the color by the given ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `ratio` - A float value between 0 and 1, the whiteness moves that far towards 100.
+    ///   When whiteness and blackness add up to more than 100, the blackness is reduced,
+    ///   so the ratio 1 always ends at pure white.
+    ///
+    /// # Example
+    ///
+    /// ``` rust
+    /// use easy_color::HWB;
+    /// let mut color = HWB::try_from("hwb(157 20% 30%)").unwrap();
+    /// color.whiten(0.5);
+    /// assert_eq!(color.to_string(), "hwb(157 60% 30%)");
+    /// color.whiten(0.5);
+    /// assert_eq!(color.to_string(), "hwb(157 80% 20%)");
+    /// ```
+    pub fn whiten(&mut self, ratio: f32) -> &mut Self {
+        let (w, b) = shift_towards_100(self.w, self.b, ratio);
+        self.w = w;
+        self.b = b;
+        self
+    }
+
+    /// Blackens the color by the given ratio, like `whiten` with the blackness,
+    /// so the ratio 1 always ends at pure black.
+    ///
+    /// # Example
+    ///
+    /// ``` rust
+    /// use easy_color::HWB;
+    /// let mut color = HWB::try_from("hwb(157 20% 30%)").unwrap();
+    /// color.blacken(0.5);
+    /// assert_eq!(color.to_string(), "hwb(157 20% 65%)");
+    /// ```
+    pub fn blacken(&mut self, ratio: f32) -> &mut Self {
+        let (b, w) = shift_towards_100(self.b, self.w, ratio);
+        self.w = w;
+        self.b = b;
+        self
+    }
+
+    pub fn random() -> Self {
+        let mut rng = rand::thread_rng();
+        let h = rng.gen_range(0..=360) as u32;
+        let w = rng.gen_range(0..=100) as u32;
+        let b = rng.gen_range(0..=100) as u32;
+        Self { h, w, b }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::common::planckian_uv;
+use crate::ColorError;
+use std::fmt::{Display, Formatter};
+
+/// colors farther than this from the Planckian locus in CIE 1960 uv have no meaningful color temperature
+const MAX_DUV: f32 = 0.05;
+
+/// Kelvin is the correlated color temperature of a light source, it can be created from a u32(1000~40000)
+/// and converted into RGB, RGBA and Hex.
+///
+/// The conversion uses Tanner Helland's fit of the blackbody color, so 6600K is white,
+/// lower temperatures are warm orange and higher temperatures are cold blue.
+/// The opposite direction is `RGB::estimate_cct`, which searches the Planckian locus with Krystek's approximation.
+/// ### example
+/// ```rust
+/// use easy_color::{Kelvin, RGB};
+/// let kelvin:Kelvin = 2000.try_into().unwrap();
+/// assert_eq!(kelvin.to_string(), "2000K");
+/// let rgb:RGB = kelvin.into();
+/// assert_eq!(rgb.to_string(), "rgb(255,137,14)");
+///
+/// let rgb:RGB = (255,255,255).try_into().unwrap();
+/// let kelvin = rgb.estimate_cct().unwrap();
+/// assert!((6400..6600).contains(&kelvin.value()));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Kelvin(pub(crate) u32);
+
+impl Default for Kelvin {
+    fn default() -> Self {
+        Self(6600)
+    }
+}
+
+impl TryFrom<u32> for Kelvin {
+    type Error = ColorError;
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if !(Kelvin::MIN..=Kelvin::MAX).contains(&value) {
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "Kelvin: args {} value error, value must between {}~{}",
+                    value,
+                    Kelvin::MIN,
+                    Kelvin::MAX
+                ),
+            })
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl Display for Kelvin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}K", self.0)
+    }
+}
+
+impl Kelvin {
+    /// The lowest supported temperature
+    pub const MIN: u32 = 1000;
+    /// The highest supported temperature
+    pub const MAX: u32 = 40000;
+
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    /// Find the temperature whose point on the Planckian locus is the nearest to the CIE 1960 uv chromaticity.
+    /// Returns None when the distance is larger than 0.05 or the temperature is out of range.
+    pub(crate) fn from_uv(u: f32, v: f32) -> Option<Self> {
+        let distance = |mired: f32| {
+            let (lu, lv) = planckian_uv(1_000_000.0 / mired);
+            ((u - lu).powi(2) + (v - lv).powi(2)).sqrt()
+        };
+        // the locus is close to uniform in mired, so a coarse scan followed by a ternary search is enough
+        let min_mired = 1_000_000.0 / Self::MAX as f32;
+        let max_mired = 1_000_000.0 / Self::MIN as f32;
+        let mut best = min_mired;
+        let mut mired = min_mired;
+        while mired <= max_mired {
+            if distance(mired) < distance(best) {
+                best = mired;
+            }
+            mired += 1.0;
+        }
+        let mut lo = (best - 1.0).max(min_mired);
+        let mut hi = (best + 1.0).min(max_mired);
+        for _ in 0..40 {
+            let m1 = lo + (hi - lo) / 3.0;
+            let m2 = hi - (hi - lo) / 3.0;
+            if distance(m1) < distance(m2) {
+                hi = m2;
+            } else {
+                lo = m1;
+            }
+        }
+        let mired = (lo + hi) / 2.0;
+        if distance(mired) > MAX_DUV {
+            return None;
+        }
+        let t = (1_000_000.0 / mired).round() as u32;
+        Self::try_from(t).ok()
+    }
+}
@@ -0,0 +1,193 @@
+use crate::common::{ab_to_chroma_hue, rgb_to_lab, LAB_ACHROMATIC_CHROMA};
+use crate::{ColorError, Hex, OkLch, CMYK, HSL, HSLA, HSV, HWB, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// LCH is the cylindrical form of CIE Lab(D50), the same space used by the CSS `lch()` function.
+/// It can be parsed from a string in the format "lch(l% c h)" or from a tuple (l,c,h).
+/// * l:f32 - lightness(0~100)
+/// * c:f32 - chroma(0~), usually not bigger than 150
+/// * h:f32 - hue(0~360)
+///
+/// Achromatic colors, whose chroma is close to 0, always have a hue of 0.
+/// Colors out of the sRGB gamut are clamped when converted back to RGB.
+/// ### example
+/// ```rust
+/// use easy_color::{Hex, LCH};
+/// let hex:Hex = "#2bc48a".try_into().unwrap();
+/// let mut lch:LCH = hex.into();
+/// assert_eq!(lch.to_string(), "lch(71% 53.6 160.7)");
+///
+/// lch.set_hue(lch.hue() + 240.0);
+/// assert_eq!(lch.to_string(), "lch(71% 53.6 40.7)");
+///
+/// let lch:LCH = (52.0, 58.1, 22.7).try_into().unwrap();
+/// let hex:Hex = lch.into();
+/// assert_eq!(hex.to_string(), "#D14D59");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct LCH {
+    pub(crate) l: f32,
+    pub(crate) c: f32,
+    pub(crate) h: f32,
+}
+
+impl TryFrom<&str> for LCH {
+    type Error = ColorError;
+    fn try_from(lch_str: &str) -> Result<Self, Self::Error> {
+        let mut color = lch_str.trim().to_lowercase();
+        if color.starts_with("lch(") && color.ends_with(')') {
+            color = color.replace("lch(", "").replace(')', "");
+            let tmp = color
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>();
+            if tmp.len() == 3 {
+                let val = tmp
+                    .iter()
+                    .map(|s| s.trim_end_matches('%').parse::<f32>())
+                    .filter_map(|v| v.ok())
+                    .collect::<Vec<_>>();
+                if val.len() == 3 {
+                    return (val[0], val[1], val[2]).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr {
+            input: lch_str.to_string(),
+            reason: format!("LCH: {} format error!", lch_str),
+        })
+    }
+}
+
+impl FromStr for LCH {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for LCH {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=100.0).contains(&value.0)
+            || !(0.0..=f32::MAX).contains(&value.1)
+            || !(0.0..=360.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr { input: format!("{:?}", value), reason: format!("LCH: args ({},{},{}) value error, lightness must between 0~100, chroma must not be negative, and hue must between 0~360!", value.0, value.1, value.2) })
+        } else {
+            Ok(Self {
+                l: value.0,
+                c: value.1,
+                h: value.2 % 360.0,
+            })
+        }
+    }
+}
+
+impl From<Hex> for LCH {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for LCH {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (l, a, b) = rgb_to_lab(r, g, b);
+        let (c, h) = ab_to_chroma_hue(a, b, LAB_ACHROMATIC_CHROMA);
+        Self { l, c, h }
+    }
+}
+
+impl From<RGBA> for LCH {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<HSL> for LCH {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for LCH {
+    fn from(hsla: HSLA) -> Self {
+        let rgb: RGB = hsla.into();
+        rgb.into()
+    }
+}
+
+impl From<HSV> for LCH {
+    fn from(hsv: HSV) -> Self {
+        let rgb: RGB = hsv.into();
+        rgb.into()
+    }
+}
+
+impl From<HWB> for LCH {
+    fn from(hwb: HWB) -> Self {
+        let rgb: RGB = hwb.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYK> for LCH {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl From<OkLch> for LCH {
+    fn from(oklch: OkLch) -> Self {
+        let rgb: RGB = oklch.into();
+        rgb.into()
+    }
+}
+
+impl Display for LCH {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "lch({:.0}% {:.1} {:.1})", self.l, self.c, self.h)
+    }
+}
+
+impl LCH {
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 100.0);
+        self
+    }
+
+    pub fn chroma(&self) -> f32 {
+        self.c
+    }
+
+    pub fn set_chroma(&mut self, chroma: f32) -> &mut Self {
+        self.c = chroma.max(0.0);
+        self
+    }
+
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    /// Set the hue, the value wraps around 360, so 400 becomes 40 and -30 becomes 330.
+    /// ```rust
+    /// use easy_color::LCH;
+    /// let mut lch:LCH = (52.0, 58.1, 22.7).try_into().unwrap();
+    /// lch.set_hue(-30.0);
+    /// assert_eq!(lch.hue(), 330.0);
+    /// ```
+    pub fn set_hue(&mut self, hue: f32) -> &mut Self {
+        self.h = hue.rem_euclid(360.0);
+        self
+    }
+}
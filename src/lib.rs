@@ -1,4 +1,4 @@
-//! A very simple and easy-to-use color conversion tool that can easily convert colors between Hex, RGB, RGBA, HSL, HSLA, HSV, and CMYK.
+//! A very simple and easy-to-use color conversion tool that can easily convert colors between Hex, RGB, RGBA, HSL, HSLA, HSV, HWB, LCH, OkLch, and CMYK.
 //! And each type has its unique API, such as RGB type can set color channels, RGBA type can set transparency, HSL type can set hue, saturation, and brightness, etc.
 //! ### example:
 //! ```rust
@@ -45,25 +45,114 @@
 //! let hex:Hex = "#2bc48a".try_into().unwrap();
 //! let hex_str = hex.to_rgb().set_blue(255).to_hsl().set_lightness(50).to_cmyk().set_cyan(100).to_hex().to_string(); // #00B5FF
 //! ```
+//!
+//! ### untrusted input
+//! The parsers never panic, `TryFrom<&str>`, `parse_with`, `parse`, `parse_list` and the readers of `Palette`
+//! return an `Err` for any bad input, like empty arguments, NUL characters, nested parentheses or very long strings.
+//! This is checked by property tests over adversarial strings.
+mod adobe_rgb;
+mod ansi256;
+mod any_color;
+mod blend;
+mod byte_order;
+mod cam16;
 mod cmyk;
+mod cmyka;
+mod color_blindness;
 mod common;
+mod contrast;
+mod css;
+mod css_filter;
+mod description;
+mod distance;
+mod easing;
+mod gradient;
+mod gray;
 mod hex;
+mod hsi;
 mod hsl;
 mod hsla;
+mod hsluv;
 mod hsv;
+mod hwb;
+mod kelvin;
+mod lch;
+mod linear_rgb;
+mod lms;
+mod luma_standard;
+mod named;
+mod oklch;
+mod p3;
+mod palette;
+mod parse_options;
+mod prophoto_rgb;
+mod ramp;
+mod rec2020;
+mod rgb16;
+mod rgb565;
 mod rgb;
 mod rgba;
+mod rgbaf;
+mod rgbf;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod string_impl;
 mod traits;
+mod xyy;
+mod xyz;
+mod ycbcr;
+mod yiq;
 
+pub use adobe_rgb::AdobeRGB;
+pub use ansi256::{Ansi256, AnsiMode};
+pub use any_color::{parse, parse_list, AnyColor};
+pub use blend::BlendMode;
+pub use byte_order::ByteOrder;
+pub use cam16::{Cam16, ViewingConditions};
 pub use cmyk::CMYK;
+pub use cmyka::CMYKA;
+pub use color_blindness::ColorBlindness;
 pub use common::ColorError;
-pub use hex::Hex;
+pub use contrast::{EnsuredContrast, TextSize, WcagLevel};
+pub use css::ToCss;
+pub use css_filter::CssFilter;
+pub use description::{ColorDescription, HueFamily, LightnessLevel, SaturationLevel};
+pub use distance::{DistanceMetric, PaletteMatcher};
+pub use easing::Easing;
+pub use gradient::{ColorStop, Gradient};
+pub use gray::Gray;
+pub use hex::{AlphaPosition, Hex, HexCase, HexPrefix};
+pub use hsi::HSI;
 pub use hsl::HSL;
 pub use hsla::HSLA;
+pub use hsluv::HSLuv;
 pub use hsv::HSV;
+pub use hwb::HWB;
+pub use kelvin::Kelvin;
+pub use lch::LCH;
+pub use linear_rgb::LinearRGB;
+pub use lms::{ConeMatrix, LMS};
+pub use luma_standard::LumaStandard;
+pub use oklch::OkLch;
+pub use p3::P3;
+pub use palette::{Palette, SkippedSwatch, Swatch};
+pub use parse_options::ParseOptions;
+pub use prophoto_rgb::ProPhotoRGB;
+pub use ramp::{Ramp, RampShade};
+pub use rec2020::Rec2020;
+pub use rgb16::RGB16;
+pub use rgb565::RGB565;
 pub use rgb::RGB;
 pub use rgba::RGBA;
+pub use rgbaf::RGBAf;
+pub use rgbf::RGBf;
 pub use traits::*;
+pub use xyy::Xyy;
+pub use xyz::{WhitePoint, XYZ};
+pub use ycbcr::{ColorRange, Standard, YCbCr};
+pub use yiq::YIQ;
 
 #[cfg(test)]
 mod tests {
@@ -118,7 +207,8 @@ mod tests {
         //rgba
         let rgba: RGBA = "rgba(43,196,138,0.85)".try_into().unwrap();
         let hex: Hex = rgba.into();
-        assert_eq!(hex.to_string(), "#2BC48AD8");
+        // the alpha is rounded to the nearest byte since the deep color hex, 0.85 * 255 = 216.75 is D9, see CHANGELOG.md
+        assert_eq!(hex.to_string(), "#2BC48AD9");
 
         let rgb: RGB = rgba.into();
         assert_eq!(rgb.to_string(), "rgb(74,204,155)");
@@ -136,4 +226,3257 @@ mod tests {
         assert_eq!(cmyk.to_string(), "cmyk(64,0,24,20)");
 
     }
+
+    #[test]
+    fn hwb_works() {
+        let hex: Hex = "#2bc48a".try_into().unwrap();
+        let hwb: HWB = hex.into();
+        assert_eq!(hwb.to_string(), "hwb(157 17% 23%)");
+        assert_eq!(hwb.to_hex().to_string(), "#2BC48A");
+
+        let hwb: HWB = "hwb(157, 17%, 23%)".try_into().unwrap();
+        assert_eq!(hwb.to_rgb().to_string(), "rgb(43,196,138)");
+
+        let hwb: HWB = "hwb(0 0% 0%)".try_into().unwrap();
+        assert_eq!(hwb.to_rgb().to_string(), "rgb(255,0,0)");
+        let hwb: HWB = "hwb(0 100% 0%)".try_into().unwrap();
+        assert_eq!(hwb.to_rgb().to_string(), "rgb(255,255,255)");
+        let hwb: HWB = "hwb(0 0% 100%)".try_into().unwrap();
+        assert_eq!(hwb.to_rgb().to_string(), "rgb(0,0,0)");
+
+        // w + b > 100 is normalized to a gray
+        let hwb: HWB = (120, 80, 40).try_into().unwrap();
+        assert_eq!(hwb.to_rgb().to_string(), "rgb(170,170,170)");
+
+        let mut hwb: HWB = (120, 20, 20).try_into().unwrap();
+        hwb.set_whiteness(150).set_blackness(150);
+        assert_eq!(hwb.to_string(), "hwb(120 100% 100%)");
+
+        assert!(HWB::try_from((361, 0, 0)).is_err());
+        assert!(HWB::try_from("hwb(120 20%)").is_err());
+        // the hue takes angle units like hsl(), but not a percentage
+        let hwb: HWB = "hwb(120deg 20% 20%)".try_into().unwrap();
+        assert_eq!(hwb.to_string(), "hwb(120 20% 20%)");
+        assert_eq!(HWB::try_from("hwb(0.5turn 20% 20%)").unwrap().hue(), 180);
+        let err = HWB::try_from("hwb(120% 20% 20%)").unwrap_err();
+        assert!(matches!(err, ColorError::ParseErr { component: Some(0), .. }));
+        // the separators follow the rules of hsl(), and HWB has no alpha
+        assert!(HWB::try_from("hwb(120, 20% 20%)").is_err());
+        assert!(HWB::try_from("hwb(120 20% 20% / 0.5)").is_err());
+
+        let hsv: HSV = (157, 78, 77).try_into().unwrap();
+        let hwb: HWB = hsv.into();
+        let cmyk: CMYK = hwb.into();
+        assert_eq!(cmyk.to_string(), "cmyk(78,0,30,23)");
+    }
+
+    #[test]
+    fn lch_works() {
+        let rgb: RGB = (255, 0, 0).try_into().unwrap();
+        let lch: LCH = rgb.into();
+        assert!((lch.lightness() - 54.29).abs() < 0.01);
+        assert!((lch.chroma() - 106.84).abs() < 0.01);
+        assert!((lch.hue() - 40.86).abs() < 0.01);
+        assert_eq!(lch.to_rgb(), rgb);
+
+        // achromatic colors have a stable hue
+        for v in [0, 1, 50, 128, 200, 255] {
+            let lch: LCH = RGB::try_from((v, v, v)).unwrap().into();
+            assert_eq!(lch.hue(), 0.0);
+            assert!(!lch.hue().is_nan());
+            assert_eq!(lch.to_rgb().to_string(), format!("rgb({},{},{})", v, v, v));
+        }
+
+        let hex: Hex = "#2bc48a".try_into().unwrap();
+        let mut lch = hex.to_lch();
+        assert_eq!(lch.to_hex(), hex);
+        lch.set_hue(lch.hue() + 360.0);
+        assert_eq!(lch.to_hex().to_string(), "#2BC48A");
+        lch.set_lightness(120.0).set_chroma(-5.0);
+        assert_eq!(lch.lightness(), 100.0);
+        assert_eq!(lch.chroma(), 0.0);
+
+        let lch: LCH = "lch(54.29% 106.84 40.86)".try_into().unwrap();
+        assert_eq!(lch.to_rgb().to_string(), "rgb(255,0,0)");
+        // out of gamut colors are clamped
+        let lch: LCH = (50.0, 150.0, 140.0).try_into().unwrap();
+        let _ = lch.to_rgb();
+        assert!(LCH::try_from((50.0, -1.0, 140.0)).is_err());
+        assert!(LCH::try_from("lch(50% 10)").is_err());
+    }
+
+    #[test]
+    fn oklch_works() {
+        let oklch: OkLch = "oklch(62.8% 0.2577 29.23)".try_into().unwrap();
+        assert_eq!(oklch.to_rgb().to_string(), "rgb(255,0,0)");
+        let oklch: OkLch = "oklch(0.628 0.2577 29.23deg)".try_into().unwrap();
+        assert_eq!(oklch.to_hex().to_string(), "#FF0000");
+
+        let oklch = RGB::try_from((255, 255, 255)).unwrap().to_oklch();
+        assert!((oklch.lightness() - 1.0).abs() < 0.001);
+        assert_eq!(oklch.hue(), 0.0);
+        for v in [0, 1, 77, 128, 254] {
+            let rgb: RGB = (v, v, v).try_into().unwrap();
+            assert_eq!(rgb.to_oklch().hue(), 0.0);
+            assert_eq!(rgb.to_oklch().to_rgb(), rgb);
+        }
+
+        // alpha is kept
+        let oklch: OkLch = "oklch(70% 0.1 200 / 50%)".try_into().unwrap();
+        assert_eq!(oklch.alpha(), 0.5);
+        let rgba = oklch.to_rgba();
+        assert_eq!(rgba.alpha(), 0.5);
+        assert_eq!(rgba.to_oklch().alpha(), 0.5);
+        let rgba: RGBA = "rgba(43,196,138,0.85)".try_into().unwrap();
+        assert_eq!(rgba.to_oklch().to_rgba(), rgba);
+
+        // out of gamut chroma is reduced, so the hue is kept
+        let oklch: OkLch = "oklch(70% 0.4 150)".try_into().unwrap();
+        let back = oklch.to_rgb().to_oklch();
+        assert!((back.hue() - 150.0).abs() < 2.0);
+        assert!((back.lightness() - 0.7).abs() < 0.01);
+        assert!(back.chroma() < 0.4);
+
+        assert!(OkLch::try_from("oklch(70% 0.1)").is_err());
+        assert!(OkLch::try_from("oklch(70% 0.1 200 / 2)").is_err());
+        assert!(OkLch::try_from((1.5, 0.1, 200.0)).is_err());
+    }
+
+    #[test]
+    fn xyz_works() {
+        let white: RGB = (255, 255, 255).try_into().unwrap();
+        let xyz = white.to_xyz();
+        let (x, y, z) = WhitePoint::D65.tristimulus();
+        assert!((xyz.x() - x).abs() < 0.01);
+        assert!((xyz.y() - y).abs() < 0.01);
+        assert!((xyz.z() - z).abs() < 0.01);
+
+        let d50 = xyz.to_white_point(WhitePoint::D50);
+        let (x, y, z) = WhitePoint::D50.tristimulus();
+        assert!((d50.x() - x).abs() < 0.01);
+        assert!((d50.y() - y).abs() < 0.01);
+        assert!((d50.z() - z).abs() < 0.01);
+        assert_eq!(d50.to_rgb(), white);
+        assert_eq!(d50.to_white_point(WhitePoint::D65).to_rgb(), white);
+
+        let hex: Hex = "#2bc48a".try_into().unwrap();
+        assert_eq!(hex.to_xyz().to_hex(), hex);
+        let black: RGB = (0, 0, 0).try_into().unwrap();
+        assert_eq!(black.to_xyz().to_string(), "xyz(0.00 0.00 0.00)");
+
+        // the inverse transform would give negative red and green, they are clamped to zero
+        let xyz: XYZ = (0.0, 0.0, 100.0).try_into().unwrap();
+        assert_eq!(xyz.to_rgb().to_string(), "rgb(0,57,255)");
+        assert!(XYZ::try_from((-1.0, 0.0, 0.0)).is_err());
+    }
+
+    #[test]
+    fn ycbcr_works() {
+        for standard in [Standard::Bt601, Standard::Bt709] {
+            // gray ramps have neutral chroma and round trip exactly
+            for v in (0..=255).step_by(5) {
+                let rgb: RGB = (v, v, v).try_into().unwrap();
+                let full = YCbCr::from_rgb(rgb, standard, ColorRange::Full);
+                assert_eq!((full.y(), full.cb(), full.cr()), (v, 128, 128));
+                assert_eq!(RGB::from(full), rgb);
+
+                let studio = YCbCr::from_rgb(rgb, standard, ColorRange::Studio);
+                let y = (16.0 + v as f32 * 219.0 / 255.0).round() as u8;
+                assert_eq!((studio.y(), studio.cb(), studio.cr()), (y, 128, 128));
+                let back: RGB = studio.into();
+                assert!(back.r.abs_diff(v) <= 1 && back.r == back.g && back.g == back.b);
+            }
+        }
+
+        let primaries = [
+            ((255, 0, 0), Standard::Bt601, (81, 90, 240)),
+            ((0, 255, 0), Standard::Bt601, (145, 54, 34)),
+            ((0, 0, 255), Standard::Bt601, (41, 240, 110)),
+            ((255, 0, 0), Standard::Bt709, (63, 102, 240)),
+            ((0, 255, 0), Standard::Bt709, (173, 42, 26)),
+            ((0, 0, 255), Standard::Bt709, (32, 240, 118)),
+        ];
+        // 8 bit YCbCr can't represent every rgb exactly, the round trip may be off by one
+        let close = |a: RGB, b: RGB| a.r.abs_diff(b.r) <= 1 && a.g.abs_diff(b.g) <= 1 && a.b.abs_diff(b.b) <= 1;
+        for (rgb, standard, expected) in primaries {
+            let rgb: RGB = rgb.try_into().unwrap();
+            let ycbcr = YCbCr::from_rgb(rgb, standard, ColorRange::Studio);
+            assert_eq!((ycbcr.y(), ycbcr.cb(), ycbcr.cr()), expected);
+            assert!(close(RGB::from(ycbcr), rgb));
+            let full = YCbCr::from_rgb(rgb, standard, ColorRange::Full);
+            assert!(close(RGB::from(full), rgb));
+        }
+
+        // out of range studio values are clamped
+        let ycbcr = YCbCr::new(0, 0, 255, Standard::Bt601, ColorRange::Studio);
+        assert_eq!(RGB::from(ycbcr), RGB::from(YCbCr::new(16, 16, 240, Standard::Bt601, ColorRange::Studio)));
+        let ycbcr: YCbCr = (255, 128, 128).try_into().unwrap();
+        assert_eq!(RGB::from(ycbcr).to_string(), "rgb(255,255,255)");
+    }
+
+    #[test]
+    fn yiq_works() {
+        let white: RGB = (255, 255, 255).try_into().unwrap();
+        let yiq = white.to_yiq();
+        assert!((yiq.luma() - 1.0).abs() < 0.001);
+        assert!(yiq.i().abs() < 0.001 && yiq.q().abs() < 0.001);
+
+        for s in ["#2BC48A", "#FF7D37", "#000000", "#0000FF", "#808080"] {
+            let hex: Hex = s.try_into().unwrap();
+            assert_eq!(hex.to_yiq().to_hex().to_string(), s);
+        }
+
+        let rgba: RGBA = "rgba(43,196,138,0.85)".try_into().unwrap();
+        assert_eq!(rgba.to_yiq().to_rgb(), rgba.to_rgb());
+
+        // saturated I/Q values are clamped instead of wrapping
+        let yiq: YIQ = (0.9, 0.5957, 0.5226).try_into().unwrap();
+        assert_eq!(yiq.to_rgb().to_string(), "rgb(255,102,255)");
+        let yiq: YIQ = (0.1, -0.5957, -0.5226).try_into().unwrap();
+        assert_eq!(yiq.to_rgb().to_string(), "rgb(0,153,0)");
+        assert!(YIQ::try_from((1.2, 0.0, 0.0)).is_err());
+    }
+
+    #[test]
+    fn xyy_works() {
+        let red: RGB = (255, 0, 0).try_into().unwrap();
+        let (x, y) = red.chromaticity();
+        assert!((x - 0.64).abs() < 0.001 && (y - 0.33).abs() < 0.001);
+        let green: RGB = (0, 255, 0).try_into().unwrap();
+        let (x, y) = green.chromaticity();
+        assert!((x - 0.30).abs() < 0.001 && (y - 0.60).abs() < 0.001);
+
+        let black: RGB = (0, 0, 0).try_into().unwrap();
+        let xyy: Xyy = black.into();
+        assert!(!xyy.x().is_nan() && !xyy.y().is_nan());
+        assert_eq!(xyy.chromaticity(), WhitePoint::D65.chromaticity());
+        assert_eq!(RGB::from(xyy), black);
+
+        let hex: Hex = "#2bc48a".try_into().unwrap();
+        let xyy: Xyy = hex.to_rgb().into();
+        assert_eq!(RGB::from(xyy), hex.to_rgb());
+        let adapted = Xyy::from(hex.to_xyz().to_white_point(WhitePoint::D50));
+        assert!((adapted.x() - xyy.x()).abs() < 0.0001 && (adapted.y() - xyy.y()).abs() < 0.0001);
+
+        // far outside the sRGB gamut
+        let xyy: Xyy = (0.1, 0.8, 0.5).try_into().unwrap();
+        assert_eq!(RGB::from(xyy).to_string(), "rgb(0,241,0)");
+        let xyy: Xyy = (0.3, 0.0, 0.5).try_into().unwrap();
+        assert_eq!(RGB::from(xyy), black);
+    }
+
+    #[test]
+    fn hsi_works() {
+        // reference triples from rgb
+        let cases = [
+            ((255, 0, 0), (0, 100, 33)),
+            ((0, 255, 0), (120, 100, 33)),
+            ((0, 0, 255), (240, 100, 33)),
+            ((255, 255, 0), (60, 100, 67)),
+            ((0, 255, 255), (180, 100, 67)),
+            ((255, 0, 255), (300, 100, 67)),
+            ((51, 204, 51), (120, 50, 40)),
+            ((51, 51, 204), (240, 50, 40)),
+            ((128, 128, 128), (0, 0, 50)),
+            ((0, 0, 0), (0, 0, 0)),
+        ];
+        for (rgb, hsi) in cases {
+            let rgb: RGB = rgb.try_into().unwrap();
+            let expected: HSI = hsi.try_into().unwrap();
+            assert_eq!(rgb.to_hsi(), expected);
+        }
+
+        // sector boundaries of the inverse transform
+        let cases = [
+            ((0, 50, 40), (204, 51, 51)),
+            ((119, 50, 40), (54, 201, 51)),
+            ((120, 50, 40), (51, 204, 51)),
+            ((121, 50, 40), (51, 201, 54)),
+            ((239, 50, 40), (51, 54, 201)),
+            ((240, 50, 40), (51, 51, 204)),
+            ((241, 50, 40), (54, 51, 201)),
+            ((360, 50, 40), (204, 51, 51)),
+        ];
+        for (hsi, rgb) in cases {
+            let hsi: HSI = hsi.try_into().unwrap();
+            let expected: RGB = rgb.try_into().unwrap();
+            assert_eq!(hsi.to_rgb(), expected, "{}", hsi);
+        }
+
+        let hex: Hex = "#2bc48a".try_into().unwrap();
+        let hsi: HSI = hex.into();
+        assert_eq!(hsi.to_string(), "hsi(158,66%,49%)");
+        let back = hsi.to_rgb();
+        assert!(back.r.abs_diff(43) <= 2 && back.g.abs_diff(196) <= 2 && back.b.abs_diff(138) <= 2);
+
+        assert!(HSI::try_from("hsi(120,50%)").is_err());
+        assert!(HSI::try_from((120, 101, 0)).is_err());
+    }
+
+    #[test]
+    fn linear_rgb_works() {
+        for v in 0..=255u8 {
+            let rgb: RGB = (v, v, v).try_into().unwrap();
+            let linear: LinearRGB = rgb.into();
+            assert_eq!(RGB::from(linear), rgb);
+        }
+        let linear: LinearRGB = RGB::try_from((10, 10, 10)).unwrap().into();
+        assert!((linear.red() - 10.0 / 255.0 / 12.92).abs() < 1e-6);
+        let rgba: RGBA = (255, 0, 0, 0.5).try_into().unwrap();
+        let linear: LinearRGB = rgba.into();
+        assert_eq!(RGB::from(linear).to_string(), "rgb(255,127,127)");
+        assert!(LinearRGB::try_from((1.2, 0.0, 0.0)).is_err());
+    }
+
+    #[test]
+    fn p3_works() {
+        // reference values from the CSS Color 4 spec
+        let cases = [
+            ((255, 0, 0), (0.9175, 0.2003, 0.1386)),
+            ((0, 255, 0), (0.4584, 0.9853, 0.2983)),
+            ((0, 0, 255), (0.0, 0.0, 0.9596)),
+            ((255, 255, 255), (1.0, 1.0, 1.0)),
+        ];
+        for (rgb, expected) in cases {
+            let rgb: RGB = rgb.try_into().unwrap();
+            let p3: P3 = rgb.into();
+            assert!((p3.red() - expected.0).abs() < 0.001);
+            assert!((p3.green() - expected.1).abs() < 0.001);
+            assert!((p3.blue() - expected.2).abs() < 0.001);
+            assert!(p3.is_in_srgb_gamut());
+            assert_eq!(RGB::from(p3), rgb);
+        }
+        let p3: P3 = "color(display-p3 100% 0% 0%)".try_into().unwrap();
+        assert!(!p3.is_in_srgb_gamut());
+        assert_eq!(p3.to_rgb().to_string(), "rgb(255,0,0)");
+        assert!(P3::try_from("color(srgb 1 0 0)").is_err());
+        assert!(P3::try_from("color(display-p3 1 0)").is_err());
+        // the wide gamut types share the color() parser of RGB and RGBA, with its errors
+        let err = P3::try_from("color(foo 1 0 0)").unwrap_err();
+        assert!(err.to_string().contains("unknown color space 'foo'"), "{}", err);
+        let err = P3::try_from("color(display-p3 1 0 0 / 0.5)").unwrap_err();
+        assert!(matches!(err, ColorError::ParseErr { component: Some(3), .. }));
+        assert!(err.to_string().contains("P3 has no alpha"), "{}", err);
+        let err = Rec2020::try_from("color(display-p3 1 0 0)").unwrap_err();
+        assert!(err.to_string().contains("'rec2020'"), "{}", err);
+        assert!(RGBf::try_from("color(srgb 1.5 0 0)").is_err());
+        assert!(P3::try_from((1.5, 0.0, 0.0)).is_err());
+    }
+
+    #[test]
+    fn rec2020_works() {
+        for (v, hex) in [((255, 255, 255), "#FFFFFF"), ((0, 0, 0), "#000000"), ((12, 200, 99), "#0CC863")] {
+            let rgb: RGB = v.try_into().unwrap();
+            let rec2020: Rec2020 = rgb.into();
+            assert!(rec2020.is_in_srgb_gamut());
+            assert_eq!(rec2020.to_hex().to_string(), hex);
+        }
+        let rgb: RGB = (0, 0, 255).try_into().unwrap();
+        let rec2020: Rec2020 = rgb.into();
+        let xyz: XYZ = rec2020.into();
+        assert_eq!(xyz.to_string(), rgb.to_xyz().to_string());
+        assert_eq!(Rec2020::from(xyz).to_string(), rec2020.to_string());
+        let rec2020: Rec2020 = "color(rec2020 1 0 0)".try_into().unwrap();
+        assert!(!rec2020.is_in_srgb_gamut());
+        assert_eq!(rec2020.clip_to_srgb().to_string(), "rgb(255,0,0)");
+        assert!(Rec2020::try_from("color(display-p3 1 0 0)").is_err());
+    }
+
+    #[test]
+    fn adobe_rgb_works() {
+        // Adobe red is slightly more saturated than sRGB red, so it clamps to pure sRGB red
+        let adobe: AdobeRGB = (255, 0, 0).try_into().unwrap();
+        assert!(!adobe.is_in_srgb_gamut());
+        let (r, g, b) = adobe.to_linear_srgb();
+        assert!((r - 1.398).abs() < 0.01 && g.abs() < 0.01 && b.abs() < 0.01);
+        assert_eq!(adobe.to_rgb().to_string(), "rgb(255,0,0)");
+        // sRGB red inside of Adobe RGB
+        let rgb: RGB = (255, 0, 0).try_into().unwrap();
+        let adobe: AdobeRGB = rgb.into();
+        assert!((adobe.red() - 0.8587).abs() < 0.001 && adobe.green() < 0.001 && adobe.blue() < 0.001);
+        assert!(adobe.is_in_srgb_gamut());
+        for v in [(255, 255, 255), (0, 0, 0), (120, 50, 200), (7, 128, 33)] {
+            let rgb: RGB = v.try_into().unwrap();
+            assert_eq!(AdobeRGB::from(rgb).to_rgb(), rgb);
+        }
+        let adobe: AdobeRGB = "color(a98-rgb 50% 50% 50%)".try_into().unwrap();
+        assert_eq!(adobe.to_hex().to_string(), "#818181");
+    }
+
+    #[test]
+    fn prophoto_rgb_works() {
+        // the D50 white of ProPhoto is adapted to the D65 white of sRGB
+        let white: ProPhotoRGB = (1.0, 1.0, 1.0).try_into().unwrap();
+        assert!(white.is_in_srgb_gamut());
+        assert_eq!(white.to_rgb().to_string(), "rgb(255,255,255)");
+        let xyz: XYZ = white.into();
+        assert_eq!(xyz.white_point(), WhitePoint::D50);
+        assert_eq!(xyz.to_white_point(WhitePoint::D65).to_string(), "xyz(95.05 100.00 108.91)");
+        for v in [(255, 255, 255), (0, 0, 0), (120, 50, 200), (7, 128, 33)] {
+            let rgb: RGB = v.try_into().unwrap();
+            let prophoto: ProPhotoRGB = rgb.into();
+            assert!(prophoto.is_in_srgb_gamut());
+            assert_eq!(prophoto.to_rgb(), rgb);
+            assert_eq!(ProPhotoRGB::from(rgb.to_xyz()).to_string(), prophoto.to_string());
+        }
+        let blue: ProPhotoRGB = (0.0, 0.0, 1.0).try_into().unwrap();
+        assert!(!blue.is_in_srgb_gamut());
+        assert_eq!(blue.to_rgb().to_string(), "rgb(0,0,255)");
+    }
+
+    #[test]
+    fn hsluv_works() {
+        // taken from the official HSLuv snapshot(rev4)
+        let snapshot = [
+            ("#000000", (0.0, 0.0, 0.0)),
+            ("#FFFFFF", (0.0, 0.0, 100.0)),
+            ("#FF0000", (12.177, 100.0, 53.237)),
+            ("#00FF00", (127.715, 100.0, 87.736)),
+            ("#0000FF", (265.874, 100.0, 32.301)),
+            ("#11AA77", (150.193, 97.997, 61.826)),
+            ("#3366CC", (257.681, 84.582, 45.033)),
+            ("#DD8844", (36.214, 80.516, 64.483)),
+            ("#883399", (297.956, 75.443, 37.667)),
+            ("#777777", (0.0, 0.0, 50.034)),
+            ("#FFEE00", (79.243, 100.0, 92.756)),
+            ("#00CCFF", (226.468, 100.0, 76.316)),
+            ("#220011", (344.489, 100.0, 3.438)),
+            ("#99BB99", (127.715, 25.006, 72.636)),
+        ];
+        for (hex, (h, s, l)) in snapshot {
+            let hex: Hex = hex.try_into().unwrap();
+            let hsluv: HSLuv = hex.into();
+            assert!((hsluv.hue() - h).abs() < 0.001, "{} {}", hex, hsluv);
+            assert!((hsluv.saturation() - s).abs() < 0.001, "{} {}", hex, hsluv);
+            assert!((hsluv.lightness() - l).abs() < 0.001, "{} {}", hex, hsluv);
+            let hsluv: HSLuv = (h, s, l).try_into().unwrap();
+            assert_eq!(hsluv.to_hex().to_string(), hex.to_string());
+        }
+        assert!(HSLuv::try_from("hsluv(361, 50%, 50%)").is_err());
+    }
+
+    #[test]
+    fn cam16_works() {
+        // J, M and hue of the Material color utilities reference implementation
+        let reference = [
+            ((255, 0, 0), (46.445, 89.494, 27.408)),
+            ((0, 255, 0), (79.331, 85.587, 142.139)),
+            ((0, 0, 255), (25.465, 68.867, 282.788)),
+            ((255, 255, 255), (100.0, 2.265, 209.492)),
+        ];
+        for (rgb, (j, m, h)) in reference {
+            let rgb: RGB = rgb.try_into().unwrap();
+            let cam16: Cam16 = rgb.into();
+            let ucs_j = 1.7 * j / (1.0 + 0.007 * j);
+            let ucs_m = (1.0_f32 + 0.0228 * m).ln() / 0.0228;
+            assert!((cam16.j() - ucs_j).abs() < 0.05, "{} {}", rgb, cam16);
+            assert!((cam16.colorfulness() - ucs_m).abs() < 0.05, "{} {}", rgb, cam16);
+            // the sRGB matrix differs slightly from the reference one, which moves the hue of near neutral colors
+            assert!((cam16.hue() - h).abs() < 0.5, "{} {}", rgb, cam16);
+            assert_eq!(cam16.to_rgb(), rgb);
+        }
+        let black: Cam16 = RGB::default().into();
+        assert_eq!(black.j(), 0.0);
+        let white: Cam16 = RGB::try_from((255, 255, 255)).unwrap().into();
+        assert!(white.delta_e_cam16(black) > 10.0);
+        assert_eq!(white.delta_e_cam16(white), 0.0);
+    }
+
+    #[test]
+    fn lms_works() {
+        let rgb: RGB = (255, 255, 255).try_into().unwrap();
+        let cat02 = LMS::from_rgb(rgb, ConeMatrix::Cat02);
+        assert_eq!(cat02.to_string(), "lms(0.9492, 1.0354, 1.0874)");
+        let hpe = cat02.to_matrix(ConeMatrix::HuntPointerEstevez);
+        assert_eq!(hpe.to_string(), "lms(1.0000, 1.0000, 1.0000)");
+        assert_eq!(hpe.matrix(), LMS::from(rgb).matrix());
+        for v in [(0, 0, 0), (10, 200, 30), (250, 5, 99), (64, 64, 192)] {
+            let rgb: RGB = v.try_into().unwrap();
+            for matrix in [ConeMatrix::HuntPointerEstevez, ConeMatrix::Cat02] {
+                assert_eq!(RGB::from(LMS::from_rgb(rgb, matrix)), rgb);
+            }
+            let xyz: XYZ = rgb.to_lms().into();
+            assert_eq!(xyz.to_string(), rgb.to_xyz().to_string());
+        }
+    }
+
+    #[test]
+    fn gray_works() {
+        let rgb: RGB = (95, 45, 155).try_into().unwrap();
+        assert_eq!(Gray::from(rgb).to_hex().to_string(), "#484848");
+        assert_eq!(rgb.to_gray().to_rgb().to_string(), "rgb(72,72,72)");
+        let rgba: RGBA = (0, 0, 0, 0.0).try_into().unwrap();
+        assert_eq!(rgba.to_gray().value(), 255);
+        for (s, v) in [("gray(0%)", 0), ("GRAY(100%)", 255), ("gray( 20 )", 20), ("gray(37.5%)", 96)] {
+            assert_eq!(Gray::try_from(s).unwrap().value(), v);
+        }
+        for s in ["gray(101%)", "gray(256)", "grey(20)", "gray()"] {
+            assert!(Gray::try_from(s).is_err());
+        }
+    }
+
+    #[test]
+    fn rgb16_works() {
+        for v in 0..=255u8 {
+            let rgb: RGB = (v, v, v).try_into().unwrap();
+            let rgb16: RGB16 = rgb.into();
+            assert_eq!(rgb16.red(), v as u16 * 257);
+            assert_eq!(RGB::from(rgb16), rgb);
+        }
+        let hex: Hex = "#0080FF".try_into().unwrap();
+        assert_eq!(hex.to_rgb16().to_string(), "#00008080FFFF");
+        let rgb16: RGB16 = (0x0080, 0x7F7F, 0xFF7F).try_into().unwrap();
+        assert_eq!(rgb16.to_hex().to_string(), "#007FFF");
+        // computed from the 16 bit channels, the 8 bit rounding would make the lightness 51%
+        let rgb16: RGB16 = (33030, 33030, 33030).try_into().unwrap();
+        assert_eq!(rgb16.to_rgb().to_hsl().to_string(), "hsl(0,0%,51%)");
+        assert_eq!(rgb16.to_hsl().to_string(), "hsl(0,0%,50%)");
+        assert_eq!(rgb16.to_hsv().to_string(), "hsv(0,0%,50%)");
+        assert!(RGB16::try_from("#FFFF8000000").is_err());
+        assert!(RGB16::try_from("#FFFF8000000G").is_err());
+    }
+
+    #[test]
+    fn rgbf_works() {
+        for v in 0..=255u8 {
+            let rgb: RGB = (v, 255 - v, v / 2).try_into().unwrap();
+            assert_eq!(rgb.to_rgbf().to_rgb(), rgb);
+        }
+        let rgbf: RGBf = "color(srgb 0.5 0.25 100%)".try_into().unwrap();
+        assert_eq!(rgbf.to_hex().to_string(), "#8040FF");
+        // HDR values are rejected instead of silently clamped
+        assert!(RGBf::try_from((0.5, 1.2, 0.0)).is_err());
+        assert!(RGBf::try_from((0.5, 0.2, -0.1)).is_err());
+        assert!(RGBAf::try_from((0.5, 0.2, 0.1, 1.1)).is_err());
+        assert_eq!(rgbf.scale(4.0).to_array(), [1.0, 1.0, 1.0]);
+        let mut rgbf = rgbf;
+        rgbf.set_red(3.0);
+        assert_eq!(rgbf.red(), 1.0);
+        let rgbaf: RGBAf = (0.0, 0.0, 0.0, 0.0).try_into().unwrap();
+        let white: RGBAf = (1.0, 1.0, 1.0, 1.0).try_into().unwrap();
+        assert_eq!(rgbaf.lerp(white, 0.25).to_array(), [0.25, 0.25, 0.25, 0.25]);
+        assert_eq!(rgbaf.lerp(white, 0.25).to_hex().to_string(), "#40404040");
+        // a NaN t is 0 like RGBA::lerp, instead of leaking NaN into the channels
+        assert_eq!(rgbaf.lerp(white, f32::NAN), rgbaf);
+        assert_eq!(rgbf.lerp(RGBf::default(), f32::NAN), rgbf);
+    }
+
+    #[test]
+    fn rgb565_works() {
+        for v in 0..=u16::MAX {
+            let rgb565: RGB565 = v.try_into().unwrap();
+            assert_eq!(rgb565.to_rgb().to_rgb565(), rgb565);
+        }
+        for (rgb, v) in [((255, 0, 0), 0xF800), ((0, 255, 0), 0x07E0), ((0, 0, 255), 0x001F), ((255, 255, 255), 0xFFFF), ((0, 0, 0), 0)] {
+            let rgb: RGB = rgb.try_into().unwrap();
+            assert_eq!(rgb.to_rgb565().value(), v);
+            assert_eq!(RGB565::try_from(v).unwrap().to_rgb(), rgb);
+        }
+        // rounding instead of truncation, which would give 0
+        let rgb: RGB = (5, 3, 5).try_into().unwrap();
+        assert_eq!(rgb.to_rgb565().to_string(), "rgb565(0x0821)");
+        assert_eq!(rgb.to_rgb565().to_le_bytes(), [0x21, 0x08]);
+    }
+
+    #[test]
+    fn ansi256_works() {
+        // every entry of the cube and the ramp maps back to itself
+        for index in 16..=255u8 {
+            let rgb: RGB = Ansi256::from_index(index).into();
+            assert_eq!(rgb.to_ansi256().to_index(), index);
+        }
+        assert_eq!(Ansi256::from_index(9).to_hex().to_string(), "#FF0000");
+        assert_eq!(Ansi256::from_index(9).to_rgb().to_ansi256().to_index(), 196);
+        // pure grays go to the grayscale ramp rather than the cube
+        for (v, index) in [(10, 232), (50, 236), (100, 241), (128, 244), (200, 251), (238, 255)] {
+            let rgb: RGB = (v, v, v).try_into().unwrap();
+            assert_eq!(rgb.to_ansi256().to_index(), index);
+        }
+        let rgb: RGB = (255, 255, 255).try_into().unwrap();
+        assert_eq!(rgb.to_ansi256().to_index(), 231);
+        let rgb: RGB = (250, 130, 10).try_into().unwrap();
+        assert_eq!(rgb.to_ansi256().to_string(), "ansi256(208)");
+    }
+
+    #[test]
+    fn cmyka_works() {
+        let cmyka: CMYKA = "cmyka(78,0,30,23,0.5)".try_into().unwrap();
+        assert_eq!(cmyka.cyan(), 78);
+        assert_eq!(cmyka.alpha(), 0.5);
+        assert_eq!(cmyka.to_rgba().alpha(), 0.5);
+        assert!(CMYKA::try_from("cmyka(78,0,30,23)").is_err());
+        assert!(CMYKA::try_from((101, 0, 0, 0, 0.5)).is_err());
+        // alpha survives every round trip instead of being composited onto white
+        for a in [0.0, 0.25, 0.5, 1.0] {
+            let rgba: RGBA = (12, 200, 99, a).try_into().unwrap();
+            assert_eq!(rgba.to_cmyka().alpha(), a);
+            assert_eq!(rgba.to_cmyka().to_rgba().alpha(), a);
+            assert_eq!(rgba.to_cmyka().to_hex().to_rgba().alpha(), a);
+            assert_eq!(rgba.to_hsla().to_cmyka().to_hsla().alpha(), a);
+        }
+        let hex: Hex = "#FF000080".try_into().unwrap();
+        assert_eq!(hex.to_cmyka().to_string(), "cmyka(0,100,100,0,0.50)");
+        assert_eq!(hex.to_cmyka().to_hex().to_hex_alpha(), "#FF000080");
+        assert_eq!(hex.to_cmyk().to_string(), "cmyk(0,51,51,0)");
+    }
+
+    #[test]
+    fn kelvin_works() {
+        assert!(Kelvin::try_from(999).is_err());
+        assert!(Kelvin::try_from(40001).is_err());
+        // 6600K is close to white
+        let rgb: RGB = Kelvin::try_from(6600).unwrap().into();
+        assert!(rgb.red() == 255 && rgb.green() >= 250 && rgb.blue() >= 250);
+        // 2000K is a warm orange
+        let rgb: RGB = Kelvin::try_from(2000).unwrap().into();
+        assert!(rgb.red() == 255 && (120..160).contains(&rgb.green()) && rgb.blue() < 40);
+        // higher temperatures get colder
+        let rgb: RGB = Kelvin::try_from(20000).unwrap().into();
+        assert!(rgb.blue() == 255 && rgb.red() < rgb.blue());
+        assert_eq!(Kelvin::try_from(6500).unwrap().to_string(), "6500K");
+
+        let rgb: RGB = (255, 255, 255).try_into().unwrap();
+        assert!((6400..6600).contains(&rgb.estimate_cct().unwrap().value()));
+        for t in [2000, 3000, 4000, 5000] {
+            let rgb: RGB = Kelvin::try_from(t).unwrap().into();
+            let cct = rgb.estimate_cct().unwrap().value();
+            assert!(cct.abs_diff(t) < t / 10, "{} {}", t, cct);
+        }
+        for rgb in [(0, 0, 0), (0, 255, 0), (255, 0, 255), (0, 0, 255)] {
+            let rgb: RGB = rgb.try_into().unwrap();
+            assert_eq!(rgb.estimate_cct(), None);
+        }
+    }
+
+    #[test]
+    fn named_color_works() {
+        let rgb: RGB = "rebeccapurple".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(102,51,153)");
+        let rgb: RGB = " Tomato ".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(255,99,71)");
+        let rgba: RGBA = "RED".try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(255,0,0,1.00)");
+        let hex: Hex = "LightGoldenrodYellow".try_into().unwrap();
+        assert_eq!(hex.to_string(), "#FAFAD2");
+        let hex: Hex = "grey".try_into().unwrap();
+        assert_eq!(hex, Hex::try_from("gray").unwrap());
+
+        let rgba: RGBA = "transparent".try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(0,0,0,0.00)");
+        let hex: Hex = "transparent".try_into().unwrap();
+        assert_eq!(hex.to_hex_alpha(), "#00000000");
+        assert!(RGB::try_from("transparent").is_err());
+
+        match RGB::try_from("notacolor") {
+            Err(ColorError::FormatErr { reason: msg, .. }) => assert!(msg.contains("notacolor")),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(RGBA::try_from("notacolor").is_err());
+        assert!(Hex::try_from("notacolor").is_err());
+    }
+
+    #[test]
+    fn css4_syntax_works() {
+        let rgb: RGB = "rgb(43 196 138)".try_into().unwrap();
+        assert_eq!(rgb, RGB::try_from("rgb(43,196,138)").unwrap());
+        let rgba: RGBA = "rgb(43 196 138 / 0.85)".try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(43,196,138,0.85)");
+        let rgba: RGBA = "rgba(43  196\t138/0.5)".try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+        let rgba: RGBA = "rgb(43,196,138)".try_into().unwrap();
+        assert_eq!(rgba.alpha(), 1.0);
+        let hsl: HSL = "hsl(157 64% 47%)".try_into().unwrap();
+        assert_eq!(hsl.to_string(), "hsl(157,64%,47%)");
+        let hsla: HSLA = "hsl(157 64% 47% / 0.5)".try_into().unwrap();
+        assert_eq!(hsla.to_string(), "hsla(157,64%,47%,0.50)");
+        let hsla: HSLA = "hsla(157, 64%, 47%, 0.5)".try_into().unwrap();
+        assert_eq!(hsla.to_string(), "hsla(157,64%,47%,0.50)");
+        let hsv: HSV = "hsv(157 64% 47%)".try_into().unwrap();
+        assert_eq!(hsv, HSV::try_from("hsv(157,64%,47%)").unwrap());
+
+        // mixing commas and the slash, or a misplaced slash, is rejected
+        for s in ["rgb(43,196,138 / 0.5)", "rgb(43 196, 138)", "rgb(43 196 / 138 0.5)", "rgb(43 196 138 /)", "rgb(43 196 138 / 0.5 / 1)", "rgb(43,,196,138)"] {
+            assert!(RGBA::try_from(s).is_err(), "{}", s);
+        }
+        assert!(HSLA::try_from("hsl(157,64%,47% / 0.5)").is_err());
+        // the alpha is an error for the types without alpha
+        match RGB::try_from("rgb(43 196 138 / 0.85)") {
+            Err(ColorError::ParseErr { reason, .. }) => assert!(reason.contains("RGBA")),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(HSL::try_from("hsl(157 64% 47% / 0.5)").is_err());
+        assert!(HSV::try_from("hsv(157 64% 47% / 0.5)").is_err());
+    }
+
+    #[test]
+    fn rgb_percent_works() {
+        let rgb: RGB = "rgb(100%, 50%, 0%)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(255,128,0)");
+        let rgb: RGB = "rgb(20% 40.5% 99.9%)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(51,103,255)");
+        // values over 100% clamp instead of erroring
+        let rgb: RGB = "rgb(150%, 0%, 0%)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(255,0,0)");
+        let rgba: RGBA = "rgba(100%, 50%, 0%, 0.5)".try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(255,128,0,0.50)");
+        let rgba: RGBA = "rgb(0% 0% 100% / 0.25)".try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(0,0,255,0.25)");
+        // mixing percentages and numbers is rejected
+        assert!(RGB::try_from("rgb(100%, 128, 0%)").is_err());
+        assert!(RGBA::try_from("rgba(100%, 128, 0, 0.5)").is_err());
+        assert!(RGB::try_from("rgb(abc%, 0%, 0%)").is_err());
+    }
+
+    #[test]
+    fn hue_units_work() {
+        let expected: HSL = (180, 64, 47).try_into().unwrap();
+        for s in ["hsl(180, 64%, 47%)", "hsl(180deg, 64%, 47%)", "hsl(0.5turn, 64%, 47%)", "hsl(200grad 64% 47%)", "hsl(3.14159rad 64% 47%)", "hsl(179.6 64% 47%)"] {
+            assert_eq!(HSL::try_from(s).unwrap(), expected, "{}", s);
+        }
+        // angles with a unit wrap into 0~360
+        let hsl: HSL = "hsl(1turn, 64%, 47%)".try_into().unwrap();
+        assert_eq!(hsl.hue(), 0);
+        let hsl: HSL = "hsl(-1.5708rad, 64%, 47%)".try_into().unwrap();
+        assert_eq!(hsl.hue(), 270);
+        let hsl: HSL = "hsl(-90deg 64% 47%)".try_into().unwrap();
+        assert_eq!(hsl.hue(), 270);
+        let hsl: HSL = "hsl(359.7deg 64% 47%)".try_into().unwrap();
+        assert_eq!(hsl.hue(), 0);
+        let hsla: HSLA = "hsl(0.25turn 64% 47% / 0.5)".try_into().unwrap();
+        assert_eq!(hsla.to_string(), "hsla(90,64%,47%,0.50)");
+        let hsv: HSV = "hsv(0.75turn, 50%, 50%)".try_into().unwrap();
+        assert_eq!(hsv.hue(), 270);
+        assert!(HSL::try_from("hsl(1lightyear, 64%, 47%)").is_err());
+    }
+
+    #[test]
+    fn hex_rgba_shorthand_works() {
+        let hex: Hex = "#FA08".try_into().unwrap();
+        assert_eq!(hex, Hex::try_from("#FFAA0088").unwrap());
+        assert_eq!(hex.to_rgba().alpha(), 136.0 / 255.0);
+        assert_eq!(hex.to_rgba().alpha(), 0.533_333_36);
+        assert_eq!(hex.to_string(), "#FFAA0088");
+        assert_eq!(hex.to_hex_alpha(), "#FFAA0088");
+        let hex: Hex = "#fa0f".try_into().unwrap();
+        assert_eq!(hex.to_string(), "#FFAA00");
+        // every alpha digit survives the round trip
+        for d in "0123456789ABCDEF".chars() {
+            let hex: Hex = format!("#000{}", d).as_str().try_into().unwrap();
+            assert_eq!(hex.to_hex_alpha(), format!("#000000{}{}", d, d));
+        }
+        assert!(Hex::try_from("#FA0G").is_err());
+    }
+
+    #[test]
+    fn hex_prefix_works() {
+        let expected: Hex = "#2BC48A".try_into().unwrap();
+        for s in ["2bc48a", "0x2BC48A", "0X2bc48a", " #2bc48a "] {
+            assert_eq!(Hex::try_from(s).unwrap(), expected, "{}", s);
+        }
+        let hex: Hex = "0X2BC48AFF".try_into().unwrap();
+        assert_eq!(hex, expected);
+        let hex: Hex = "fa08".try_into().unwrap();
+        assert_eq!(hex.to_string(), "#FFAA0088");
+        let hex: Hex = "0xfac".try_into().unwrap();
+        assert_eq!(hex.to_string(), "#FFAACC");
+        // 4 digits is the #RGBA shorthand, other lengths are garbage
+        assert_eq!(Hex::try_from("2bc4").unwrap().to_hex_alpha(), "#22BBCC44");
+        for s in ["2bc48", "2bc48aa", "0x2bc48", "#2bc48a0", "0x", "", "#", "2bc48g", "0x#2bc48a", "##2bc48a"] {
+            match Hex::try_from(s) {
+                Err(ColorError::FormatErr { reason: msg, .. }) => assert!(msg.contains(&format!("'{}'", s)), "{}", msg),
+                other => panic!("{} unexpected {:?}", s, other),
+            }
+        }
+    }
+
+    #[test]
+    fn from_str_works() {
+        use std::str::FromStr;
+        assert_eq!("#2bc48a".parse::<Hex>().unwrap(), Hex::try_from("#2bc48a").unwrap());
+        assert_eq!("rgb(43,196,138)".parse::<RGB>().unwrap(), RGB::try_from("rgb(43,196,138)").unwrap());
+        assert_eq!("rgba(43,196,138,0.5)".parse::<RGBA>().unwrap(), RGBA::try_from("rgba(43,196,138,0.5)").unwrap());
+        assert_eq!("hsl(157,64%,47%)".parse::<HSL>().unwrap(), HSL::try_from("hsl(157,64%,47%)").unwrap());
+        assert_eq!("hsla(157,64%,47%,0.5)".parse::<HSLA>().unwrap(), HSLA::try_from("hsla(157,64%,47%,0.5)").unwrap());
+        assert_eq!("hsv(157,78%,77%)".parse::<HSV>().unwrap(), HSV::try_from("hsv(157,78%,77%)").unwrap());
+        assert_eq!("cmyk(78,0,30,23)".parse::<CMYK>().unwrap(), CMYK::try_from("cmyk(78,0,30,23)").unwrap());
+        assert_eq!(CMYKA::from_str("cmyka(78,0,30,23,0.5)").unwrap(), CMYKA::try_from("cmyka(78,0,30,23,0.5)").unwrap());
+        // the errors are the same too
+        assert_eq!(format!("{:?}", "rgb(43,196)".parse::<RGB>()), format!("{:?}", RGB::try_from("rgb(43,196)")));
+        assert_eq!(format!("{:?}", "#2bc48".parse::<Hex>()), format!("{:?}", Hex::try_from("#2bc48")));
+        assert_eq!(format!("{:?}", "hsl(0,101%,0%)".parse::<HSL>()), format!("{:?}", HSL::try_from("hsl(0,101%,0%)")));
+
+        fn parse_all<T: FromStr<Err = ColorError>>(list: &[&str]) -> Result<Vec<T>, ColorError> {
+            list.iter().map(|s| s.parse()).collect()
+        }
+        let colors: Vec<RGB> = parse_all(&["red", "rgb(0 0 255)"]).unwrap();
+        assert_eq!(colors[1].blue(), 255);
+    }
+
+    #[test]
+    fn parse_works() {
+        for (s, expected) in [
+            ("#2bc48a", "#2BC48A"),
+            ("0x2bc48a", "#2BC48A"),
+            ("rgb(43,196,138)", "rgb(43,196,138)"),
+            ("rgb(43 196 138 / 0.5)", "rgba(43,196,138,0.50)"),
+            ("rgba(43,196,138,0.5)", "rgba(43,196,138,0.50)"),
+            ("hsl(157,64%,47%)", "hsl(157,64%,47%)"),
+            ("hsla(157,64%,47%,0.5)", "hsla(157,64%,47%,0.50)"),
+            ("hsv(157,78%,77%)", "hsv(157,78%,77%)"),
+            ("cmyk(78,0,30,23)", "cmyk(78,0,30,23)"),
+            ("hwb(157 17% 23%)", "hwb(157 17% 23%)"),
+            ("tomato", "rgb(255,99,71)"),
+            ("transparent", "rgba(0,0,0,0.00)"),
+            ("#FFFF80000000", "#FFFF80000000"),
+        ] {
+            assert_eq!(parse(s).unwrap().to_string(), expected, "{}", s);
+        }
+        assert!(matches!(parse("rgb(43,196,138)").unwrap(), AnyColor::RGB(_)));
+        assert!(matches!(parse("rgb(43 196 138 / 0.5)").unwrap(), AnyColor::RGBA(_)));
+        assert!(matches!(parse("color(display-p3 1 0 0)").unwrap(), AnyColor::P3(_)));
+        assert!(matches!(parse("color(srgb 1 0 0)").unwrap(), AnyColor::RGBf(_)));
+        assert_eq!(parse("hsl(157 64% 47% / 0.5)").unwrap().to_rgba().to_string(), "rgba(43,196,138,0.50)");
+        assert_eq!(parse("#2bc48a").unwrap().to_rgb(), parse("rgb(43,196,138)").unwrap().to_rgb());
+        assert_eq!(parse("red").unwrap().to_hex().to_string(), "#FF0000");
+
+        match parse("notacolor") {
+            Err(ColorError::FormatErr { reason: msg, .. }) => assert!(msg.contains("notacolor") && msg.contains("hsl()") && msg.contains("named colors"), "{}", msg),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(parse("foo(1,2,3)").is_err());
+        // a bare word is not taken as hex, only the explicit Hex parser accepts it
+        assert!(parse("bad").is_err());
+        assert!(parse("2bc48a").is_err());
+        assert!(parse("decade").is_err());
+        assert!(matches!(parse("0xBADFACE0").unwrap(), AnyColor::Hex(_)));
+        assert!(Hex::try_from("bad").is_ok());
+        assert!(parse("rgb(43,196)").is_err());
+        assert!(parse("color(xyz 1 0 0)").is_err());
+    }
+
+    #[test]
+    fn transparent_works() {
+        let rgba: RGBA = "transparent".try_into().unwrap();
+        assert_eq!(rgba, RGBA::TRANSPARENT);
+        let hsla: HSLA = " Transparent ".try_into().unwrap();
+        assert_eq!(hsla, HSLA::TRANSPARENT);
+        assert_eq!(hsla.to_string(), "hsla(0,0%,0%,0.00)");
+        let hex: Hex = "transparent".try_into().unwrap();
+        assert_eq!(hex.to_string(), "#00000000");
+        assert_eq!(Hex::from(RGBA::TRANSPARENT).to_string(), "#00000000");
+        assert_eq!(RGBA::from(HSLA::TRANSPARENT), RGBA::TRANSPARENT);
+        // RGB has no alpha, so transparent is composited onto white
+        assert_eq!(RGB::from(RGBA::TRANSPARENT).to_string(), "rgb(255,255,255)");
+        assert_eq!(hex.to_rgb().to_string(), "rgb(255,255,255)");
+        assert_eq!(HSLA::TRANSPARENT.to_rgb().to_string(), "rgb(255,255,255)");
+        assert!(RGB::try_from("transparent").is_err());
+    }
+
+    #[test]
+    fn hue_wrapping_works() {
+        let expected: HSL = (120, 64, 47).try_into().unwrap();
+        assert_eq!(HSL::try_from("hsl(480, 64%, 47%)").unwrap(), expected);
+        assert_eq!(HSL::try_from("hsl(-240 64% 47%)").unwrap(), expected);
+        assert_eq!(HSL::try_from("hsl(-120, 64%, 47%)").unwrap().hue(), 240);
+        assert_eq!(HSL::try_from("hsl(360, 64%, 47%)").unwrap().hue(), 0);
+        assert_eq!(HSL::try_from("hsl(-0.4, 64%, 47%)").unwrap().hue(), 0);
+        assert_eq!(HSLA::try_from("hsla(-120, 64%, 47%, 0.5)").unwrap().hue(), 240);
+        assert_eq!(HSV::try_from("hsv(720, 64%, 47%)").unwrap().hue(), 0);
+        assert_eq!(HSL::new_wrapping(480, 64, 47).unwrap(), expected);
+        assert_eq!(HSL::new_wrapping(-120, 64, 47).unwrap().hue(), 240);
+        assert_eq!(HSV::new_wrapping(-1, 64, 47).unwrap().hue(), 359);
+        assert_eq!(HSLA::new_wrapping(-3600, 64, 47, 0.5).unwrap().hue(), 0);
+        // saturation and lightness are still validated
+        assert!(HSL::try_from("hsl(480, 101%, 47%)").is_err());
+        assert!(HSL::new_wrapping(0, 64, 101).is_err());
+        assert!(HSLA::new_wrapping(0, 64, 47, 1.5).is_err());
+        // the tuple form is still strict
+        assert!(HSL::try_from((480, 64, 47)).is_err());
+    }
+
+    #[test]
+    fn rgb_fraction_works() {
+        let rgb: RGB = "rgb(43.6, 196.2, 138.0)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(44,196,138)");
+        // .5 rounds away from zero
+        let rgb: RGB = "rgb(0.5 1.5 254.5)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(1,2,255)");
+        let rgb: RGB = "rgb(0.49, 1.49, 254.49)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(0,1,254)");
+        // scientific notation
+        let rgb: RGB = "rgb(1e2, 2.55e2, 0e0)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(100,255,0)");
+        let rgba: RGBA = "rgba(43.6, 196.2, 138, 0.5)".try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(44,196,138,0.50)");
+        let rgb: RGB = "rgb(-0.4, 0, 255.4)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(0,0,255)");
+        // out of range after rounding is an error
+        for s in ["rgb(255.5, 0, 0)", "rgb(-0.5, 0, 0)", "rgb(300, 0, 0)", "rgb(1e3, 0, 0)"] {
+            assert!(RGB::try_from(s).unwrap_err().to_string().contains("out of range"), "{}", s);
+        }
+        assert!(RGBA::try_from("rgba(256, 0, 0, 0.5)").unwrap_err().to_string().contains("out of range"));
+        assert!(RGB::try_from("rgb(nan, 0, 0)").unwrap_err().to_string().contains("invalid number"));
+        assert!(RGB::try_from("rgb(inf, 0, 0)").unwrap_err().to_string().contains("invalid number"));
+    }
+
+    #[test]
+    fn percent_alpha_works() {
+        let rgba: RGBA = "rgba(43,196,138,85%)".try_into().unwrap();
+        assert_eq!(rgba.alpha(), 0.85);
+        let rgba: RGBA = "rgb(43 196 138 / 50%)".try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+        let hsla: HSLA = "hsla(157,64%,47%,50%)".try_into().unwrap();
+        assert_eq!(hsla.alpha(), 0.5);
+        let hsla: HSLA = "hsl(157deg 64% 47% / 85%)".try_into().unwrap();
+        assert_eq!(hsla.to_string(), "hsla(157,64%,47%,0.85)");
+        // no leading zero
+        let rgba: RGBA = "rgba(43,196,138,.85)".try_into().unwrap();
+        assert_eq!(rgba.alpha(), 0.85);
+        let hsla: HSLA = "hsla(157,64%,47%,.5)".try_into().unwrap();
+        assert_eq!(hsla.alpha(), 0.5);
+        // over 100% clamps, but a bare number over 1 is still an error
+        let rgba: RGBA = "rgba(43,196,138,150%)".try_into().unwrap();
+        assert_eq!(rgba.alpha(), 1.0);
+        assert!(RGBA::try_from("rgba(43,196,138,1.5)").is_err());
+        assert!(RGBA::try_from("rgba(43,196,138,-10%)").is_err());
+        assert!(HSLA::try_from("hsla(157,64%,47%,abc%)").is_err());
+    }
+
+    #[test]
+    fn parse_err_works() {
+        let check = |err: ColorError, component: Option<usize>, reason: &str| match err {
+            ColorError::ParseErr { component: c, reason: r, .. } => {
+                assert_eq!(c, component);
+                assert_eq!(r, reason);
+            }
+            other => panic!("unexpected {:?}", other),
+        };
+        check(RGB::try_from("rgb(43,196)").unwrap_err(), None, "expected 3 components, found 2");
+        check(RGB::try_from("rgb(43,abc,138)").unwrap_err(), Some(1), "invalid number 'abc'");
+        check(RGB::try_from("rgb(43,196,300)").unwrap_err(), Some(2), "value 300 out of range 0..=255");
+        check(RGB::try_from("rgb(100%,50,0)").unwrap_err(), None, "percentages and numbers can not be mixed");
+        check(RGB::try_from("rgb(43,196 / 138)").unwrap_err(), None, "commas and '/' can not be mixed");
+        check(RGBA::try_from("rgba(43,196,138,1.5)").unwrap_err(), Some(3), "value 1.5 out of range 0..=1");
+        check(RGBA::try_from("rgb(43 196 138 / x)").unwrap_err(), Some(3), "invalid number 'x'");
+        check(HSL::try_from("hsl(157,64%)").unwrap_err(), None, "expected 3 components, found 2");
+        check(HSL::try_from("hsl(abc,64%,47%)").unwrap_err(), Some(0), "invalid hue 'abc'");
+        check(HSLA::try_from("hsla(157,64%,147%,0.5)").unwrap_err(), Some(2), "value 147% out of range 0..=100");
+        check(HSV::try_from("hsv(157,64%,47% / 1)").unwrap_err(), None, "commas and '/' can not be mixed");
+
+        // the detail is part of the Display output
+        let err = RGB::try_from("rgb(43,abc,138)").unwrap_err();
+        assert_eq!(err.to_string(), "'rgb(43,abc,138)' component 1 error: invalid number 'abc'");
+        let err = RGB::try_from("rgb(43,196)").unwrap_err();
+        assert_eq!(err.to_string(), "'rgb(43,196)' format error: expected 3 components, found 2");
+        // strings in an unknown format are still a FormatErr
+        assert!(matches!(RGB::try_from("hsl(157,64%,47%)"), Err(ColorError::FormatErr { .. })));
+        let err: Box<dyn std::error::Error> = Box::new(err);
+        assert!(err.to_string().contains("expected 3 components"));
+    }
+
+    #[test]
+    fn parse_options_works() {
+        let strict = ParseOptions::strict();
+        let lenient = ParseOptions::lenient();
+        let default = ParseOptions::default();
+        // the default options are what TryFrom uses
+        for s in ["rgb(300,0,0)", " rgb(43,196,138) ", "RGB(43,196,138)", "tomato", "rgb(43,196)"] {
+            assert_eq!(format!("{:?}", RGB::parse_with(s, &default)), format!("{:?}", RGB::try_from(s)), "{}", s);
+        }
+        assert_eq!(format!("{:?}", HSL::parse_with("hsl(157,64,47)", &default)), format!("{:?}", HSL::try_from("hsl(157,64,47)")));
+
+        // clamp_out_of_range
+        assert_eq!(RGB::parse_with("rgb(300, -5, 128)", &lenient).unwrap().to_string(), "rgb(255,0,128)");
+        assert_eq!(RGBA::parse_with("rgba(0, 0, 0, 1.5)", &lenient).unwrap().alpha(), 1.0);
+        assert_eq!(HSL::parse_with("hsl(157, 120%, -3%)", &lenient).unwrap().to_string(), "hsl(157,100%,0%)");
+        assert_eq!(HSLA::parse_with("hsla(157, 64%, 47%, -1)", &lenient).unwrap().alpha(), 0.0);
+        assert!(RGB::parse_with("rgb(300, 0, 0)", &default).is_err());
+
+        // allow_percent_omission
+        assert!(HSL::parse_with("hsl(157, 64, 47)", &lenient).is_ok());
+        assert!(HSL::parse_with("hsl(157, 64, 47%)", &strict).is_err());
+        assert!(HSV::parse_with("hsv(157, 64, 47)", &strict).is_err());
+        assert!(HSLA::parse_with("hsla(157,64%,47%,0.5)", &strict).is_ok());
+
+        // allow_named_colors
+        assert!(RGB::parse_with("tomato", &strict).is_err());
+        assert!(RGBA::parse_with("transparent", &strict).is_err());
+        assert!(HSLA::parse_with("transparent", &strict).is_err());
+        assert!(Hex::parse_with("tomato", &strict).is_err());
+        assert_eq!(Hex::parse_with("tomato", &lenient).unwrap().to_string(), "#FF6347");
+
+        // require_exact_syntax
+        assert!(RGB::parse_with("rgb(43,196,138)", &strict).is_ok());
+        assert!(RGB::parse_with(" rgb(43,196,138)", &strict).is_err());
+        assert!(RGB::parse_with("RGB(43,196,138)", &strict).is_err());
+        assert!(Hex::parse_with("#2BC48A", &strict).is_ok());
+        assert!(Hex::parse_with("2BC48A", &strict).is_err());
+        assert!(Hex::parse_with("0x2BC48A", &strict).is_err());
+        assert!(Hex::parse_with(" #2BC48A", &strict).is_err());
+        let exact_only = ParseOptions { require_exact_syntax: true, ..ParseOptions::default() };
+        assert!(RGB::parse_with("tomato", &exact_only).is_ok());
+    }
+
+    #[test]
+    fn u32_works() {
+        let hex: Hex = 0x2BC48Au32.try_into().unwrap();
+        assert_eq!(hex.to_string(), "#2BC48A");
+        let hex: Hex = 0x0000FFu32.try_into().unwrap();
+        assert_eq!(hex.to_string(), "#0000FF");
+        assert!(Hex::try_from(0x12BC48Au32).is_err());
+
+        let rgb = RGB::from_u32(0x0000FF);
+        assert_eq!(rgb.to_string(), "rgb(0,0,255)");
+        assert_eq!(rgb.to_u32(), 0x0000FF);
+        // RGB takes the low 24 bits
+        assert_eq!(RGB::from_u32(0xFF2BC48A).to_string(), "rgb(43,196,138)");
+        assert_eq!(RGB::from_u32(0xFF2BC48A).to_u32(), 0x2BC48A);
+
+        let rgba = RGBA::from_u32(0x2BC48A80);
+        assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+        assert_eq!(rgba.to_u32(), 0x2BC48A80);
+        let rgba = RGBA::from_u32(0x000000FF);
+        assert_eq!(rgba.to_string(), "rgba(0,0,0,1.00)");
+        let rgba = RGBA::from_argb_u32(0x802BC48A);
+        assert_eq!(rgba, RGBA::from_u32(0x2BC48A80));
+        assert_eq!(rgba.to_argb_u32(), 0x802BC48A);
+        assert_eq!(RGBA::from_argb_u32(0x000000FF).to_string(), "rgba(0,0,255,0.00)");
+        for v in [0u32, 0x01, 0xFF, 0x0000FF00, 0xFFFFFFFF, 0x12345678, 0x00FF00FF] {
+            assert_eq!(RGBA::from_u32(v).to_u32(), v);
+            assert_eq!(RGBA::from_argb_u32(v).to_argb_u32(), v);
+        }
+    }
+
+    #[test]
+    fn byte_array_works() {
+        let rgb: RGB = [43, 196, 138].into();
+        assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+        assert_eq!(rgb.to_array(), [43, 196, 138]);
+        let bytes: [u8; 3] = rgb.into();
+        assert_eq!(bytes, [43, 196, 138]);
+        let rgb = RGB::try_from(&[0u8, 0, 255][..]).unwrap();
+        assert_eq!(rgb.to_string(), "rgb(0,0,255)");
+        assert!(RGB::try_from(&[0u8, 0][..]).is_err());
+        assert!(RGB::try_from(&[0u8, 0, 0, 0][..]).is_err());
+
+        let rgba: RGBA = [43, 196, 138, 128].into();
+        assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+        let bytes: [u8; 4] = rgba.into();
+        assert_eq!(bytes, [43, 196, 138, 128]);
+        assert!(RGBA::try_from(&[0u8, 0, 0][..]).is_err());
+        let err = RGBA::try_from(&[0u8; 5][..]).unwrap_err();
+        assert!(format!("{:?}", err).contains("found 5"));
+
+        // every alpha byte survives the round trip
+        for a in 0..=255u8 {
+            let rgba: RGBA = [1, 2, 3, a].into();
+            assert_eq!(rgba.to_array(), [1, 2, 3, a]);
+        }
+        let pixels = [0u8, 0, 0, 0, 255, 255, 255, 255];
+        let colors = pixels
+            .chunks_exact(4)
+            .map(RGBA::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(colors, vec![RGBA::TRANSPARENT, RGBA::from_u32(0xFFFFFFFF)]);
+    }
+
+    #[test]
+    fn color_function_works() {
+        let rgb: RGB = "color(srgb 0.169 0.769 0.541)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+        let rgb: RGB = "color(srgb 100% 50% 0%)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(255,128,0)");
+        let rgb: RGB = "color(srgb-linear 0.2159 0.2159 0.2159)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(128,128,128)");
+        let rgb: RGB = "color(display-p3 0.9175 0.2003 0.1386)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(255,0,0)");
+        assert!(RGB::try_from("color(srgb 0.169 0.769 0.541 / 0.5)").is_err());
+
+        let rgba: RGBA = "color(srgb 0.169 0.769 0.541 / 0.5)".try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+        let rgba: RGBA = "color(srgb 0.169 0.769 0.541 / 25%)".try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(43,196,138,0.25)");
+        let rgba: RGBA = "color(display-p3 0.17 0.77 0.54)".try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(0,200,133,1.00)");
+
+        let err = RGBA::try_from("color(xyz 0.1 0.2 0.3)").unwrap_err();
+        assert!(err.to_string().contains("unknown color space 'xyz'"));
+        assert!(err.to_string().contains("srgb-linear"));
+        let err = RGB::try_from("color(srgb 1.5 0 0)").unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+        let rgb = RGB::parse_with("color(srgb 1.5 0 0)", &ParseOptions::lenient()).unwrap();
+        assert_eq!(rgb.to_string(), "rgb(255,0,0)");
+        assert!(RGB::try_from("color(srgb 1, 0, 0)").is_err());
+        assert!(RGB::try_from("color(srgb 1 0)").is_err());
+        let color = crate::parse("color(srgb 0.169 0.769 0.541 / 0.5)").unwrap();
+        assert!(matches!(color, AnyColor::RGBA(_)));
+        let err = crate::parse("color(xyz 0.1 0.2 0.3)").unwrap_err();
+        assert!(err.to_string().contains("unknown color space"));
+        // parse dispatches on the color space, a wide gamut color with an alpha is not clipped into RGBA
+        assert!(matches!(crate::parse("color(srgb-linear 1 0 0)").unwrap(), AnyColor::RGBA(_)));
+        assert!(matches!(crate::parse("COLOR(Rec2020 1 0 0)").unwrap(), AnyColor::Rec2020(_)));
+        assert!(matches!(crate::parse("color(a98-rgb 1 0 0)").unwrap(), AnyColor::AdobeRGB(_)));
+        assert!(matches!(crate::parse("color(prophoto-rgb 1 0 0)").unwrap(), AnyColor::ProPhotoRGB(_)));
+        let err = crate::parse("color(display-p3 1 0 0 / 0.5)").unwrap_err();
+        assert!(err.to_string().contains("P3 has no alpha"), "{}", err);
+    }
+
+    #[test]
+    fn device_cmyk_and_gray_works() {
+        let cmyk: CMYK = "device-cmyk(0.78 0 0.3 0.23)".try_into().unwrap();
+        assert_eq!(cmyk.to_string(), "cmyk(78,0,30,23)");
+        let cmyk: CMYK = "device-cmyk(78%, 0%, 30%, 23%)".try_into().unwrap();
+        assert_eq!(cmyk.to_string(), "cmyk(78,0,30,23)");
+        let cmyk: CMYK = "DEVICE-CMYK(1.5 -0.2 50% 200%)".try_into().unwrap();
+        assert_eq!(cmyk.to_string(), "cmyk(100,0,50,100)");
+        assert!(CMYK::try_from("device-cmyk(0.78 0 0.3)").is_err());
+        assert!(CMYK::try_from("device-cmyk(0.78 0 0.3 0.23 / 0.5)").is_err());
+        let cmyk: CMYK = "cmyk(78,0,30,23)".try_into().unwrap();
+        assert_eq!(cmyk.to_string(), "cmyk(78,0,30,23)");
+        assert!(matches!(
+            crate::parse("device-cmyk(0 1 1 0)").unwrap(),
+            AnyColor::CMYK(_)
+        ));
+
+        let rgb: RGB = "gray(50%)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(128,128,128)");
+        let rgb: RGB = "gray(64)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(64,64,64)");
+        let rgb: RGB = "gray(150%)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(255,255,255)");
+        let rgb: RGB = "gray(-10)".try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(0,0,0)");
+        assert!(RGB::try_from("gray(1 2)").is_err());
+        assert!(RGB::try_from("gray(half)").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_works() {
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        struct Theme {
+            primary: Hex,
+            text: RGB,
+            overlay: RGBA,
+            accent: HSL,
+            shadow: HSLA,
+            highlight: HSV,
+            print: CMYK,
+        }
+        let json = r##"{
+            "primary": "#2bc48a",
+            "text": "rgb(43 196 138)",
+            "overlay": "rgba(0,0,0,0.5)",
+            "accent": "hsl(157,64%,47%)",
+            "shadow": "transparent",
+            "highlight": "hsv(157,78%,77%)",
+            "print": "device-cmyk(0.78 0 0.3 0.23)"
+        }"##;
+        let theme: Theme = serde_json::from_str(json).unwrap();
+        assert_eq!(theme.primary.to_string(), "#2BC48A");
+        assert_eq!(theme.text.to_string(), "rgb(43,196,138)");
+        assert_eq!(
+            serde_json::to_string(&theme).unwrap(),
+            r##"{"primary":"#2BC48A","text":"rgb(43,196,138)","overlay":"rgba(0,0,0,0.50)","accent":"hsl(157,64%,47%)","shadow":"hsla(0,0%,0%,0.00)","highlight":"hsv(157,78%,77%)","print":"cmyk(78,0,30,23)"}"##
+        );
+
+        let err = serde_json::from_str::<RGB>(r#""rgb(300,0,0)""#).unwrap_err();
+        assert!(err.to_string().contains("value 300 out of range 0..=255"));
+        assert!(serde_json::from_str::<Hex>("42").is_err());
+    }
+
+    // the parsers match the input in place instead of trimming and lowercasing a copy,
+    // so any casing and padding must parse exactly like the canonical string
+    proptest::proptest! {
+        #[test]
+        fn parse_ignores_case_and_whitespace(
+            r in 0u8..=255,
+            g in 0u8..=255,
+            b in 0u8..=255,
+            a in 0u8..=100,
+            modern in proptest::bool::ANY,
+            upper in proptest::collection::vec(proptest::bool::ANY, 32),
+            pad in "[ \t]{0,2}",
+        ) {
+            let mixed = |s: &str| -> String {
+                let s = s
+                    .chars()
+                    .zip(upper.iter().cycle())
+                    .map(|(c, up)| if *up { c.to_ascii_uppercase() } else { c })
+                    .collect::<String>();
+                format!("{}{}{}", pad, s, pad)
+            };
+            let a = a as f32 / 100.0;
+            let rgba: RGBA = (r, g, b, a).try_into().unwrap();
+            let hsla: HSLA = rgba.into();
+            let hsv: HSV = rgba.into();
+            let rgb = RGB { r, g, b };
+            let cmyk: CMYK = rgb.into();
+            let (rgb_str, rgba_str, hsla_str) = if modern {
+                (
+                    format!("rgb({} {} {})", r, g, b),
+                    format!("rgb({} {} {} / {})", r, g, b, a),
+                    format!("hsl({}deg {}% {}% / {})", hsla.hue(), hsla.saturation(), hsla.lightness(), a),
+                )
+            } else {
+                (
+                    format!("rgb({},{},{})", r, g, b),
+                    format!("rgba({}, {}, {}, {})", r, g, b, a),
+                    format!("hsla({}, {}%, {}%, {})", hsla.hue(), hsla.saturation(), hsla.lightness(), a),
+                )
+            };
+            let hsv_str = format!("hsv({},{}%,{}%)", hsv.hue(), hsv.saturation(), hsv.value());
+            let hex_str = Hex::from(rgba).to_string();
+            let cmyk_str = cmyk.to_string();
+            for s in [&rgb_str, &rgba_str, &hsla_str, &hsv_str, &hex_str, &cmyk_str] {
+                proptest::prop_assert_eq!(
+                    format!("{:?}", crate::parse(&mixed(s))),
+                    format!("{:?}", crate::parse(s))
+                );
+            }
+            proptest::prop_assert_eq!(RGB::try_from(mixed(&rgb_str).as_str()).unwrap(), rgb);
+            proptest::prop_assert_eq!(RGBA::try_from(mixed(&rgba_str).as_str()).unwrap(), rgba);
+            proptest::prop_assert_eq!(
+                HSLA::try_from(mixed(&hsla_str).as_str()).unwrap(),
+                HSLA::try_from(hsla_str.as_str()).unwrap()
+            );
+            proptest::prop_assert_eq!(
+                HSV::try_from(mixed(&hsv_str).as_str()).unwrap(),
+                HSV::try_from(hsv_str.as_str()).unwrap()
+            );
+            proptest::prop_assert_eq!(CMYK::try_from(mixed(&cmyk_str).as_str()).unwrap(), cmyk);
+            proptest::prop_assert_eq!(Hex::try_from(mixed(&hex_str).as_str()).unwrap().to_string(), hex_str);
+        }
+    }
+
+    /// The components of "name(a,b,c)" the way the allocating parsers found them, before the rewrite
+    fn old_components(color_str: &str, name: &str) -> Option<Vec<String>> {
+        let color = color_str.trim().to_lowercase();
+        let prefix = format!("{}(", name);
+        if !(color.starts_with(&prefix) && color.ends_with(')')) {
+            return None;
+        }
+        let color = color.replace(&prefix, "").replace(')', "");
+        Some(color.split(',').map(|s| s.to_string()).collect())
+    }
+
+    /// The allocating `parse_color_function` before the rewrite
+    fn old_color_function(color_str: &str, space: &str) -> Option<(f32, f32, f32)> {
+        let color = color_str.trim().to_lowercase();
+        let color = color.strip_prefix("color(")?.strip_suffix(')')?;
+        let tmp = color.split_whitespace().collect::<Vec<_>>();
+        if tmp.len() != 4 || tmp[0] != space {
+            return None;
+        }
+        let val = tmp[1..]
+            .iter()
+            .map(|s| match s.strip_suffix('%') {
+                Some(s) => s.parse::<f32>().ok().map(|v| v / 100.0),
+                None => s.parse::<f32>().ok(),
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some((val[0], val[1], val[2]))
+    }
+
+    /// Parse the string with the allocating parsers before the rewrite, None when they reject it
+    fn old_parse(color_str: &str) -> Option<AnyColor> {
+        let u8s = |tmp: &[String], trim: bool| {
+            tmp.iter()
+                .map(|s| if trim { s.trim() } else { s.as_str() }.parse::<u8>().ok())
+                .collect::<Option<Vec<_>>>()
+        };
+        let u32s = |tmp: &[String]| {
+            tmp.iter()
+                .map(|s| s.trim().trim_end_matches('%').parse::<u32>().ok())
+                .collect::<Option<Vec<_>>>()
+        };
+        if let Some(tmp) = old_components(color_str, "rgb").filter(|tmp| tmp.len() == 3) {
+            let v = u8s(&tmp, true)?;
+            return RGB::try_from((v[0], v[1], v[2])).ok().map(AnyColor::RGB);
+        }
+        if let Some(tmp) = old_components(color_str, "rgba").filter(|tmp| tmp.len() == 4) {
+            let (v, a) = (u8s(&tmp[..3], true)?, tmp[3].trim().parse::<f32>().ok()?);
+            return RGBA::try_from((v[0], v[1], v[2], a)).ok().map(AnyColor::RGBA);
+        }
+        if let Some(tmp) = old_components(color_str, "hsl").filter(|tmp| tmp.len() == 3) {
+            let v = u32s(&tmp)?;
+            return HSL::try_from((v[0], v[1], v[2])).ok().map(AnyColor::HSL);
+        }
+        if let Some(tmp) = old_components(color_str, "hsla").filter(|tmp| tmp.len() == 4) {
+            let (v, a) = (u32s(&tmp[..3])?, tmp[3].trim().parse::<f32>().ok()?);
+            return HSLA::try_from((v[0], v[1], v[2], a)).ok().map(AnyColor::HSLA);
+        }
+        if let Some(tmp) = old_components(color_str, "hsv").filter(|tmp| tmp.len() == 3) {
+            let v = u32s(&tmp)?;
+            return HSV::try_from((v[0], v[1], v[2])).ok().map(AnyColor::HSV);
+        }
+        if let Some(tmp) = old_components(color_str, "cmyk").filter(|tmp| tmp.len() == 4) {
+            let v = u8s(&tmp, false)?;
+            return CMYK::try_from((v[0], v[1], v[2], v[3])).ok().map(AnyColor::CMYK);
+        }
+        if let Some(rgb) = old_color_function(color_str, "srgb") {
+            return RGBf::try_from(rgb).ok().map(AnyColor::RGBf);
+        }
+        if let Some(rgb) = old_color_function(color_str, "display-p3") {
+            return P3::try_from(rgb).ok().map(AnyColor::P3);
+        }
+        let digits = color_str.trim().to_lowercase().strip_prefix('#')?.to_string();
+        let chunk = if digits.len() == 3 { 1 } else { 2 };
+        let val = digits
+            .chars()
+            .collect::<Vec<_>>()
+            .chunks(chunk)
+            .map(|c| u8::from_str_radix(&c.iter().collect::<String>().repeat(2)[0..2], 16).ok())
+            .collect::<Option<Vec<_>>>()?;
+        let rgba = match (digits.len(), val.as_slice()) {
+            (3 | 6, [r, g, b]) => (*r, *g, *b, 1.0),
+            (8, [r, g, b, a]) => (*r, *g, *b, *a as f32 / 255.0),
+            _ => return None,
+        };
+        Some(AnyColor::Hex(Hex { rgba }))
+    }
+
+    // the allocation-free parsers must return exactly what the old parsers did on every string the old ones accepted
+    proptest::proptest! {
+        #[test]
+        fn parsers_match_the_allocating_parsers(
+            color_str in proptest::prop_oneof![
+                r"[ \t]{0,2}[rR][gG][bB]\(( ?[0-9]{1,3} ?,){2} ?[0-9]{1,3} ?\)[ \t]{0,2}",
+                r"[rR][gG][bB][aA]\(( ?[0-9]{1,3} ?,){3} ?(0|1|0?\.[0-9]{1,3}|1\.0) ?\)",
+                r"[hH][sS][lLvV]\( ?([0-9]{1,2}|[12][0-9]{2}|3[0-5][0-9]) ?, ?[0-9]{1,3}% ?, ?[0-9]{1,3}% ?\)",
+                r"[hH][sS][lL][aA]\( ?([0-9]{1,2}|[12][0-9]{2}|3[0-5][0-9]) ?,( ?[0-9]{1,3}% ?,){2} ?(0|1|0?\.[0-9]{1,3}) ?\)",
+                r"[cC][mM][yY][kK]\(([0-9]{1,3},){3}[0-9]{1,3}\)",
+                r"[cC][oO][lL][oO][rR]\((srgb|SRGB|display-p3|Display-P3)( +(0|1|0?\.[0-9]{1,4}|[0-9]{1,2}(\.[0-9])?%)){3} *\)",
+                r" ?#([0-9a-fA-F]{3}|[0-9a-fA-F]{6}|[0-9a-fA-F]{8}) ?",
+            ],
+        ) {
+            if let Some(expected) = old_parse(&color_str) {
+                proptest::prop_assert_eq!(crate::parse(&color_str).ok(), Some(expected), "{}", color_str);
+            }
+        }
+    }
+
+    #[test]
+    fn byte_order_works() {
+        let rgb = RGB::from_colorref(0x008AC42B);
+        assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+        assert_eq!(rgb.to_colorref(), 0x008AC42B);
+        // the high byte is ignored on input and zeroed on output
+        let rgb = RGB::from_colorref(0xFF8AC42B);
+        assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+        assert_eq!(rgb.to_colorref(), 0x008AC42B);
+        assert_eq!(RGB::from_colorref(0x00FF0000).to_string(), "rgb(0,0,255)");
+        assert_eq!(
+            RGB::from_u32_with(0x008AC42B, ByteOrder::Bgr),
+            RGB::from_u32(0x2BC48A)
+        );
+
+        let rgba: RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+        let packed = [
+            (ByteOrder::Rgb, 0x002BC48A),
+            (ByteOrder::Bgr, 0x008AC42B),
+            (ByteOrder::Rgba, 0x2BC48A80),
+            (ByteOrder::Bgra, 0x8AC42B80),
+            (ByteOrder::Argb, 0x802BC48A),
+            (ByteOrder::Abgr, 0x808AC42B),
+        ];
+        for (order, value) in packed {
+            assert_eq!(rgba.to_u32_with(order), value);
+            let color = RGBA::from_u32_with(value, order);
+            assert_eq!(color.rgb, rgba.rgb);
+            assert_eq!(color.alpha(), if order.has_alpha() { 128.0 / 255.0 } else { 1.0 });
+            assert_eq!(RGB::from_u32_with(value, order), rgba.rgb);
+        }
+        assert_eq!(RGB::from_u32(0x2BC48A).to_u32_with(ByteOrder::Argb), 0xFF2BC48A);
+    }
+
+    #[test]
+    fn parse_list_works() {
+        let colors = crate::parse_list("#2bc48a, #ff7d37, rgb(0,0,0)").unwrap();
+        assert_eq!(colors.len(), 3);
+        assert!(matches!(colors[0], AnyColor::Hex(_)));
+        assert_eq!(colors[2].to_string(), "rgb(0,0,0)");
+
+        let colors = crate::parse_list("#2bc48a\n#ff7d37\r\n\n  hsl(157 64% 47% / 0.5)\n").unwrap();
+        assert_eq!(colors.len(), 3);
+        assert_eq!(RGBA::from(colors[2]).to_string(), "rgba(43,196,138,0.50)");
+        let colors = crate::parse_list("tomato rgb(43 196 138),,color(srgb 1 0 0) ,").unwrap();
+        assert_eq!(colors.len(), 3);
+        assert!(crate::parse_list("").unwrap().is_empty());
+        assert!(crate::parse_list(" , \n").unwrap().is_empty());
+
+        let err = crate::parse_list("#2bc48a, , rgb(0,0,300), #fff").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "entry 1: 'rgb(0,0,300)' component 2 error: value 300 out of range 0..=255"
+        );
+        assert_eq!(err.input(), Some("#2bc48a, , rgb(0,0,300), #fff"));
+
+        // a word that happens to be hex digits is not a color in a list
+        let err = crate::parse_list("#2bc48a, bad, rgb(0,0,0)").unwrap_err();
+        let message = err.to_string();
+        assert!(message.starts_with("entry 1: 'bad' is not a color, tried"), "{}", message);
+        assert_eq!(message.matches("is not a color").count(), 1);
+    }
+
+    #[test]
+    fn hsb_works() {
+        let hsv: HSV = "hsv(262,85%,79%)".try_into().unwrap();
+        let hsb: HSV = "hsb(262,85%,79%)".try_into().unwrap();
+        assert_eq!(hsv, hsb);
+        assert_eq!(hsb.to_string(), "hsv(262,85%,79%)");
+        assert_eq!(hsb.to_hsb_string(), "hsb(262,85%,79%)");
+        let hsb: HSV = "HSB(262deg 85% 79%)".try_into().unwrap();
+        assert_eq!(hsv, hsb);
+        assert!(HSV::try_from("hsb(262,85%,79%,0.5)").is_err());
+        assert_eq!(crate::parse("hsb(262,85%,79%)").unwrap(), AnyColor::HSV(hsv));
+    }
+
+    #[test]
+    fn i32_tuple_works() {
+        let rgb: RGB = (43i32, 196, 138).try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+        let err = RGB::try_from((300i32, 128, 0)).unwrap_err();
+        assert!(format!("{:?}", err).contains("red is 300"));
+        let err = RGB::try_from((0i32, 128, -1)).unwrap_err();
+        assert!(matches!(err, ColorError::ValueErr { .. }));
+        assert!(format!("{:?}", err).contains("blue is -1"));
+
+        let rgba = RGBA::new_checked(43, 196, 138, 0.5).unwrap();
+        assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+        let err = RGBA::new_checked(43, 256, 138, 0.5).unwrap_err();
+        assert!(format!("{:?}", err).contains("green is 256"));
+        assert!(RGBA::new_checked(43, 196, 138, 1.5).is_err());
+        // untyped literals still pick the u8 tuple
+        let rgba: RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+        assert_eq!(rgba, RGBA::new_checked(43, 196, 138, 0.5).unwrap());
+
+        // u8 tuples keep working
+        let r: u8 = 43;
+        let rgb: RGB = (r, 196, 138).try_into().unwrap();
+        assert_eq!(rgb, RGB::try_from((43i32, 196, 138)).unwrap());
+    }
+
+    #[test]
+    fn f32_tuple_works() {
+        let rgb: RGB = (1.0, 0.5, 0.0).try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(255,128,0)");
+        let rgb: RGB = (0.169, 0.769, 0.541).try_into().unwrap();
+        assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+        assert_eq!(RGB::try_from(rgb.to_f32_tuple()).unwrap(), rgb);
+        assert!(RGB::try_from((1.01, 0.0, 0.0)).is_err());
+        assert!(RGB::try_from((0.0, -0.01, 0.0)).is_err());
+        assert!(RGB::try_from((0.0, 0.0, f32::NAN)).is_err());
+        for v in 0..=255u8 {
+            let rgb = RGB { r: v, g: v, b: v };
+            assert_eq!(RGB::try_from(rgb.to_f32_tuple()).unwrap(), rgb);
+        }
+        // round half up, 0.5 / 255 is the half between 0 and 1
+        assert_eq!(RGB::try_from((0.5 / 255.0, 1.5 / 255.0, 1.0)).unwrap().to_array(), [1, 2, 255]);
+
+        let rgba: RGBA = (1.0, 0.5, 0.0, 0.25).try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(255,128,0,0.25)");
+        assert_eq!(rgba.to_f32_tuple(), (1.0, 128.0 / 255.0, 0.0, 0.25));
+        assert!(RGBA::try_from((1.0, 0.5, 0.0, f32::NAN)).is_err());
+        assert!(RGBA::try_from((f32::NAN, 0.5, 0.0, 1.0)).is_err());
+        assert!(RGBA::try_from((1.0, 0.5, 2.0, 1.0)).is_err());
+    }
+
+    #[test]
+    fn u8_alpha_works() {
+        let rgba: RGBA = (43, 196, 138, 128).try_into().unwrap();
+        assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+        assert_eq!(rgba.alpha_u8(), 128);
+        let rgba: RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+        assert_eq!(rgba.alpha_u8(), 128);
+        for a in 0..=255u8 {
+            let rgba: RGBA = (43, 196, 138, a).try_into().unwrap();
+            assert_eq!(rgba.alpha_u8(), a);
+            let hex: Hex = rgba.into();
+            let expected = if a == 255 {
+                "#2BC48A".to_string()
+            } else {
+                format!("#2BC48A{:02X}", a)
+            };
+            assert_eq!(hex.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn gpl_works() {
+        let gpl = "GIMP Palette\r\nName: Brand colors\nColumns: 4\n# a comment\n\n 43 196 138\tGreen Mint\n255   0   0\n  0   0 255 Blue\n";
+        let palette = Palette::from_gpl(gpl).unwrap();
+        assert_eq!(palette.name(), Some("Brand colors"));
+        assert_eq!(palette.columns(), Some(4));
+        assert_eq!(palette.len(), 3);
+        let swatches = palette.swatches();
+        assert_eq!(swatches[0].name(), Some("Green Mint"));
+        assert_eq!(swatches[1].name(), None);
+        assert_eq!(swatches[2].name(), Some("Blue"));
+        assert_eq!(
+            palette.to_rgb_vec(),
+            vec![RGB::from_u32(0x2BC48A), RGB::from_u32(0xFF0000), RGB::from_u32(0x0000FF)]
+        );
+        let written = palette.to_gpl();
+        assert_eq!(
+            written,
+            "GIMP Palette\nName: Brand colors\nColumns: 4\n#\n 43 196 138\tGreen Mint\n255   0   0\n  0   0 255\tBlue\n"
+        );
+        assert_eq!(Palette::from_gpl(&written).unwrap(), palette);
+
+        let mut palette = Palette::new();
+        palette.push(AnyColor::Hex("#FF7D37".try_into().unwrap()), Some("Orange"));
+        assert_eq!(palette.to_gpl(), "GIMP Palette\n#\n255 125  55\tOrange\n");
+
+        assert!(Palette::from_gpl("43 196 138").is_err());
+        let err = Palette::from_gpl("GIMP Palette\n#\n43 196 138\n43 300 138 Bad\n").unwrap_err();
+        assert!(err.to_string().contains("line 4"));
+        assert!(matches!(err, ColorError::ParseErr { component: Some(1), .. }));
+        let err = Palette::from_gpl("GIMP Palette\n43 196\n").unwrap_err();
+        assert!(err.to_string().contains("line 2, expected 3 components, found 2"));
+        assert!(Palette::from_gpl("GIMP Palette\nColumns: x\n").is_err());
+    }
+
+    #[test]
+    fn aco_ase_works() {
+        fn utf16(name: &str) -> Vec<u8> {
+            let mut units = name.encode_utf16().collect::<Vec<_>>();
+            units.push(0);
+            let mut bytes = (units.len() as u16).to_be_bytes().to_vec();
+            bytes.extend(units.iter().flat_map(|u| u.to_be_bytes()));
+            bytes
+        }
+        // (color space, values, name)
+        let colors: [(u16, [u16; 4], &str); 3] = [
+            (0, [0x2B2B, 0xC4C4, 0x8A8A, 0], "Green"),
+            (2, [65535 - 50463, 65535, 65535 - 19661, 65535 - 15073], "Print"),
+            (7, [5000, 0, 0, 0], "Lab"),
+        ];
+        let mut aco = vec![0, 1, 0, 3];
+        for (space, values, _) in colors {
+            aco.extend(space.to_be_bytes());
+            aco.extend(values.iter().flat_map(|v| v.to_be_bytes()));
+        }
+        let palette = Palette::from_aco(&aco).unwrap();
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette.swatches()[0].color(), AnyColor::RGB(RGB::from_u32(0x2BC48A)));
+        assert_eq!(palette.swatches()[0].name(), None);
+        assert_eq!(palette.swatches()[1].color().to_string(), "cmyk(77,0,30,23)");
+        assert_eq!(palette.skipped()[0].color_space(), "lab");
+
+        aco.extend([0, 2, 0, 3]);
+        for (space, values, name) in colors {
+            aco.extend(space.to_be_bytes());
+            aco.extend(values.iter().flat_map(|v| v.to_be_bytes()));
+            aco.extend([0, 0]);
+            aco.extend(utf16(name));
+        }
+        let palette = Palette::from_aco(&aco).unwrap();
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette.swatches()[0].name(), Some("Green"));
+        assert_eq!(palette.swatches()[1].name(), Some("Print"));
+        assert_eq!(palette.skipped()[0].name(), Some("Lab"));
+        for len in 0..aco.len() {
+            if ![0, 4 + 10 * 3].contains(&len) {
+                assert!(Palette::from_aco(&aco[..len]).is_err());
+            }
+        }
+
+        fn ase_block(kind: u16, data: Vec<u8>) -> Vec<u8> {
+            let mut block = kind.to_be_bytes().to_vec();
+            block.extend((data.len() as u32).to_be_bytes());
+            block.extend(data);
+            block
+        }
+        fn ase_color(name: &str, model: &[u8], values: &[f32], kind: u16) -> Vec<u8> {
+            let mut data = utf16(name);
+            data.extend(model);
+            data.extend(values.iter().flat_map(|v| v.to_be_bytes()));
+            data.extend(kind.to_be_bytes());
+            ase_block(0x0001, data)
+        }
+        let blocks = [
+            ase_block(0xC001, utf16("Brand")),
+            ase_color("Green", b"RGB ", &[43.0 / 255.0, 196.0 / 255.0, 138.0 / 255.0], 2),
+            ase_color("Print", b"CMYK", &[0.77, 0.0, 0.3, 0.23], 0),
+            ase_color("Lab", b"LAB ", &[0.5, 10.0, -10.0], 2),
+            ase_color("Pantone", b"RGB ", &[1.0, 0.0, 0.0], 1),
+            ase_block(0xC002, vec![]),
+        ];
+        let mut ase = b"ASEF".to_vec();
+        ase.extend([0, 1, 0, 0]);
+        ase.extend((blocks.len() as u32).to_be_bytes());
+        ase.extend(blocks.concat());
+        let palette = Palette::from_ase(&ase).unwrap();
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette.swatches()[0].name(), Some("Green"));
+        assert_eq!(palette.swatches()[0].color(), AnyColor::RGB(RGB::from_u32(0x2BC48A)));
+        assert_eq!(palette.swatches()[1].color().to_string(), "cmyk(77,0,30,23)");
+        let skipped = palette.skipped();
+        assert_eq!((skipped[0].name(), skipped[0].color_space()), (Some("Lab"), "lab"));
+        assert_eq!((skipped[1].name(), skipped[1].color_space()), (Some("Pantone"), "spot"));
+        for len in 0..ase.len() {
+            assert!(Palette::from_ase(&ase[..len]).is_err());
+        }
+        assert!(Palette::from_ase(b"ASEX\0\x01\0\0\0\0\0\0").is_err());
+
+        let ase_file = |blocks: &[Vec<u8>]| {
+            let mut ase = b"ASEF".to_vec();
+            ase.extend([0, 1, 0, 0]);
+            ase.extend((blocks.len() as u32).to_be_bytes());
+            ase.extend(blocks.concat());
+            ase
+        };
+        // the type of an unknown color space is read from the end of the block, not from its values
+        let palette = Palette::from_ase(&ase_file(&[
+            ase_color("Lab spot", b"LAB ", &[0.5, 10.0, -10.0], 1),
+            ase_color("Gray", b"Gray", &[0.5], 2),
+        ]))
+        .unwrap();
+        let skipped = palette.skipped();
+        assert_eq!((skipped[0].name(), skipped[0].color_space()), (Some("Lab spot"), "spot"));
+        assert_eq!((skipped[1].name(), skipped[1].color_space()), (Some("Gray"), "gray"));
+        // a known color space without the type field is truncated
+        let mut truncated = utf16("Green");
+        truncated.extend(b"RGB ");
+        truncated.extend([0.2f32, 0.8, 0.5].iter().flat_map(|v| v.to_be_bytes()));
+        let err = Palette::from_ase(&ase_file(&[ase_block(0x0001, truncated)])).unwrap_err();
+        assert!(err.to_string().contains("truncated"), "{}", err);
+    }
+
+    #[test]
+    fn optional_percent_works() {
+        // the saturation, lightness and value take an optional %
+        for (with, without) in [
+            ("hsl(157,64%,47%)", "hsl(157,64,47)"),
+            ("hsl(157 64% 47%)", "hsl(157 64 47)"),
+            ("hsla(157,64%,47%,0.5)", "hsla(157,64,47,0.5)"),
+            ("hsla(157 64% 47% / 0.5)", "hsla(157 64 47 / 0.5)"),
+        ] {
+            assert_eq!(HSLA::try_from(with).unwrap(), HSLA::try_from(without).unwrap(), "{}", without);
+        }
+        assert_eq!(HSL::try_from("hsl(157, 64, 47%)").unwrap().to_string(), "hsl(157,64%,47%)");
+        assert_eq!(HSLA::try_from("hsla(157,64,47,0.5)").unwrap().to_string(), "hsla(157,64%,47%,0.50)");
+        assert_eq!(HSV::try_from("hsv(157,64,47)").unwrap(), HSV::try_from("hsv(157,64%,47%)").unwrap());
+        assert_eq!(HSV::try_from("hsb(157 64 47)").unwrap().to_string(), "hsv(157,64%,47%)");
+
+        // the hue never takes %
+        for s in ["hsl(157%,64%,47%)", "hsla(157%,64%,47%,0.5)", "hsv(157%,64%,47%)", "hsl(157% 64 47)"] {
+            assert!(parse(s).is_err(), "{}", s);
+        }
+        let err = HSL::try_from("hsl(157%,64%,47%)").unwrap_err();
+        assert!(err.to_string().contains("can not be a percentage"), "{}", err);
+
+        // a stray or doubled % is still an error
+        assert!(HSL::try_from("hsl(157,64%%,47%)").is_err());
+        assert!(HSL::try_from("hsl(157,%,47%)").is_err());
+    }
+
+    #[test]
+    fn cmyk_percent_works() {
+        for s in [
+            "cmyk(78,0,30,23)",
+            "cmyk(78%,0%,30%,23%)",
+            "cmyk(78%, 0%, 30%, 23%)",
+            "cmyk(0.78, 0, 0.3, 0.23)",
+            "cmyk(.78,0.0,.3,.23)",
+            "cmyk(77.6%, 0%, 30.2%, 23%)",
+        ] {
+            assert_eq!(CMYK::try_from(s).unwrap().to_string(), "cmyk(78,0,30,23)", "{}", s);
+        }
+        assert_eq!(CMYK::try_from("cmyk(1.0, 1, 0, 0)").unwrap().to_string(), "cmyk(100,100,0,0)");
+
+        // mixed forms
+        for s in ["cmyk(78%,0,30%,23%)", "cmyk(0.78,0%,0.3,0.23)", "cmyk(0.78,0,30,23)"] {
+            assert!(CMYK::try_from(s).is_err(), "{}", s);
+        }
+        let err = CMYK::try_from("cmyk(78%,0,30%,23%)").unwrap_err();
+        assert!(err.to_string().contains("component 1"), "{}", err);
+
+        // out of range
+        assert!(matches!(CMYK::try_from("cmyk(101,0,0,0)"), Err(ColorError::ValueErr { .. })));
+        assert!(matches!(CMYK::try_from("cmyk(120%,0%,0%,0%)"), Err(ColorError::ValueErr { .. })));
+        assert!(CMYK::try_from("cmyk(1.5,0,0,0)").is_err());
+        assert!(CMYK::try_from("cmyk(78,0,30)").is_err());
+    }
+
+    #[test]
+    fn deep_hex_works() {
+        let hex: Hex = "#2B2BC4C48A8A".try_into().unwrap();
+        assert_eq!(hex.to_string(), "#2BC48A");
+        assert_eq!(hex, Hex::try_from("#2BC48A").unwrap());
+        // channels are rounded, not truncated
+        assert_eq!(Hex::try_from("#2BC42BC48A8A").unwrap().to_string(), "#2C2C8A");
+        assert_eq!(Hex::try_from("#0000FFFF007F").unwrap().to_string(), "#00FF00");
+
+        // the 16 bit alpha keeps its precision
+        let hex: Hex = "#2BC42BC48A8A8000".try_into().unwrap();
+        assert!((RGBA::from(hex).alpha() - 32768.0 / 65535.0).abs() < 1e-6);
+        assert_eq!(RGBA::from(Hex::try_from("0x2BC42BC48A8AFFFF").unwrap()).alpha(), 1.0);
+        // the alpha is rounded to 8 bit when written, 0x8000 is 0x80 and not 0x7F
+        let hex: Hex = "#2B2BC4C48A8A8000".try_into().unwrap();
+        assert_eq!(hex.to_string(), "#2BC48A80");
+        assert_eq!(hex.format(HexPrefix::None, HexCase::Upper, AlphaPosition::First), "802BC48A");
+        assert_eq!(Hex::try_from(hex.to_string().as_str()).unwrap().to_string(), "#2BC48A80");
+
+        // 8 digits are still RGBA, other lengths are errors
+        assert_eq!(Hex::try_from("#2BC48A80").unwrap().to_hex_alpha(), "#2BC48A80");
+        for s in ["#2BC42BC48A", "#2BC42BC48A8A80", "#2BC42BC48A8A80000", "#2BC42BC48A8G"] {
+            assert!(Hex::try_from(s).is_err(), "{}", s);
+        }
+
+        // parse keeps the full precision of 12 digits in RGB16
+        let color = parse("#2BC42BC48A8A").unwrap();
+        assert_eq!(color, AnyColor::RGB16(RGB16::try_from((0x2BC4, 0x2BC4, 0x8A8A)).unwrap()));
+        assert!(matches!(parse("#2BC42BC48A8A8000").unwrap(), AnyColor::Hex(_)));
+        assert!(matches!(parse("#2BC48A80").unwrap(), AnyColor::Hex(_)));
+    }
+
+    /// Feed the input to every parser, only a panic fails the test
+    fn parse_everything(s: &str) {
+        let _ = crate::parse(s);
+        let _ = crate::parse_list(s);
+        let _ = Hex::try_from(s);
+        let _ = RGB::try_from(s);
+        let _ = RGBA::try_from(s);
+        let _ = HSL::try_from(s);
+        let _ = HSLA::try_from(s);
+        let _ = HSV::try_from(s);
+        let _ = HSI::try_from(s);
+        let _ = HWB::try_from(s);
+        let _ = CMYK::try_from(s);
+        let _ = CMYKA::try_from(s);
+        let _ = LCH::try_from(s);
+        let _ = OkLch::try_from(s);
+        let _ = HSLuv::try_from(s);
+        let _ = Gray::try_from(s);
+        let _ = RGB16::try_from(s);
+        let _ = RGBf::try_from(s);
+        let _ = P3::try_from(s);
+        let _ = Rec2020::try_from(s);
+        let _ = AdobeRGB::try_from(s);
+        let _ = ProPhotoRGB::try_from(s);
+        let _ = Gradient::from_css(s);
+        let _ = Gradient::from_css(&format!("linear-gradient({})", s));
+        for options in [ParseOptions::strict(), ParseOptions::lenient()] {
+            let _ = Hex::parse_with(s, &options);
+            let _ = RGB::parse_with(s, &options);
+            let _ = RGBA::parse_with(s, &options);
+            let _ = HSL::parse_with(s, &options);
+            let _ = HSLA::parse_with(s, &options);
+            let _ = HSV::parse_with(s, &options);
+        }
+        let _ = Palette::from_gpl(s);
+        let _ = Palette::from_aco(s.as_bytes());
+        let _ = Palette::from_ase(s.as_bytes());
+    }
+
+    /// Strings that look like colors but are broken in every way the tokens can combine
+    fn adversarial_color() -> impl proptest::strategy::Strategy<Value = String> {
+        use proptest::prelude::*;
+        let names = prop::sample::select(vec![
+            "", "#", "0x", "rgb", "rgba", "hsl", "hsla", "hsv", "hsb", "hsi", "hwb", "cmyk", "cmyka",
+            "device-cmyk", "lch", "oklch", "hsluv", "gray", "color", "RGB", "Hsl", "transparent", "tomato",
+        ]);
+        let tokens = prop::collection::vec(
+            prop::sample::select(vec![
+                "", "(", ")", ",", "/", "%", " ", "\t", "\0", "-", "+", ".", "e", "E", "0", "1", "9", "255",
+                "256", "-1", "1e40", "-1e40", "1e-40", "nan", "NaN", "inf", "-inf", "deg", "rad", "grad",
+                "turn", "srgb", "display-p3", "none", "ff", "FFFF", "é", "\u{FF10}", "💥", "999999999999",
+                "0.5", ".5", "5.", "4294967296", "65536", "%%", ",,", "//",
+            ]),
+            0..24,
+        );
+        (names, tokens, prop::bool::ANY).prop_map(|(name, tokens, close)| {
+            let mut s = format!("{}({}", name, tokens.concat());
+            if close {
+                s.push(')');
+            }
+            s
+        })
+    }
+
+    // the parsers return Err on bad input and never panic
+    proptest::proptest! {
+        #![proptest_config(proptest::prelude::ProptestConfig::with_cases(2000))]
+        #[test]
+        fn parsers_never_panic_on_adversarial_colors(s in adversarial_color()) {
+            parse_everything(&s);
+        }
+
+        #[test]
+        fn parsers_never_panic_on_any_string(s in "\\PC{0,40}") {
+            parse_everything(&s);
+        }
+
+        #[test]
+        fn parsers_never_panic_on_hex_like_strings(s in "[#0xX]{0,2}[0-9a-fA-FgG#é]{0,20}") {
+            parse_everything(&s);
+        }
+    }
+
+    #[test]
+    fn parsers_never_panic_on_pathological_input() {
+        let long_number = "9".repeat(10_000);
+        let long = [
+            "rgb(".repeat(10_000),
+            ")".repeat(10_000),
+            format!("rgb({})", ",".repeat(100_000)),
+            format!("hsl({},{},{})", long_number, long_number, long_number),
+            format!("#{}", "f".repeat(100_000)),
+            format!("color(srgb {})", "1 ".repeat(10_000)),
+            "a, ".repeat(10_000),
+        ];
+        for s in long.iter().map(|s| s.as_str()).chain([
+            "", "(", ")", "()", "rgba()", "rgb(,,)", "hsl(,,,)", "hsla(,,,,)", "cmyk(,,,)", "cmyka(,,,,)",
+            "rgb(\0,\0,\0)", "rgb(1,2,3\0)", "rgb((1,2,3))", "rgb(1,(2),3)", "color()", "color(srgb)",
+            "device-cmyk()", "gray()", "#", "0x", "# ", "rgb(1 2 3 /)", "rgb(/ 1 2 3)",
+            "lch(,,)", "oklch(,,)", "hsluv(,,)", "hwb(,,)", "hsi(,,)", "hsv(1e40,1,1)", "hsl(-1e40deg,1%,1%)",
+        ]) {
+            parse_everything(s);
+            assert!(crate::parse(s).is_err(), "{:?}", s.chars().take(40).collect::<String>());
+        }
+        // the conversions build the channels directly, so a NaN alpha does not panic either
+        let mut rgba = RGBA::default();
+        rgba.set_alpha(f32::NAN);
+        let _ = rgba.grayscale();
+    }
+
+    #[test]
+    fn try_from_string_works() {
+        fn parse_owned<T: TryFrom<String, Error = ColorError>>(s: &str) -> Result<T, ColorError> {
+            T::try_from(s.to_string())
+        }
+        let s = String::from("hsl(157, 64%, 47%)");
+        assert_eq!(HSL::try_from(&s).unwrap(), HSL::try_from(s.as_str()).unwrap());
+        assert_eq!(parse_owned::<RGB>("rgb(43 196 138)").unwrap().to_string(), "rgb(43,196,138)");
+        assert_eq!(parse_owned::<Hex>("#2bc48a").unwrap().to_string(), "#2BC48A");
+        assert_eq!(parse_owned::<CMYKA>("cmyka(78,0,30,23,0.5)").unwrap().alpha(), 0.5);
+        assert_eq!(parse_owned::<P3>("color(display-p3 1 0 0)").unwrap().to_string(), "color(display-p3 1.0000 0.0000 0.0000)");
+        assert!(parse_owned::<RGB16>("#2bc48a").is_err());
+
+        // the error owns the input, so it outlives the string
+        let err = {
+            let s = String::from("rgb(43,196,300)");
+            RGB::try_from(s).unwrap_err()
+        };
+        assert_eq!(err.input(), Some("rgb(43,196,300)"));
+        assert_eq!(format!("{:?}", err), format!("{:?}", RGB::try_from("rgb(43,196,300)").unwrap_err()));
+        // every variant keeps the input, format and value errors as well
+        let err = RGB::try_from(String::from("blorp")).unwrap_err();
+        assert!(matches!(err, ColorError::FormatErr { .. }));
+        assert_eq!(err.input(), Some("blorp"));
+        let err = RGB::try_from((0i32, 0, 300)).unwrap_err();
+        assert!(matches!(err, ColorError::ValueErr { .. }));
+        assert_eq!(err.input(), Some("(0, 0, 300)"));
+    }
+
+    #[test]
+    fn to_css_works() {
+        let rgb: RGB = (43, 196, 138).try_into().unwrap();
+        assert_eq!(rgb.to_css(), "rgb(43,196,138)");
+        assert_eq!(rgb.to_css_modern(), "rgb(43 196 138)");
+
+        let rgba: RGBA = (43, 196, 138, 0.85).try_into().unwrap();
+        assert_eq!(rgba.to_css(), rgba.to_string());
+        assert_eq!(rgba.to_css_modern(), "rgb(43 196 138 / 0.85)");
+        let rgba: RGBA = (43, 196, 138, 1.0).try_into().unwrap();
+        assert_eq!(rgba.to_css_modern(), "rgb(43 196 138)");
+        let rgba: RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+        assert_eq!(rgba.to_css_modern(), "rgb(43 196 138 / 0.5)");
+
+        let hsla: HSLA = (157, 64, 47, 0.5).try_into().unwrap();
+        assert_eq!(hsla.to_css(), "hsla(157,64%,47%,0.50)");
+        assert_eq!(hsla.to_css_modern(), "hsl(157deg 64% 47% / 0.5)");
+        let hsl: HSL = (157, 64, 47).try_into().unwrap();
+        assert_eq!(hsl.to_css(), "hsl(157,64%,47%)");
+        assert_eq!(hsl.to_css_modern(), "hsl(157deg 64% 47%)");
+        let hwb: HWB = "hwb(157 0% 23%)".try_into().unwrap();
+        assert_eq!(hwb.to_css_modern(), "hwb(157deg 0% 23%)");
+
+        // Hex picks the shorthand only when it is lossless
+        for (hex, legacy, modern) in [
+            ("#2BC48A", "#2BC48A", "#2BC48A"),
+            ("#FFAA00", "#FA0", "#FA0"),
+            ("#FFAA0088", "rgba(255,170,0,0.53)", "#FA08"),
+            ("#FFAA0080", "rgba(255,170,0,0.50)", "#FFAA0080"),
+        ] {
+            let hex: Hex = hex.try_into().unwrap();
+            assert_eq!((hex.to_css().as_str(), hex.to_css_modern().as_str()), (legacy, modern));
+        }
+
+        // the modern output parses back to the same color
+        let rgba: RGBA = (43, 196, 138, 0.85).try_into().unwrap();
+        assert_eq!(RGBA::try_from(rgba.to_css_modern().as_str()).unwrap(), rgba);
+        assert_eq!(HSLA::try_from(hsla.to_css_modern().as_str()).unwrap(), hsla);
+        let rgbaf: RGBAf = rgba.into();
+        assert_eq!(rgbaf.to_css_modern(), "color(srgb 0.1686 0.7686 0.5412 / 0.85)");
+
+        // types without a CSS syntax are written as RGB or RGBA
+        let hsv: HSV = rgb.into();
+        assert_eq!(hsv.to_css(), RGB::from(hsv).to_css());
+        let cmyka: CMYKA = "cmyka(78,0,30,23,0.5)".try_into().unwrap();
+        assert_eq!(cmyka.to_css_modern(), RGBA::from(cmyka).to_css_modern());
+        assert_eq!(parse("#FFAA00").unwrap().to_css_modern(), "rgb(255 170 0)");
+    }
+
+    #[test]
+    fn alpha_precision_works() {
+        let rgba: RGBA = (43, 196, 138, 0.333).try_into().unwrap();
+        // the default stays 2 decimals
+        assert_eq!(rgba.to_string(), "rgba(43,196,138,0.33)");
+        assert_eq!(format!("{}", rgba), "rgba(43,196,138,0.33)");
+        assert_eq!(format!("{:.3}", rgba), "rgba(43,196,138,0.333)");
+        assert_eq!(format!("{:.0}", rgba), "rgba(43,196,138,0)");
+        assert_eq!(format!("{:#}", rgba), "rgba(43,196,138,0.33)");
+        assert_eq!(format!("{:#.5}", rgba), "rgba(43,196,138,0.333)");
+
+        let hsla: HSLA = (157, 64, 47, 1.0).try_into().unwrap();
+        assert_eq!(hsla.to_string(), "hsla(157,64%,47%,1.00)");
+        assert_eq!(format!("{:#}", hsla), "hsla(157,64%,47%,1)");
+        let hsla: HSLA = (157, 64, 47, 0.5).try_into().unwrap();
+        assert_eq!(format!("{:#}", hsla), "hsla(157,64%,47%,0.5)");
+        assert_eq!(format!("{:.1}", hsla), "hsla(157,64%,47%,0.5)");
+
+        let cmyka: CMYKA = "cmyka(78,0,30,23,0.25)".try_into().unwrap();
+        assert_eq!(format!("{:#.1}", cmyka), "cmyka(78,0,30,23,0.2)");
+        let rgbaf: RGBAf = rgba.into();
+        assert_eq!(format!("{:#}", rgbaf), "color(srgb 0.1686 0.7686 0.5412 / 0.33)");
+
+        // the flags pass through AnyColor, and the output still parses
+        let color = parse("rgba(43,196,138,0.125)").unwrap();
+        assert_eq!(format!("{:.3}", color), "rgba(43,196,138,0.125)");
+        assert_eq!(RGBA::try_from(format!("{:#}", rgba).as_str()).unwrap().alpha(), 0.33);
+    }
+
+    #[test]
+    fn short_hex_works() {
+        for (hex, short) in [
+            ("#FFAA00", "#FA0"),
+            ("#ffaa00", "#FA0"),
+            ("#000000", "#000"),
+            ("#FFFFFF", "#FFF"),
+            ("#FFAA0088", "#FA08"),
+            ("#FA0", "#FA0"),
+            ("#FA08", "#FA08"),
+            ("#2BC48A", "#2BC48A"),
+            ("#FFAA01", "#FFAA01"),
+            // the alpha byte has to be compressible too
+            ("#FFAA0080", "#FFAA0080"),
+        ] {
+            let hex: Hex = hex.try_into().unwrap();
+            assert_eq!(hex.to_short_string(), short);
+            assert_eq!(hex.is_compressible(), short.len() < 6, "{}", short);
+            // the shorthand is lossless
+            assert_eq!(Hex::try_from(short).unwrap().to_string(), hex.to_string());
+        }
+        // an opaque alpha is not written, so it does not prevent the shorthand
+        assert_eq!(Hex::try_from("#FFAA00FF").unwrap().to_short_string(), "#FA0");
+    }
+
+    #[test]
+    fn percentage_string_works() {
+        let rgb: RGB = (43, 196, 138).try_into().unwrap();
+        assert_eq!(rgb.to_percentage_string(1), "rgb(16.9%, 76.9%, 54.1%)");
+        assert_eq!(rgb.to_percentage_string(3), "rgb(16.863%, 76.863%, 54.118%)");
+        assert_eq!(rgb.to_percentage_string(0), "rgb(17%, 77%, 54%)");
+        let (r, g, b) = rgb.to_percentage_tuple();
+        assert!((r - 16.86275).abs() < 1e-4 && (g - 76.86275).abs() < 1e-4 && (b - 54.11765).abs() < 1e-4);
+
+        // the ends are exact at any precision
+        let rgb: RGB = (255, 0, 255).try_into().unwrap();
+        assert_eq!(rgb.to_percentage_tuple(), (100.0, 0.0, 100.0));
+        for precision in 0..6 {
+            let s = rgb.to_percentage_string(precision);
+            let zero = format!("{:.*}%", precision, 0.0);
+            let full = format!("{:.*}%", precision, 100.0);
+            assert_eq!(s, format!("rgb({}, {}, {})", full, zero, full));
+        }
+
+        let rgba: RGBA = (43, 196, 138, 0.85).try_into().unwrap();
+        assert_eq!(rgba.to_percentage_string(1), "rgba(16.9%, 76.9%, 54.1%, 0.85)");
+        // the output parses back to the same color
+        for v in 0..=255u8 {
+            let rgb = RGB { r: v, g: 255 - v, b: v / 2 };
+            assert_eq!(RGB::try_from(rgb.to_percentage_string(2).as_str()).unwrap(), rgb);
+        }
+    }
+
+    #[test]
+    fn byte_order_round_trip_works() {
+        let orders = [
+            ByteOrder::Rgb,
+            ByteOrder::Bgr,
+            ByteOrder::Rgba,
+            ByteOrder::Bgra,
+            ByteOrder::Argb,
+            ByteOrder::Abgr,
+        ];
+        // distinct channels, so a swap of red and blue can not go unnoticed
+        for (a, byte) in [(0.0, 0x00), (0.5, 0x80), (1.0, 0xFF)] {
+            let rgba: RGBA = (0x11, 0x22, 0x33, a).try_into().unwrap();
+            let expected = [
+                0x00112233,
+                0x00332211,
+                0x11223300 | byte,
+                0x33221100 | byte,
+                (byte << 24) | 0x112233,
+                (byte << 24) | 0x332211,
+            ];
+            for (order, value) in orders.into_iter().zip(expected) {
+                assert_eq!(rgba.to_u32_with(order), value, "{:?} {}", order, a);
+                let color = RGBA::from_u32_with(value, order);
+                assert_eq!(color.to_array()[..3], [0x11, 0x22, 0x33], "{:?}", order);
+                assert_eq!(color.alpha_u8(), if order.has_alpha() { byte as u8 } else { 255 });
+                assert_eq!(color.to_u32_with(order), value);
+                // RGB ignores the alpha byte
+                assert_eq!(RGB::from_u32_with(value, order).to_array(), [0x11, 0x22, 0x33]);
+            }
+        }
+        // the alpha is rounded, not truncated
+        let rgba: RGBA = (0, 0, 0, 0.999).try_into().unwrap();
+        assert_eq!(rgba.to_u32_with(ByteOrder::Rgba), 0x000000FF);
+        let rgba: RGBA = (0, 0, 0, 0.001).try_into().unwrap();
+        assert_eq!(rgba.to_u32_with(ByteOrder::Argb), 0x00000000);
+        // RGB is opaque in the orders with alpha
+        let rgb = RGB::from_u32(0x112233);
+        for order in orders {
+            let opaque = RGBA::from_u32_with(rgb.to_u32_with(order), order);
+            assert_eq!((opaque.rgb, opaque.alpha()), (rgb, 1.0), "{:?}", order);
+        }
+    }
+
+    #[test]
+    fn tuple_export_works() {
+        let rgb: RGB = (43, 196, 138).try_into().unwrap();
+        let (r, g, b): (u8, u8, u8) = rgb.into();
+        assert_eq!((r, g, b), (43, 196, 138));
+        assert_eq!(RGB::try_from(rgb.as_tuple()).unwrap(), rgb);
+        let bytes: [u8; 3] = rgb.into();
+        assert_eq!(bytes, [43, 196, 138]);
+
+        let rgba: RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+        let (r, g, b, a): (u8, u8, u8, f32) = rgba.into();
+        assert_eq!((r, g, b, a), (43, 196, 138, 0.5));
+        assert_eq!(RGBA::try_from(rgba.as_tuple()).unwrap(), rgba);
+        let bytes: [u8; 4] = rgba.into();
+        assert_eq!(bytes, [43, 196, 138, 128]);
+
+        let hsl: HSL = (157, 64, 47).try_into().unwrap();
+        assert_eq!(HSL::try_from(hsl.as_tuple()).unwrap(), hsl);
+        let array: [u32; 3] = hsl.into();
+        assert_eq!(array, hsl.to_array());
+        let hsv: HSV = (157, 78, 77).try_into().unwrap();
+        let (h, s, v): (u32, u32, u32) = hsv.into();
+        assert_eq!([h, s, v], hsv.to_array());
+        assert_eq!(HSV::try_from(hsv.as_tuple()).unwrap(), hsv);
+        let hsla: HSLA = (157, 64, 47, 0.25).try_into().unwrap();
+        assert_eq!(hsla.as_tuple(), (157, 64, 47, 0.25));
+        assert_eq!(HSLA::try_from(hsla.as_tuple()).unwrap(), hsla);
+        let cmyk: CMYK = (78, 0, 30, 23).try_into().unwrap();
+        let array: [u8; 4] = cmyk.into();
+        assert_eq!(array, [78, 0, 30, 23]);
+        assert_eq!(CMYK::try_from(cmyk.as_tuple()).unwrap(), cmyk);
+
+        // writing pixels into a byte buffer
+        let buffer: Vec<u8> = [rgb, RGB::from_u32(0xFF0000)].iter().flat_map(|c| c.to_array()).collect();
+        assert_eq!(buffer, [43, 196, 138, 255, 0, 0]);
+    }
+
+    #[test]
+    fn ansi_escape_works() {
+        let rgb: RGB = (43, 196, 138).try_into().unwrap();
+        assert_eq!(rgb.to_ansi_fg(), "\x1b[38;2;43;196;138m");
+        assert_eq!(rgb.to_ansi_bg(), "\x1b[48;2;43;196;138m");
+        let index = Ansi256::from(rgb).to_index();
+        assert_eq!(rgb.to_ansi256_fg(), format!("\x1b[38;5;{}m", index));
+        assert_eq!(rgb.to_ansi256_bg(), format!("\x1b[48;5;{}m", index));
+        assert_eq!(rgb.to_ansi_fg_with(AnsiMode::default()), rgb.to_ansi_fg());
+        assert_eq!(rgb.paint("ok"), "\x1b[38;2;43;196;138mok\x1b[0m");
+        assert_eq!(rgb.paint_with("ok", AnsiMode::Ansi256), format!("\x1b[38;5;{}mok\x1b[0m", index));
+
+        // the 16 colors use the normal and bright codes
+        for (rgb, fg, bg) in [
+            ((0, 0, 0), 30, 40),
+            ((128, 0, 0), 31, 41),
+            ((192, 192, 192), 37, 47),
+            ((128, 128, 128), 90, 100),
+            ((255, 0, 0), 91, 101),
+            ((250, 250, 250), 97, 107),
+            ((0, 120, 130), 36, 46),
+        ] {
+            let rgb: RGB = rgb.try_into().unwrap();
+            assert_eq!(rgb.to_ansi_fg_with(AnsiMode::Ansi16), format!("\x1b[{}m", fg));
+            assert_eq!(rgb.to_ansi_bg_with(AnsiMode::Ansi16), format!("\x1b[{}m", bg));
+        }
+    }
+
+    #[test]
+    fn hex_format_works() {
+        let rgb: RGB = (43, 196, 138).try_into().unwrap();
+        assert_eq!(format!("{:x}", rgb), "2bc48a");
+        assert_eq!(format!("{:X}", rgb), "2BC48A");
+        assert_eq!(format!("{:#x}", rgb), "0x2bc48a");
+        assert_eq!(format!("{:#X}", rgb), "0x2BC48A");
+        // leading zeros are kept
+        assert_eq!(format!("{:x}", RGB::from_u32(0x0000FF)), "0000ff");
+        // width and fill
+        assert_eq!(format!("{:>10x}", rgb), "    2bc48a");
+        assert_eq!(format!("{:*<10X}", rgb), "2BC48A****");
+        assert_eq!(format!("{:#012x}", rgb), "0x00002bc48a");
+        assert_eq!(format!("[{:^10x}]", rgb), "[  2bc48a  ]");
+
+        let rgba: RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+        assert_eq!(format!("{:x}", rgba), "2bc48a80");
+        assert_eq!(format!("{:#X}", rgba), "0x2BC48A80");
+        assert_eq!(format!("{:X}", RGBA::from(rgb)), "2BC48AFF");
+
+        let hex: Hex = "#2bc48a".try_into().unwrap();
+        assert_eq!(format!("{:X}", hex), "2BC48A");
+        assert_eq!(format!("{:#x}", hex), "0x2bc48a");
+        let hex: Hex = "#2BC48A80".try_into().unwrap();
+        assert_eq!(format!("{:x}", hex), "2bc48a80");
+        assert_eq!(format!("{:>12X}", hex), "    2BC48A80");
+        assert_eq!(format!("#{:X}", hex), hex.to_string());
+    }
+
+    #[test]
+    fn modern_hue_string_works() {
+        let hsl: HSL = "hsl(157,64%,47%)".try_into().unwrap();
+        assert_eq!(hsl.to_css_modern(), "hsl(157deg 64% 47%)");
+        let hsla: HSLA = "hsla(157,64%,47%,0.85)".try_into().unwrap();
+        assert_eq!(hsla.to_css_modern(), "hsl(157deg 64% 47% / 0.85)");
+        let hsla: HSLA = "hsla(157,64%,47%,1)".try_into().unwrap();
+        assert_eq!(hsla.to_css_modern(), "hsl(157deg 64% 47%)");
+        let hsv: HSV = "hsv(157,78%,77%)".try_into().unwrap();
+        assert_eq!(hsv.to_modern_string(), "hsv(157deg 78% 77%)");
+        let hwb: HWB = "hwb(157 0% 23%)".try_into().unwrap();
+        assert_eq!(hwb.to_css_modern(), "hwb(157deg 0% 23%)");
+
+        // the modern strings parse back
+        for hue in (0..360).step_by(15) {
+            let hsla: HSLA = (hue, 64, 47, 0.85).try_into().unwrap();
+            assert_eq!(HSLA::try_from(hsla.to_css_modern().as_str()).unwrap(), hsla);
+            let hsv: HSV = (hue, 78, 77).try_into().unwrap();
+            assert_eq!(HSV::try_from(hsv.to_modern_string().as_str()).unwrap(), hsv);
+        }
+    }
+
+
+    #[test]
+    fn describe_works() {
+        let cases = [
+            ((0, 0, 0), "black"),
+            ((255, 255, 255), "white"),
+            ((128, 128, 128), "gray"),
+            ((60, 62, 60), "dark gray"),
+            ((255, 0, 0), "vivid red"),
+            ((255, 140, 0), "vivid orange"),
+            ((255, 180, 100), "light vivid orange"),
+            ((230, 210, 30), "vivid yellow"),
+            ((43, 196, 138), "green"),
+            ((0, 230, 118), "vivid green"),
+            ((60, 110, 115), "dark desaturated cyan"),
+            ((20, 40, 180), "dark vivid blue"),
+            ((120, 60, 200), "purple"),
+            ((150, 90, 60), "desaturated orange"),
+            ((200, 40, 200), "magenta"),
+            ((240, 120, 180), "light vivid pink"),
+        ];
+        for ((r, g, b), text) in cases {
+            let rgb: RGB = (r, g, b).try_into().unwrap();
+            assert_eq!(rgb.describe().to_string(), text, "{}", rgb);
+        }
+        let description = Hex::try_from("#3c6e73").unwrap().describe();
+        assert_eq!(description.hue_family(), Some(HueFamily::Cyan));
+        assert_eq!(description.lightness(), LightnessLevel::Dark);
+        assert_eq!(description.saturation(), SaturationLevel::Desaturated);
+        assert!(!description.is_neutral());
+        assert!(RGB::try_from((250, 250, 250)).unwrap().describe().is_neutral());
+    }
+
+
+    #[test]
+    fn cmyk_strings_works() {
+        let cmyk: CMYK = (78, 0, 30, 23).try_into().unwrap();
+        assert_eq!(cmyk.to_string(), "cmyk(78,0,30,23)");
+        assert_eq!(cmyk.to_percent_string(), "cmyk(78%, 0%, 30%, 23%)");
+        assert_eq!(cmyk.to_plate_string(), "C78 M0 Y30 K23");
+        assert_eq!(cmyk.to_fraction_string(), "device-cmyk(0.78 0 0.3 0.23)");
+        let cmyk: CMYK = (100, 5, 0, 100).try_into().unwrap();
+        assert_eq!(cmyk.to_fraction_string(), "device-cmyk(1 0.05 0 1)");
+        for s in [cmyk.to_percent_string(), cmyk.to_fraction_string()] {
+            assert_eq!(CMYK::try_from(s.as_str()).unwrap(), cmyk);
+        }
+    }
+
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_with_works() {
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Wire {
+            #[serde(with = "crate::serde::hex_string")]
+            hex_rgb: RGB,
+            #[serde(with = "crate::serde::hex_string")]
+            hex_rgba: RGBA,
+            #[serde(with = "crate::serde::rgb_struct")]
+            struct_rgb: RGB,
+            #[serde(with = "crate::serde::rgb_struct")]
+            struct_rgba: RGBA,
+            #[serde(with = "crate::serde::rgb_struct")]
+            struct_hex: Hex,
+            #[serde(with = "crate::serde::packed_u32")]
+            packed_rgb: RGB,
+            #[serde(with = "crate::serde::packed_u32")]
+            packed_rgba: RGBA,
+            #[serde(with = "crate::serde::packed_u32")]
+            packed_hex: Hex,
+        }
+        let rgb: RGB = (43, 196, 138).try_into().unwrap();
+        let rgba = RGBA::from_u32(0x2BC48A80);
+        let hex: Hex = "#2BC48A80".try_into().unwrap();
+        let wire = Wire {
+            hex_rgb: rgb,
+            hex_rgba: rgba,
+            struct_rgb: rgb,
+            struct_rgba: RGBA::try_from((43, 196, 138, 0.5)).unwrap(),
+            struct_hex: hex,
+            packed_rgb: rgb,
+            packed_rgba: rgba,
+            packed_hex: hex,
+        };
+        let json = serde_json::to_string(&wire).unwrap();
+        let expected = concat!(
+            r##"{"hex_rgb":"#2BC48A","hex_rgba":"#2BC48A80","##,
+            r#""struct_rgb":{"r":43,"g":196,"b":138},"struct_rgba":{"r":43,"g":196,"b":138,"a":0.5},"#,
+            r#""struct_hex":{"r":43,"g":196,"b":138,"a":0.5019608},"#,
+            r#""packed_rgb":2868362,"packed_rgba":734300800,"packed_hex":734300800}"#
+        );
+        assert_eq!(json, expected);
+        assert_eq!(serde_json::from_str::<Wire>(&json).unwrap(), wire);
+
+        // the alpha is 1 when it is missing, and is validated like `try_from`
+        #[derive(::serde::Deserialize)]
+        struct Field(#[serde(with = "crate::serde::rgb_struct")] RGBA);
+        let Field(rgba) = serde_json::from_str(r#"{"b":3,"g":2,"r":1}"#).unwrap();
+        assert_eq!(rgba.to_string(), "rgba(1,2,3,1.00)");
+        assert!(serde_json::from_str::<Field>(r#"{"r":1,"g":2,"b":3,"a":2}"#).is_err());
+        assert!(serde_json::from_str::<Field>(r#"{"r":300,"g":2,"b":3}"#).is_err());
+        assert!(serde_json::from_str::<Field>(r#"{"r":1,"g":2}"#).is_err());
+        assert!(serde_json::from_str::<Field>(r#"{"r":1,"g":2,"b":3,"x":0}"#).is_err());
+    }
+
+
+    #[test]
+    fn hex_format_prefix_works() {
+        let opaque: Hex = "#2bc48a".try_into().unwrap();
+        let transparent: Hex = "#2BC48A80".try_into().unwrap();
+        assert_eq!(
+            opaque.format(HexPrefix::default(), HexCase::default(), AlphaPosition::default()),
+            opaque.to_string()
+        );
+        assert_eq!(
+            transparent.format(HexPrefix::Hash, HexCase::Upper, AlphaPosition::Auto),
+            transparent.to_string()
+        );
+        assert_eq!(
+            opaque.format(HexPrefix::Hash, HexCase::Upper, AlphaPosition::Last),
+            opaque.to_hex_alpha()
+        );
+        assert_eq!(
+            transparent.format(HexPrefix::Hash, HexCase::Upper, AlphaPosition::First),
+            transparent.to_alpha_hex()
+        );
+        let cases = [
+            (HexPrefix::ZeroX, HexCase::Upper, AlphaPosition::Auto, "0x2BC48A80"),
+            (HexPrefix::None, HexCase::Lower, AlphaPosition::Auto, "2bc48a80"),
+            (HexPrefix::Hash, HexCase::Lower, AlphaPosition::First, "#802bc48a"),
+            (HexPrefix::ZeroX, HexCase::Lower, AlphaPosition::Omit, "0x2bc48a"),
+        ];
+        for (prefix, case, position, expected) in cases {
+            let s = transparent.format(prefix, case, position);
+            assert_eq!(s, expected);
+        }
+        // every written string except the alpha first one parses back
+        let s = transparent.format(HexPrefix::ZeroX, HexCase::Lower, AlphaPosition::Last);
+        assert_eq!(Hex::try_from(s.as_str()).unwrap(), transparent);
+        let s = opaque.format(HexPrefix::None, HexCase::Lower, AlphaPosition::Omit);
+        assert_eq!(Hex::try_from(s.as_str()).unwrap(), opaque);
+    }
+
+
+    #[test]
+    fn gradient_to_css_works() {
+        let css = "linear-gradient(90deg, #2BC48A 0%, #FF7D37 100%)";
+        let gradient = Gradient::from_css(css).unwrap();
+        assert_eq!(gradient.direction(), Some("90deg"));
+        assert_eq!(gradient.to_css("90deg"), css);
+
+        // the missing positions are written out, spread like CSS does
+        let gradient = Gradient::from_css(
+            "LINEAR-GRADIENT(#2bc48a, hsl(0 100% 50%), rgb(0 0 0 / 50%) 40%, white 20%, blue)",
+        )
+        .unwrap();
+        assert_eq!(gradient.direction(), None);
+        assert_eq!(gradient.stops()[2].position(), Some(40.0));
+        assert_eq!(gradient.positions(), vec![0.0, 20.0, 40.0, 40.0, 100.0]);
+        let css = gradient.to_css("to right");
+        assert_eq!(
+            css,
+            "linear-gradient(to right, #2BC48A 0%, #FF0000 20%, rgba(0,0,0,0.50) 40%, #FFFFFF 40%, #0000FF 100%)"
+        );
+        let again = Gradient::from_css(&css).unwrap();
+        assert_eq!(again.to_css("to right"), css);
+        assert_eq!(again.positions(), gradient.positions());
+
+        let mut gradient = Gradient::new();
+        gradient
+            .push(RGB::try_from((0, 0, 0)).unwrap(), None)
+            .push(RGB::try_from((0, 0, 0)).unwrap(), None)
+            .push(RGB::try_from((0, 0, 0)).unwrap(), Some(60.0))
+            .push(RGB::try_from((0, 0, 0)).unwrap(), None);
+        assert_eq!(gradient.positions(), vec![0.0, 30.0, 60.0, 100.0]);
+        assert_eq!(gradient.len(), 4);
+
+        let errors = [
+            ("radial-gradient(red, blue)", "is not a linear-gradient()"),
+            ("linear-gradient(90deg, red)", "at least 2 color stops"),
+            ("linear-gradient(red, blue x%)", "component 1 error: invalid position 'x%'"),
+            ("linear-gradient(to left, red, bleu)", "component 2 error: stop 'bleu' is not a color"),
+        ];
+        for (css, reason) in errors {
+            let err = Gradient::from_css(css).unwrap_err().to_string();
+            assert!(err.contains(reason), "{}", err);
+        }
+    }
+
+
+    #[test]
+    fn ramp_variables_works() {
+        let mut ramp = Ramp::new();
+        for (key, hex) in [
+            ("50", "#EAF9F3"),
+            ("100", "#D5F3E8"),
+            ("500", "#2BC48A"),
+            ("900", "#09271C"),
+        ] {
+            ramp.push(key, Hex::try_from(hex).unwrap());
+        }
+        ramp.push("overlay", RGBA::try_from((0, 0, 0, 0.5)).unwrap());
+        // pushing an existing key replaces the color and keeps the order
+        ramp.push("100", RGB::try_from((213, 243, 232)).unwrap());
+        assert_eq!(ramp.len(), 5);
+        assert_eq!(ramp.get("500").unwrap().to_string(), "rgba(43,196,138,1.00)");
+        assert_eq!(ramp.get("600"), None);
+        assert_eq!(
+            ramp.to_css_variables("brand"),
+            ":root {
+  --brand-50: #EAF9F3;
+  --brand-100: #D5F3E8;
+  --brand-500: #2BC48A;
+  --brand-900: #09271C;
+  --brand-overlay: #00000080;
+}
+"
+        );
+        assert_eq!(
+            ramp.to_scss_variables("brand"),
+            "$brand-50: #EAF9F3;
+$brand-100: #D5F3E8;
+$brand-500: #2BC48A;
+$brand-900: #09271C;
+$brand-overlay: #00000080;
+"
+        );
+        assert_eq!(Ramp::new().to_css_variables("empty"), ":root {\n}\n");
+        assert_eq!(Ramp::new().to_scss_variables("empty"), "");
+    }
+
+
+    #[test]
+    fn css_filter_works() {
+        let targets = [
+            (43, 196, 138),
+            (255, 125, 55),
+            (255, 0, 0),
+            (0, 0, 255),
+            (0, 0, 0),
+            (255, 255, 255),
+            (128, 128, 128),
+            (250, 220, 20),
+            (120, 60, 200),
+            (10, 40, 60),
+        ];
+        for target in targets {
+            let rgb: RGB = target.try_into().unwrap();
+            let filter = rgb.to_css_filter();
+            assert!(filter.delta_e() < 5.0, "{} {} {}", rgb, filter, filter.delta_e());
+            assert_eq!(filter, rgb.to_css_filter());
+            let s = filter.to_string();
+            let names = ["invert", "sepia", "saturate", "hue-rotate", "brightness", "contrast"];
+            let parts: Vec<&str> = s.split(' ').collect();
+            assert_eq!(parts.len(), 6);
+            for (part, name) in parts.iter().zip(names) {
+                assert!(part.starts_with(name) && part.ends_with(")"), "{}", s);
+            }
+        }
+        let rgb: RGB = (255, 0, 0).try_into().unwrap();
+        let filter = rgb.to_css_filter();
+        assert_eq!(
+            filter.to_string(),
+            "invert(19%) sepia(97%) saturate(6598%) hue-rotate(359deg) brightness(116%) contrast(122%)"
+        );
+        assert_eq!(filter.color(), rgb);
+        assert!(filter.loss() < 1.0);
+
+        let rgb: RGB = (43, 196, 138).try_into().unwrap();
+        let other = rgb.to_css_filter_with_seed(42);
+        assert_eq!(other, rgb.to_css_filter_with_seed(42));
+        assert!(other.delta_e() < 5.0);
+    }
+
+
+    #[test]
+    fn rotate_hue_works() {
+        let hsl: HSL = (350, 100, 50).try_into().unwrap();
+        assert_eq!(hsl.rotate_hue(20).hue(), 10);
+        assert_eq!(hsl.rotate_hue(20).rotate_hue(-30).hue(), 340);
+        assert_eq!(hsl.rotate_hue(-350).hue(), 0);
+        for degrees in [360, -360, 720, -1080, 0] {
+            assert_eq!(hsl.rotate_hue(degrees), hsl);
+        }
+        // every hue is kept exactly, not rounded through RGB
+        let hsl: HSL = (10, 64, 47).try_into().unwrap();
+        for degrees in (-720i32..720).step_by(7) {
+            let expected = (10 + degrees).rem_euclid(360) as u32;
+            assert_eq!(hsl.rotate_hue(degrees).hue(), expected);
+            assert_eq!(hsl.rotate_hue(degrees).rotate_hue(-degrees), hsl);
+        }
+        let hsl: HSL = (350, 100, 50).try_into().unwrap();
+        // i32::MIN is -128 and i32::MAX is 127 modulo 360, without overflowing
+        assert_eq!(hsl.rotate_hue(i32::MIN).hue(), 222);
+        assert_eq!(hsl.rotate_hue(i32::MAX).hue(), 117);
+
+        let rgb: RGB = (255, 0, 0).try_into().unwrap();
+        assert_eq!(rgb.rotate_hue(180).to_string(), "rgb(0,255,255)");
+        assert_eq!(rgb.rotate_hue(-120).to_string(), "rgb(0,0,255)");
+        let rgba: RGBA = (255, 0, 0, 0.5).try_into().unwrap();
+        assert_eq!(rgba.rotate_hue(120).to_string(), "rgba(0,255,0,0.50)");
+        let hex: Hex = "#2BC48A".try_into().unwrap();
+        assert_eq!(hex.rotate_hue(360), hex);
+
+        // a hue of 360 is 0, it used to fall out of every sector and turn black
+        let hsl: HSL = (360, 100, 50).try_into().unwrap();
+        assert_eq!(RGB::from(hsl).to_string(), "rgb(255,0,0)");
+        let hsv: HSV = (360, 100, 100).try_into().unwrap();
+        assert_eq!(RGB::from(hsv).to_string(), "rgb(255,0,0)");
+        for h in 355..=365 {
+            let red = RGB::from(HSL::try_from((h % 361, 100, 50)).unwrap());
+            assert!(red.r > 200, "{} {}", h, red);
+        }
+        // and a hue just below 360 rounds to 0 instead of 360
+        let rgb: RGB = (255, 0, 1).try_into().unwrap();
+        assert_eq!(HSL::from(rgb).to_string(), "hsl(0,100%,50%)");
+        assert_eq!(HSV::from(rgb).hue(), 0);
+    }
+
+
+    #[test]
+    fn whiten_blacken_works() {
+        for hex in ["#2BC48A", "#000000", "#FFFFFF", "#FF7D37", "#808080"] {
+            let mut hex: Hex = hex.try_into().unwrap();
+            assert_eq!(hex.whiten(1.0).to_string(), "#FFFFFF");
+            assert_eq!(hex.blacken(1.0).to_string(), "#000000");
+        }
+        // through the whole numbers of HWB, which are exact for this color
+        let mut hex: Hex = "#2BC48A".try_into().unwrap();
+        assert_eq!(hex.whiten(0.0), hex);
+        assert_eq!(hex.blacken(0.0), hex);
+        let mut rgb: RGB = (43, 196, 138).try_into().unwrap();
+        assert_eq!(HWB::from(rgb.whiten(0.5)).to_string(), "hwb(158 59% 23%)");
+        assert_eq!(HWB::from(rgb.blacken(0.5)).to_string(), "hwb(157 17% 62%)");
+        // the other component is reduced, so the hue is kept until the end
+        let mut hwb: HWB = (157, 17, 23).try_into().unwrap();
+        assert_eq!(hwb.whiten(0.9).to_string(), "hwb(157 92% 8%)");
+        let mut hwb: HWB = (157, 60, 40).try_into().unwrap();
+        assert_eq!(hwb.blacken(0.5).to_string(), "hwb(157 30% 70%)");
+        let mut hwb: HWB = (157, 10, 10).try_into().unwrap();
+        assert_eq!(hwb.whiten(-1.0).whiten(f32::NAN).to_string(), "hwb(157 10% 10%)");
+        assert_eq!(hwb.whiten(2.0).to_string(), "hwb(157 100% 0%)");
+        // the alpha is kept
+        let mut rgba: RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+        assert_eq!(rgba.blacken(1.0).to_string(), "rgba(0,0,0,0.50)");
+    }
+
+
+    #[test]
+    fn tint_shade_works() {
+        for hex in ["#2BC48A", "#000000", "#FFFFFF", "#FF7D37", "#808080"] {
+            let hex: Hex = hex.try_into().unwrap();
+            assert_eq!(hex.tint(0.0), hex);
+            assert_eq!(hex.shade(0.0), hex);
+            assert_eq!(hex.tint(1.0).to_string(), "#FFFFFF");
+            assert_eq!(hex.shade(1.0).to_string(), "#000000");
+        }
+        let rgb: RGB = (43, 196, 138).try_into().unwrap();
+        assert_eq!(rgb.tint(0.2).to_string(), "rgb(85,207,161)");
+        assert_eq!(rgb.shade(0.2).to_string(), "rgb(34,156,110)");
+        assert_eq!(rgb.tint(0.2), ColorMix::mix(&rgb, RGB::try_from((255, 255, 255)).unwrap(), Some(0.2)));
+        // out of range weights are clamped
+        assert_eq!(rgb.tint(2.0).to_string(), "rgb(255,255,255)");
+        assert_eq!(rgb.shade(-1.0), rgb);
+        assert_eq!(rgb.shade(f32::NAN), rgb);
+
+        // the alpha is kept, not averaged towards the opaque white and black
+        let rgba: RGBA = (43, 196, 138, 0.3).try_into().unwrap();
+        assert_eq!(rgba.tint(0.7).alpha(), 0.3);
+        assert_eq!(rgba.shade(0.7).alpha(), 0.3);
+        let hsla: HSLA = "hsla(157, 64%, 47%, 0.5)".try_into().unwrap();
+        assert_eq!(hsla.shade(1.0).to_string(), "hsla(0,0%,0%,0.50)");
+    }
+
+
+    #[test]
+    fn complement_works() {
+        let hsl: HSL = (157, 64, 47).try_into().unwrap();
+        assert_eq!(hsl.complement().to_string(), "hsl(337,64%,47%)");
+        assert_eq!(hsl.complement().complement(), hsl);
+        let hsl: HSL = (300, 50, 50).try_into().unwrap();
+        assert_eq!(hsl.complement().hue(), 120);
+
+        let rgb: RGB = (255, 0, 0).try_into().unwrap();
+        assert_eq!(rgb.complement().to_string(), "rgb(0,255,255)");
+        let hex: Hex = "#2BC48A".try_into().unwrap();
+        assert_eq!(hex.complement().to_string(), "#C52B66");
+        // through the whole numbers of HSL, so it is an involution within rounding
+        let twice = hex.complement().complement();
+        assert_eq!(HSL::from(twice), HSL::from(hex));
+        // unlike negate, which inverts the channels
+        assert_eq!(hex.negate().to_string(), "#D43B75");
+
+        for gray in ["#000000", "#808080", "#FFFFFF"] {
+            let hex: Hex = gray.try_into().unwrap();
+            assert_eq!(hex.complement(), hex);
+        }
+        let hsl: HSL = (120, 0, 40).try_into().unwrap();
+        assert_eq!(hsl.complement(), hsl);
+        let rgba: RGBA = (255, 0, 0, 0.5).try_into().unwrap();
+        assert_eq!(rgba.complement().to_string(), "rgba(0,255,255,0.50)");
+    }
+
+
+    #[test]
+    fn triadic_works() {
+        let hsl: HSL = (300, 64, 47).try_into().unwrap();
+        let hues = hsl.triadic().map(|c| c.hue());
+        assert_eq!(hues, [300, 60, 180]);
+        assert!(hsl.triadic().iter().all(|c| c.saturation() == 64 && c.lightness() == 47));
+
+        let cases = [
+            ("#FF0000", ["#FF0000", "#00FF00", "#0000FF"]),
+            ("#2BC48A", ["#2BC48A", "#8A2BC5", "#C5892B"]),
+            ("#FF7D37", ["#FF7D37", "#38FF7E", "#7E38FF"]),
+        ];
+        for (base, expected) in cases {
+            let hex: Hex = base.try_into().unwrap();
+            let triad = hex.triadic().map(|c| c.to_string());
+            assert_eq!(triad, expected.map(String::from), "{}", base);
+        }
+        let rgba: RGBA = (255, 0, 0, 0.5).try_into().unwrap();
+        assert_eq!(rgba.triadic()[1].to_string(), "rgba(0,255,0,0.50)");
+    }
+
+
+    #[test]
+    fn tetradic_works() {
+        let hsl: HSL = (300, 64, 47).try_into().unwrap();
+        assert_eq!(hsl.tetradic().map(|c| c.hue()), [300, 30, 120, 210]);
+        assert_eq!(hsl.rectangle(60).map(|c| c.hue()), [300, 0, 120, 180]);
+        assert_eq!(hsl.rectangle(-30).map(|c| c.hue()), [300, 270, 120, 90]);
+        assert_eq!(hsl.rectangle(0).map(|c| c.hue()), [300, 300, 120, 120]);
+        assert!(hsl.tetradic().iter().all(|c| c.saturation() == 64 && c.lightness() == 47));
+
+        let hex: Hex = "#FF0000".try_into().unwrap();
+        let square = hex.tetradic().map(|c| c.to_string());
+        assert_eq!(square, ["#FF0000", "#80FF00", "#00FFFF", "#8000FF"].map(String::from));
+        let hsla: HSLA = "hsla(350, 100%, 50%, 0.5)".try_into().unwrap();
+        assert_eq!(hsla.rectangle(30)[3].to_string(), "hsla(200,100%,50%,0.50)");
+    }
+
+
+    #[test]
+    fn analogous_works() {
+        let hsl: HSL = (10, 64, 47).try_into().unwrap();
+        let hues: Vec<u32> = hsl.analogous(5, 60).iter().map(|c| c.hue()).collect();
+        assert_eq!(hues, vec![340, 355, 10, 25, 40]);
+        let hues: Vec<u32> = hsl.analogous(4, 60).iter().map(|c| c.hue()).collect();
+        assert_eq!(hues, vec![340, 0, 20, 40]);
+        let hues: Vec<u32> = hsl.analogous(2, 90).iter().map(|c| c.hue()).collect();
+        assert_eq!(hues, vec![325, 55]);
+        assert_eq!(hsl.analogous(1, 60), vec![hsl]);
+        assert!(hsl.analogous(0, 60).is_empty());
+        assert_eq!(hsl.analogous(3, 0), vec![hsl; 3]);
+        let hues: Vec<u32> = hsl.analogous(5, 720).iter().map(|c| c.hue()).collect();
+        assert_eq!(hues, vec![190, 280, 10, 100, 190]);
+        assert!(hsl.analogous(5, 60).iter().all(|c| c.saturation() == 64 && c.lightness() == 47));
+
+        let hex: Hex = "#FF0000".try_into().unwrap();
+        let colors: Vec<String> = hex.analogous(3, 60).iter().map(|c| c.to_string()).collect();
+        assert_eq!(colors, vec!["#FF0080", "#FF0000", "#FF7F00"]);
+    }
+
+
+    #[test]
+    fn split_complementary_works() {
+        let hsl: HSL = (300, 64, 47).try_into().unwrap();
+        assert_eq!(hsl.split_complementary(30).map(|c| c.hue()), [300, 90, 150]);
+        assert_eq!(hsl.split_complementary(0).map(|c| c.hue()), [300, 120, 120]);
+        assert_eq!(hsl.split_complementary(0)[1], hsl.complement());
+        assert_eq!(hsl.split_complementary(90).map(|c| c.hue()), [300, 30, 210]);
+        assert_eq!(hsl.split_complementary(150), hsl.split_complementary(90));
+        let hsl: HSL = (170, 64, 47).try_into().unwrap();
+        assert_eq!(hsl.split_complementary(30).map(|c| c.hue()), [170, 320, 20]);
+
+        let hex: Hex = "#FF0000".try_into().unwrap();
+        let colors = hex.split_complementary(30).map(|c| c.to_string());
+        assert_eq!(colors, ["#FF0000", "#00FF80", "#007FFF"].map(String::from));
+        let rgb: RGB = (255, 0, 0).try_into().unwrap();
+        assert_eq!(rgb.split_complementary(60)[1].to_string(), "rgb(0,255,0)");
+    }
+
+
+    #[test]
+    fn monochromatic_works() {
+        let hsl: HSL = (157, 64, 47).try_into().unwrap();
+        let scale: Vec<u32> = hsl.monochromatic(5).iter().map(|c| c.lightness()).collect();
+        assert_eq!(scale, vec![17, 33, 47, 67, 83]);
+        let scale: Vec<u32> = hsl.monochromatic_with(5, true).iter().map(|c| c.lightness()).collect();
+        assert_eq!(scale, vec![0, 25, 47, 75, 100]);
+        let scale = hsl.monochromatic(4);
+        let lightness: Vec<u32> = scale.iter().map(|c| c.lightness()).collect();
+        assert_eq!(lightness, vec![20, 47, 60, 80]);
+        assert!(scale.iter().all(|c| c.hue() == 157 && c.saturation() == 64));
+        assert_eq!(scale[1], hsl);
+        assert_eq!(hsl.monochromatic(1), vec![hsl]);
+        assert_eq!(hsl.monochromatic_with(1, true), vec![hsl]);
+        assert!(hsl.monochromatic(0).is_empty());
+
+        // ordered dark to light, with the color at its natural position
+        let hex: Hex = "#2BC48A".try_into().unwrap();
+        let scale = hex.monochromatic(7);
+        assert!(scale.contains(&hex));
+        let lightness: Vec<u32> = scale.iter().map(|c| HSL::from(*c).lightness()).collect();
+        assert!(lightness.windows(2).all(|w| w[0] <= w[1]), "{:?}", lightness);
+        let hex: Hex = "#000000".try_into().unwrap();
+        assert_eq!(hex.monochromatic(3)[0], hex);
+    }
+
+
+    #[test]
+    fn lerp_works() {
+        let a: Hex = "#2BC48A".try_into().unwrap();
+        let b: Hex = "#FF7D3780".try_into().unwrap();
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, -1.0), a);
+        assert_eq!(a.lerp(b, 2.0), b);
+        assert_eq!(a.lerp(b, f32::NAN), a);
+        let mid: RGBA = a.lerp(b, 0.5).into();
+        assert_eq!(mid.to_string(), "rgba(149,161,97,0.75)");
+        // the alpha does not weight the channels like mix does
+        let rgba: RGBA = (0, 0, 0, 1.0).try_into().unwrap();
+        let other: RGBA = (255, 255, 255, 0.0).try_into().unwrap();
+        assert_eq!(rgba.lerp(other, 0.5).to_string(), "rgba(128,128,128,0.50)");
+        let rgb: RGB = (10, 20, 30).try_into().unwrap();
+        assert_eq!(Lerp::lerp(&rgb, other, 1.0), RGB::try_from((255, 255, 255)).unwrap());
+        // the alpha of the end is exact at t=1
+        let a: Hex = "#2BC48A01".try_into().unwrap();
+        let b: Hex = "#FF7D3703".try_into().unwrap();
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(RGBA::from(a).lerp(b, 1.0), RGBA::from(b));
+        assert_eq!(RGBAf::from(a).lerp(b, 1.0), RGBAf::from(b));
+        let rgbf: RGBf = (0.1, 0.7, 0.3).try_into().unwrap();
+        let other: RGBf = (0.9, 0.2, 0.6).try_into().unwrap();
+        assert_eq!(rgbf.lerp(other, 1.0), other);
+        // 0.7 + (0.1 - 0.7) * 1.0 is not 0.1 in f32, RGBAf must return the end exactly as well
+        let rgbaf: RGBAf = (0.7, 0.6, 0.3, 0.7).try_into().unwrap();
+        let other: RGBAf = (0.1, 0.2, 0.6, 0.1).try_into().unwrap();
+        assert_eq!(rgbaf.lerp(other, 1.0), other);
+        assert_eq!(rgbaf.lerp(other, 1.0).alpha(), 0.1);
+    }
+
+
+    #[test]
+    fn gradient_sample_works() {
+        let black: RGBA = (0, 0, 0, 1.0).try_into().unwrap();
+        let red: RGBA = (255, 0, 0, 1.0).try_into().unwrap();
+        let white: RGBA = (255, 255, 255, 1.0).try_into().unwrap();
+        let gradient = Gradient::from_stops([(1.0, white), (0.0, black), (0.5, red)]);
+        let colors: Vec<RGBA> = gradient.stops().iter().map(|s| s.color()).collect();
+        assert_eq!(colors, vec![black, red, white]);
+        assert_eq!(gradient.sample(0.0), black);
+        assert_eq!(gradient.sample(0.5), red);
+        assert_eq!(gradient.sample(1.0), white);
+        assert_eq!(gradient.sample(-1.0), black);
+        assert_eq!(gradient.sample(f32::NAN), black);
+        assert_eq!(gradient.sample(0.25).to_string(), "rgba(128,0,0,1.00)");
+        assert_eq!(gradient.sample(0.75).to_string(), "rgba(255,128,128,1.00)");
+        let colors: Vec<String> = gradient.colors(5).iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            colors,
+            vec![
+                "rgba(0,0,0,1.00)",
+                "rgba(128,0,0,1.00)",
+                "rgba(255,0,0,1.00)",
+                "rgba(255,128,128,1.00)",
+                "rgba(255,255,255,1.00)"
+            ]
+        );
+        assert_eq!(gradient.colors(1), vec![black]);
+        assert!(gradient.colors(0).is_empty());
+
+        // the stops at the same position keep their order, the later one wins at the edge
+        let mut gradient = Gradient::new();
+        gradient
+            .add_stop(0.0, black)
+            .add_stop(0.5, black)
+            .add_stop(1.0, white)
+            .add_stop(0.5, red);
+        let colors: Vec<RGBA> = gradient.stops().iter().map(|s| s.color()).collect();
+        assert_eq!(colors, vec![black, black, red, white]);
+        assert_eq!(gradient.sample(0.49), black);
+        assert_eq!(gradient.sample(0.5), red);
+
+        // the stops end at the first and last positions
+        let gradient = Gradient::from_stops([(0.2, black), (0.8, white)]);
+        assert_eq!(gradient.sample(0.1), black);
+        assert_eq!(gradient.sample(0.9), white);
+        assert_eq!(Gradient::from_stops([(0.3, red)]).sample(0.9), red);
+        assert_eq!(Gradient::new().sample(0.5), RGBA::default());
+    }
+
+
+    #[test]
+    fn easing_works() {
+        let curves = [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::CubicBezier(0.68, -0.55, 0.27, 1.55),
+        ];
+        for easing in curves {
+            assert_eq!(easing.ease(0.0), 0.0);
+            assert_eq!(easing.ease(1.0), 1.0);
+            assert_eq!(easing.ease(-1.0), 0.0);
+            assert_eq!(easing.ease(2.0), 1.0);
+        }
+        assert!((Easing::EaseInOut.ease(0.5) - 0.5).abs() < 1e-4);
+        assert!((Easing::CubicBezier(0.0, 0.0, 1.0, 1.0).ease(0.3) - 0.3).abs() < 1e-4);
+        // the values of ease-in of the browsers
+        assert!((Easing::EaseIn.ease(0.5) - 0.3153).abs() < 1e-3);
+        assert!((Easing::EaseOut.ease(0.5) - 0.6847).abs() < 1e-3);
+        // the back curve overshoots
+        assert!(Easing::CubicBezier(0.68, -0.55, 0.27, 1.55).ease(0.1) < 0.0);
+
+        let a: Hex = "#2BC48A".try_into().unwrap();
+        let b: Hex = "#FF7D37".try_into().unwrap();
+        for easing in curves {
+            assert_eq!(a.lerp_eased(b, 0.0, easing), a);
+            assert_eq!(a.lerp_eased(b, 1.0, easing), b);
+        }
+        assert_eq!(a.lerp_eased(b, 0.5, Easing::EaseInOut), a.lerp(b, 0.5));
+        assert_eq!(a.lerp_eased(b, 0.3, Easing::Linear), a.lerp(b, 0.3));
+
+        let black: RGBA = (0, 0, 0, 1.0).try_into().unwrap();
+        let white: RGBA = (255, 255, 255, 1.0).try_into().unwrap();
+        let gradient = Gradient::from_stops([(0.0, black), (1.0, white)]);
+        assert_eq!(gradient.sample_eased(0.5, Easing::EaseInOut), gradient.sample(0.5));
+        assert_eq!(gradient.sample_eased(1.0, Easing::EaseIn), white);
+        assert!(gradient.sample_eased(0.5, Easing::EaseIn).red() < gradient.sample(0.5).red());
+    }
+
+
+    #[test]
+    fn steps_to_works() {
+        let a: Hex = "#2BC48A".try_into().unwrap();
+        let b: Hex = "#FF7D37".try_into().unwrap();
+        let steps: Vec<String> = a.steps_to(b, 5).iter().map(|c| c.to_string()).collect();
+        assert_eq!(steps, vec!["#2BC48A", "#60B275", "#95A161", "#CA8F4C", "#FF7D37"]);
+        assert_eq!(a.steps_to(b, 1), vec![a]);
+        assert_eq!(a.steps_to(b, 2), vec![a, b]);
+        assert!(a.steps_to(b, 0).is_empty());
+        let rgb: RGB = (0, 0, 0).try_into().unwrap();
+        let white: RGBA = (255, 255, 255, 1.0).try_into().unwrap();
+        let steps = rgb.steps_to(white, 3);
+        assert_eq!(steps[1], RGB::try_from((128, 128, 128)).unwrap());
+        assert_eq!(steps[2], RGB::try_from((255, 255, 255)).unwrap());
+    }
+
+
+    #[test]
+    fn blend_works() {
+        let hex = |s: &str| -> RGBA { Hex::try_from(s).unwrap().into() };
+        let blend = |source: &str, backdrop: &str, mode| Hex::from(hex(source).blend(hex(backdrop), mode)).to_string();
+        assert_eq!(blend("#808080", "#808080", BlendMode::Multiply), "#404040");
+        assert_eq!(blend("#808080", "#808080", BlendMode::Screen), "#C0C0C0");
+        assert_eq!(blend("#2BC48A", "#FF7D37", BlendMode::Normal), "#2BC48A");
+        assert_eq!(blend("#2BC48A", "#FF7D37", BlendMode::Darken), "#2B7D37");
+        assert_eq!(blend("#2BC48A", "#FF7D37", BlendMode::Lighten), "#FFC48A");
+        assert_eq!(blend("#2BC48A", "#FF7D37", BlendMode::Difference), "#D44753");
+        assert_eq!(blend("#FFFFFF", "#2BC48A", BlendMode::Exclusion), "#D43B75");
+        assert_eq!(blend("#000000", "#2BC48A", BlendMode::ColorDodge), "#2BC48A");
+        assert_eq!(blend("#FFFFFF", "#2BC48A", BlendMode::ColorBurn), "#2BC48A");
+        assert_eq!(blend("#FFFFFF", "#2BC48A", BlendMode::ColorDodge), "#FFFFFF");
+        assert_eq!(blend("#000000", "#2BC48A", BlendMode::ColorBurn), "#000000");
+        assert_eq!(blend("#FFFFFF", "#2BC48A", BlendMode::HardLight), "#FFFFFF");
+        assert_eq!(blend("#2BC48A", "#FFFFFF", BlendMode::Overlay), "#FFFFFF");
+        assert_eq!(blend("#2BC48A", "#FF7D37", BlendMode::Overlay), "#FFC03C");
+        assert_eq!(blend("#2BC48A", "#FF7D37", BlendMode::HardLight), "#56C347");
+        assert_eq!(blend("#2BC48A", "#FF7D37", BlendMode::SoftLight), "#FF9A3C");
+        assert_eq!(blend("#2BC48A", "#FF7D37", BlendMode::ColorDodge), "#FFFF78");
+        assert_eq!(blend("#2BC48A", "#FF7D37", BlendMode::ColorBurn), "#FF5600");
+
+        // the source alpha composites the blended color onto the backdrop
+        assert_eq!(blend("#2BC48A00", "#FF7D37", BlendMode::Multiply), "#FF7D37");
+        assert_eq!(blend("#80808080", "#808080", BlendMode::Multiply), "#606060");
+        // a transparent backdrop shows the source color
+        assert_eq!(blend("#2BC48A", "#FF7D3700", BlendMode::Multiply), "#2BC48A");
+        let source: RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+        let rgba = source.blend(RGBA::try_from((255, 125, 55, 0.5)).unwrap(), BlendMode::Screen);
+        assert_eq!(rgba.alpha(), 0.75);
+        assert_eq!(hex("#2BC48A00").blend(hex("#FF7D3700"), BlendMode::Screen), RGBA::default());
+    }
+
+
+    #[test]
+    fn over_works() {
+        let fg: RGBA = (43, 196, 138, 0.4).try_into().unwrap();
+        let bg: RGBA = (18, 18, 18, 1.0).try_into().unwrap();
+        assert_eq!(fg.over(bg).to_string(), "rgba(28,89,66,1.00)");
+        assert_eq!(fg.over_linear(bg).to_string(), "rgba(30,130,91,1.00)");
+        let transparent: RGBA = (43, 196, 138, 0.0).try_into().unwrap();
+        let translucent: RGBA = (255, 125, 55, 0.3).try_into().unwrap();
+        assert_eq!(transparent.over(translucent), translucent);
+        assert_eq!(transparent.over_linear(translucent), translucent);
+        let opaque: RGBA = (43, 196, 138, 1.0).try_into().unwrap();
+        assert_eq!(opaque.over(translucent), opaque);
+        assert_eq!(opaque.over_linear(translucent), opaque);
+        assert_eq!(translucent.over(transparent), translucent);
+        // a_out = a_fg + a_bg * (1 - a_fg)
+        assert!((translucent.over(fg).alpha() - 0.58).abs() < 1e-6);
+
+        let hsla: HSLA = (157, 64, 47, 0.5).try_into().unwrap();
+        let hex: Hex = "#121212".try_into().unwrap();
+        let over: HSLA = hsla.over(hex);
+        assert_eq!(over.alpha(), 1.0);
+        assert_eq!(over, HSLA::from(RGBA::from(hsla).over(hex)));
+    }
+
+
+    #[test]
+    fn flatten_onto_works() {
+        let dark: Hex = "#121212".try_into().unwrap();
+        let rgba: RGBA = "rgba(43,196,138,0.4)".try_into().unwrap();
+        let flat = rgba.flatten_onto(dark);
+        assert_eq!(flat.to_string(), "rgb(28,89,66)");
+        let hsla: HSLA = rgba.into();
+        assert_eq!(hsla.flatten_onto(dark), RGBA::from(hsla).flatten_onto(dark));
+        let hex: Hex = rgba.into();
+        assert_eq!(hex.flatten_onto(dark), RGBA::from(hex).flatten_onto(dark));
+        // rounded instead of truncated, 0.5 * 255 + 0.5 * 0 is 127.5
+        let half: RGBA = (255, 255, 255, 0.5).try_into().unwrap();
+        assert_eq!(half.flatten_onto(RGB::try_from((0, 0, 0)).unwrap()).to_string(), "rgb(128,128,128)");
+        // the default of From is the white background
+        let white = RGB::try_from((255, 255, 255)).unwrap();
+        assert_eq!(rgba.flatten_onto(white), RGB::from(rgba));
+        let opaque: RGBA = (43, 196, 138, 1.0).try_into().unwrap();
+        assert_eq!(opaque.flatten_onto(dark).to_string(), "rgb(43,196,138)");
+        let transparent: RGBA = (43, 196, 138, 0.0).try_into().unwrap();
+        assert_eq!(transparent.flatten_onto(dark), RGB::from(dark));
+    }
+
+
+    #[test]
+    fn contrast_ratio_works() {
+        let black: RGB = (0, 0, 0).try_into().unwrap();
+        let white: Hex = "#FFFFFF".try_into().unwrap();
+        assert_eq!(black.contrast_ratio(white), 21.0);
+        assert_eq!(white.contrast_ratio(black), 21.0);
+        assert_eq!(white.contrast_ratio(white), 1.0);
+        let hex: Hex = "#2BC48A".try_into().unwrap();
+        let rgb: RGB = (255, 125, 55).try_into().unwrap();
+        assert_eq!(hex.contrast_ratio(rgb), rgb.contrast_ratio(hex));
+        // #777777 on white is 4.48, the famous near miss of AA
+        let gray: Hex = "#777777".try_into().unwrap();
+        assert!((gray.contrast_ratio(white) - 4.48).abs() < 0.01);
+        assert!((hex.contrast_ratio(white) - 2.24).abs() < 0.01);
+        assert_eq!(black.relative_luminance(), 0.0);
+        assert_eq!(white.relative_luminance(), 1.0);
+        assert!((Hex::try_from("#808080").unwrap().relative_luminance() - 0.2159).abs() < 1e-4);
+        // the relative luminance is in linear light, unlike the luma of is_dark
+        let red: RGB = (255, 0, 0).try_into().unwrap();
+        assert!((red.relative_luminance() - 0.2126).abs() < 1e-6);
+    }
+
+
+    #[test]
+    fn luminance_works() {
+        let white: Hex = "#FFFFFF".try_into().unwrap();
+        let black: HSL = (0, 0, 0).try_into().unwrap();
+        let gray: Hex = "#808080".try_into().unwrap();
+        assert_eq!(white.luminance(), 1.0);
+        assert_eq!(black.luminance(), 0.0);
+        assert!((gray.luminance() - 0.216).abs() < 1e-3);
+        assert_eq!(white.luma(), 255);
+        assert_eq!(black.luma(), 0);
+        assert_eq!(gray.luma(), 128);
+        let rgb: RGB = (43, 196, 138).try_into().unwrap();
+        assert_eq!(rgb.luma(), 144);
+        assert_eq!(Luminance::luminance(&rgb), rgb.relative_luminance());
+        let hsla: HSLA = rgb.into();
+        assert_eq!(hsla.luma(), RGB::from(hsla).luma());
+    }
+
+
+    #[test]
+    fn is_dark_with_works() {
+        let rgb: RGB = (43, 196, 138).try_into().unwrap();
+        assert_eq!(rgb.is_dark(), rgb.is_dark_with(LumaStandard::Rec601, 192.0));
+        assert!(rgb.is_dark());
+        assert!(!rgb.is_dark_with(LumaStandard::Rec601, 128.0));
+        assert!(!rgb.is_dark_with(LumaStandard::Rec709, 128.0));
+        assert!(rgb.is_dark_with(LumaStandard::Rec709, 160.0));
+        assert!(rgb.is_dark_with(LumaStandard::WcagRelativeLuminance, 0.5));
+        assert!(!rgb.is_dark_with(LumaStandard::WcagRelativeLuminance, 0.4));
+        // pure blue is dark with every standard, pure green is only light with Rec709 and 128
+        let blue: Hex = "#0000FF".try_into().unwrap();
+        let green: Hex = "#00FF00".try_into().unwrap();
+        for standard in [LumaStandard::Rec601, LumaStandard::Rec709] {
+            assert!(blue.is_dark_with(standard, 128.0));
+        }
+        assert!(green.is_dark_with(LumaStandard::Rec601, 192.0));
+        assert!(!green.is_dark_with(LumaStandard::Rec709, 128.0));
+        assert!(!green.is_dark_with(LumaStandard::WcagRelativeLuminance, 0.5));
+        let white: RGB = (255, 255, 255).try_into().unwrap();
+        assert!(!white.is_dark_with(LumaStandard::WcagRelativeLuminance, 1.0));
+        assert!(white.is_dark_with(LumaStandard::WcagRelativeLuminance, 1.1));
+    }
+
+
+    #[test]
+    fn contrast_text_works() {
+        let black: RGB = (0, 0, 0).try_into().unwrap();
+        let white: RGB = (255, 255, 255).try_into().unwrap();
+        let hex: Hex = "#2BC48A".try_into().unwrap();
+        assert_eq!(hex.contrast_text(), black);
+        // is_dark says dark, but black text still has the higher contrast
+        assert!(hex.is_dark());
+        let navy: Hex = "#1E3A8A".try_into().unwrap();
+        assert_eq!(navy.contrast_text(), white);
+        assert_eq!(white.contrast_text(), black);
+        assert_eq!(black.contrast_text(), white);
+        // black and white have the same contrast at the luminance 0.179, between the grays 116 and 118
+        let gray: RGB = (116, 116, 116).try_into().unwrap();
+        assert_eq!(gray.contrast_text(), white);
+        let mid: RGB = (118, 118, 118).try_into().unwrap();
+        assert_eq!(mid.contrast_text(), black);
+        // a tie is black
+        assert_eq!(mid.contrast_text_custom(black, black), black);
+        assert_eq!(mid.contrast_text_custom(mid, mid), mid);
+        let dark: Hex = "#112233".try_into().unwrap();
+        let light: Hex = "#F0F0E0".try_into().unwrap();
+        assert_eq!(hex.contrast_text_custom(dark, light), RGB::from(dark));
+        assert_eq!(navy.contrast_text_custom(dark, light), RGB::from(light));
+        assert_eq!(white.contrast_text_custom(white, white), white);
+    }
+
+
+    #[test]
+    fn ensure_contrast_works() {
+        let white: RGB = (255, 255, 255).try_into().unwrap();
+        let black: RGB = (0, 0, 0).try_into().unwrap();
+        let dark: Hex = "#121212".try_into().unwrap();
+        let fg: Hex = "#2BC48A".try_into().unwrap();
+
+        // already passing, unchanged
+        let result = fg.ensure_contrast(dark, 4.5);
+        assert!(result.achieved());
+        assert_eq!(result.color(), RGB::from(fg));
+        assert_eq!(result.ratio(), fg.contrast_ratio(dark));
+
+        // darkened on white, by the smallest step
+        let result = fg.ensure_contrast(white, 4.5);
+        assert!(result.achieved());
+        assert!(result.ratio() >= 4.5 && result.ratio() < 4.6, "{}", result.ratio());
+        assert!(result.color().relative_luminance() < fg.relative_luminance());
+
+        // lightened on black
+        let navy: Hex = "#1E3A8A".try_into().unwrap();
+        let result = navy.ensure_contrast(black, 7.0);
+        assert!(result.achieved());
+        assert!(result.ratio() >= 7.0 && result.ratio() < 7.1, "{}", result.ratio());
+        assert!(result.color().relative_luminance() > navy.relative_luminance());
+
+        // the other way when white is not enough, white is 3.37 on the gray and black is 6.24
+        let gray: RGB = (140, 140, 140).try_into().unwrap();
+        let light: RGB = (150, 150, 150).try_into().unwrap();
+        let result = light.ensure_contrast(gray, 4.5);
+        assert!(result.achieved());
+        assert!(result.ratio() >= 4.5);
+        assert!(result.color().relative_luminance() < gray.relative_luminance());
+
+        // unreachable, the best of black and white
+        let result = fg.ensure_contrast(gray, 10.0);
+        assert!(!result.achieved());
+        assert_eq!(result.color(), black);
+        assert_eq!(result.ratio(), black.contrast_ratio(gray));
+        assert_eq!(white.ensure_contrast(white, 21.0).color(), black);
+        assert!(white.ensure_contrast(white, 21.0).achieved());
+    }
+
+
+    #[test]
+    fn is_readable_on_works() {
+        assert_eq!(WcagLevel::AA.min_ratio(TextSize::Normal), 4.5);
+        assert_eq!(WcagLevel::AA.min_ratio(TextSize::Large), 3.0);
+        assert_eq!(WcagLevel::AAA.min_ratio(TextSize::Normal), 7.0);
+        assert_eq!(WcagLevel::AAA.min_ratio(TextSize::Large), 4.5);
+        // the minimum itself passes
+        assert!(WcagLevel::AA.is_met_by(4.5, TextSize::Normal));
+        assert!(!WcagLevel::AA.is_met_by(4.499, TextSize::Normal));
+        assert!(WcagLevel::AA.is_met_by(3.0, TextSize::Large));
+        assert!(WcagLevel::AAA.is_met_by(7.0, TextSize::Normal));
+        assert!(!WcagLevel::AAA.is_met_by(6.999, TextSize::Normal));
+        assert!(WcagLevel::AAA.is_met_by(4.5, TextSize::Large));
+
+        let white: Hex = "#FFFFFF".try_into().unwrap();
+        // #777777 is 4.48 and #767676 is 4.54 on white
+        let gray: Hex = "#777777".try_into().unwrap();
+        assert!(!gray.is_readable_on(white, WcagLevel::AA, TextSize::Normal));
+        assert!(gray.is_readable_on(white, WcagLevel::AA, TextSize::Large));
+        assert!(!gray.is_readable_on(white, WcagLevel::AAA, TextSize::Large));
+        let gray: Hex = "#767676".try_into().unwrap();
+        assert!(gray.is_readable_on(white, WcagLevel::AA, TextSize::Normal));
+        assert!(gray.is_readable_on(white, WcagLevel::AAA, TextSize::Large));
+        assert!(!gray.is_readable_on(white, WcagLevel::AAA, TextSize::Normal));
+        let black: RGB = (0, 0, 0).try_into().unwrap();
+        assert!(black.is_readable_on(white, WcagLevel::AAA, TextSize::Normal));
+        assert!(!white.is_readable_on(white, WcagLevel::AA, TextSize::Large));
+
+        // the color of ensure_contrast is readable at the target
+        let fg: Hex = "#2BC48A".try_into().unwrap();
+        let ensured = fg.ensure_contrast(white, WcagLevel::AA.min_ratio(TextSize::Normal));
+        assert!(ensured.color().is_readable_on(white, WcagLevel::AA, TextSize::Normal));
+    }
+
+
+    #[test]
+    fn delta_e2000_works() {
+        // the test data of Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference Formula"
+        type Lab = (f32, f32, f32);
+        let pairs: [(Lab, Lab, f32); 34] = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, 3.1571, -77.2803), (50.0, 0.0, -82.7485), 2.8615),
+            ((50.0, 2.8361, -74.0200), (50.0, 0.0, -82.7485), 3.4412),
+            ((50.0, -1.3802, -84.2814), (50.0, 0.0, -82.7485), 1.0000),
+            ((50.0, -1.1848, -84.8006), (50.0, 0.0, -82.7485), 1.0000),
+            ((50.0, -0.9009, -85.5211), (50.0, 0.0, -82.7485), 1.0000),
+            ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+            ((50.0, -1.0, 2.0), (50.0, 0.0, 0.0), 2.3669),
+            ((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0009), 7.1792),
+            ((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0010), 7.1792),
+            ((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0011), 7.2195),
+            ((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0012), 7.2195),
+            ((50.0, -0.0010, 2.4900), (50.0, 0.0009, -2.4900), 4.8045),
+            ((50.0, -0.0010, 2.4900), (50.0, 0.0010, -2.4900), 4.8045),
+            ((50.0, -0.0010, 2.4900), (50.0, 0.0011, -2.4900), 4.7461),
+            ((50.0, 2.5, 0.0), (50.0, 0.0, -2.5), 4.3065),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            ((50.0, 2.5, 0.0), (61.0, -5.0, 29.0), 22.8977),
+            ((50.0, 2.5, 0.0), (56.0, -27.0, -3.0), 31.9030),
+            ((50.0, 2.5, 0.0), (58.0, 24.0, 15.0), 19.4535),
+            ((50.0, 2.5, 0.0), (50.0, 3.1736, 0.5854), 1.0000),
+            ((50.0, 2.5, 0.0), (50.0, 3.2972, 0.0), 1.0000),
+            ((50.0, 2.5, 0.0), (50.0, 1.8634, 0.5757), 1.0000),
+            ((50.0, 2.5, 0.0), (50.0, 3.2592, 0.3350), 1.0000),
+            ((60.2574, -34.0099, 36.2677), (60.4626, -34.1751, 39.4387), 1.2644),
+            ((63.0109, -31.0961, -5.8663), (62.8187, -29.7946, -4.0864), 1.2630),
+            ((61.2901, 3.7196, -5.3901), (61.4292, 2.2480, -4.9620), 1.8731),
+            ((35.0831, -44.1164, 3.7933), (35.0232, -40.0716, 1.5901), 1.8645),
+            ((22.7233, 20.0904, -46.6940), (23.0331, 14.9730, -42.5619), 2.0373),
+            ((36.4612, 47.8580, 18.3852), (36.2715, 50.5065, 21.2231), 1.4146),
+            ((90.8027, -2.0831, 1.4410), (91.1528, -1.6435, 0.0447), 1.4441),
+            ((90.9257, -0.5406, -0.9208), (88.6381, -0.8985, -0.7239), 1.5381),
+            ((6.7747, -0.2908, -2.4247), (5.8714, -0.0985, -2.2286), 0.6377),
+            ((2.0776, 0.0795, -1.1350), (0.9033, -0.0636, -0.5514), 0.9082),
+        ];
+        for (idx, (lab1, lab2, expected)) in pairs.into_iter().enumerate() {
+            let de = crate::common::delta_e2000(lab1, lab2);
+            assert!((de - expected).abs() < 1e-4, "pair {}: {} != {}", idx + 1, de, expected);
+            // symmetric
+            let de = crate::common::delta_e2000(lab2, lab1);
+            assert!((de - expected).abs() < 1e-4, "pair {} swapped: {} != {}", idx + 1, de, expected);
+        }
+
+        let a: Hex = "#2BC48A".try_into().unwrap();
+        let b: RGB = (255, 125, 55).try_into().unwrap();
+        assert_eq!(a.delta_e2000(a), 0.0);
+        assert_eq!(a.delta_e2000(b), b.delta_e2000(a));
+        let black: RGB = (0, 0, 0).try_into().unwrap();
+        let white: RGB = (255, 255, 255).try_into().unwrap();
+        assert!((black.delta_e2000(white) - 100.0).abs() < 0.01);
+    }
+
+
+    #[test]
+    fn nearest_in_works() {
+        let palette: Vec<Hex> = ["#000000", "#FF0000", "#2BC48A", "#1E3A8A", "#FFFFFF"]
+            .iter()
+            .map(|s| Hex::try_from(*s).unwrap())
+            .collect();
+        let hsl: HSL = (157, 60, 40).try_into().unwrap();
+        for metric in [DistanceMetric::Redmean, DistanceMetric::WeightedRgb, DistanceMetric::DeltaE2000] {
+            let (idx, color, distance) = hsl.nearest_in(&palette, metric).unwrap();
+            assert_eq!(idx, 2);
+            assert_eq!(color, RGB::from(palette[2]));
+            assert_eq!(distance, metric.distance(hsl.into(), color));
+            // an exact match has no distance
+            let (idx, _, distance) = palette[3].nearest_in(&palette, metric).unwrap();
+            assert_eq!((idx, distance), (3, 0.0));
+        }
+        let empty: [Hex; 0] = [];
+        assert!(hsl.nearest_in(&empty, DistanceMetric::Redmean).is_none());
+        // the first color wins a tie
+        let twice = [palette[1], palette[1]];
+        assert_eq!(hsl.nearest_in(&twice, DistanceMetric::WeightedRgb).unwrap().0, 0);
+
+        let matcher = PaletteMatcher::new(&palette, DistanceMetric::DeltaE2000);
+        assert_eq!(matcher.colors().len(), 5);
+        assert_eq!(matcher.metric(), DistanceMetric::DeltaE2000);
+        let dark_red: RGB = (120, 10, 10).try_into().unwrap();
+        assert_eq!(matcher.nearest(dark_red), dark_red.nearest_in(&palette, DistanceMetric::DeltaE2000));
+        let navy: RGB = (20, 30, 100).try_into().unwrap();
+        assert_eq!(matcher.nearest(navy).unwrap().0, 3);
+    }
+
+
+    #[test]
+    fn distance_works() {
+        let black: RGB = (0, 0, 0).try_into().unwrap();
+        let white: Hex = "#FFFFFF".try_into().unwrap();
+        let a: Hex = "#2BC48A".try_into().unwrap();
+        let b: HSL = (22, 100, 61).try_into().unwrap();
+        assert_eq!(a.distance(a), 0.0);
+        assert_eq!(a.distance_redmean(a), 0.0);
+        assert_eq!(a.distance(b), b.distance(a));
+        assert_eq!(a.distance_redmean(b), b.distance_redmean(a));
+        assert!((black.distance(white) - 441.67).abs() < 0.01);
+        assert!((black.distance_redmean(white) - 764.83).abs() < 0.01);
+        let rgb: RGB = (1, 2, 2).try_into().unwrap();
+        assert_eq!(black.distance(rgb), 3.0);
+        // the redmean weights green the most
+        let red: RGB = (10, 0, 0).try_into().unwrap();
+        let green: RGB = (0, 10, 0).try_into().unwrap();
+        assert_eq!(black.distance(red), black.distance(green));
+        assert!(black.distance_redmean(red) < black.distance_redmean(green));
+        assert_eq!(DistanceMetric::Redmean.distance(black, red), black.distance_redmean(red));
+    }
+
+
+    #[test]
+    fn approx_eq_works() {
+        let rgb: RGB = (43, 196, 138).try_into().unwrap();
+        assert!(rgb.approx_eq(rgb, 0));
+        let near: RGB = (45, 194, 139).try_into().unwrap();
+        assert!(rgb.approx_eq(near, 2));
+        assert!(!rgb.approx_eq(near, 1));
+        assert!(near.approx_eq(rgb, 2));
+        let hex: Hex = "#2DC28B".try_into().unwrap();
+        assert!(rgb.approx_eq(hex, 2));
+
+        let rgba: RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+        let other: RGBA = (44, 196, 138, 0.52).try_into().unwrap();
+        assert!(rgba.approx_eq(other, 1, 0.02 + f32::EPSILON));
+        assert!(!rgba.approx_eq(other, 0, 0.1));
+        assert!(!rgba.approx_eq(other, 1, 0.01));
+
+        // the hue wraps around 0/360
+        let hsl: HSL = (358, 64, 47).try_into().unwrap();
+        assert!(hsl.approx_eq(HSL::try_from((2, 64, 47)).unwrap(), 4));
+        assert!(!hsl.approx_eq(HSL::try_from((2, 64, 47)).unwrap(), 3));
+        assert!(hsl.approx_eq(HSL::try_from((0, 64, 47)).unwrap(), 2));
+        assert!(HSL::try_from((360, 64, 47)).unwrap().approx_eq(HSL::try_from((0, 64, 47)).unwrap(), 0));
+        assert!(!hsl.approx_eq(HSL::try_from((178, 64, 47)).unwrap(), 179));
+        assert!(!hsl.approx_eq(HSL::try_from((358, 60, 47)).unwrap(), 3));
+        let hsv: HSV = (1, 50, 50).try_into().unwrap();
+        assert!(hsv.approx_eq(HSV::try_from((359, 51, 49)).unwrap(), 2));
+        assert!(!hsv.approx_eq(HSV::try_from((359, 51, 49)).unwrap(), 1));
+
+        // round trips
+        let hsl: HSL = (157, 64, 47).try_into().unwrap();
+        let round_trip: HSL = RGB::from(hsl).into();
+        assert!(hsl.approx_eq(round_trip, 1));
+        let hsv: HSV = rgb.into();
+        assert!(rgb.approx_eq(RGB::from(hsv), 2));
+    }
+
+
+    #[test]
+    fn simulate_works() {
+        let simulate = |s: &str, deficiency| Hex::try_from(s).unwrap().simulate(deficiency).to_string();
+        let cases = [
+            ("#FF0000", ["#6D5F00", "#A39000", "#FF000F", "#7F7F7F"]),
+            ("#00FF00", ["#FFE500", "#EFD63A", "#00F7D9", "#DCDCDC"]),
+            ("#0000FF", ["#0059FF", "#003DFB", "#006B96", "#4C4C4C"]),
+            ("#2BC48A", ["#C1B587", "#AFA88E", "#00C4B4", "#ADADAD"]),
+            ("#FF7D37", ["#A2902D", "#C1AD34", "#FF606F", "#A2A2A2"]),
+            ("#FFFFFF", ["#FFFFFF", "#FFFFFF", "#FFFFFF", "#FFFFFF"]),
+        ];
+        let deficiencies = [
+            ColorBlindness::Protanopia,
+            ColorBlindness::Deuteranopia,
+            ColorBlindness::Tritanopia,
+            ColorBlindness::Achromatopsia,
+        ];
+        for (color, expected) in cases {
+            for (deficiency, expected) in deficiencies.into_iter().zip(expected) {
+                assert_eq!(simulate(color, deficiency), expected, "{} {:?}", color, deficiency);
+            }
+        }
+        // the severity moves from the normal vision to the dichromacy
+        assert_eq!(simulate("#2BC48A", ColorBlindness::Deuteranomaly(0.0)), "#2BC48A");
+        assert_eq!(simulate("#2BC48A", ColorBlindness::Deuteranomaly(0.5)), "#82B78C");
+        assert_eq!(simulate("#2BC48A", ColorBlindness::Deuteranomaly(1.0)), "#AFA88E");
+        assert_eq!(simulate("#2BC48A", ColorBlindness::Protanomaly(2.0)), "#C1B587");
+        assert_eq!(simulate("#2BC48A", ColorBlindness::Tritanomaly(f32::NAN)), "#2BC48A");
+        // the alpha is kept
+        let rgba: RGBA = (255, 0, 0, 0.3).try_into().unwrap();
+        assert_eq!(rgba.simulate(ColorBlindness::Protanopia).to_string(), "rgba(109,95,0,0.30)");
+    }
+
+
+    #[test]
+    fn sepia_works() {
+        let hex: Hex = "#2BC48A".try_into().unwrap();
+        assert_eq!(hex.sepia(0.0), hex);
+        assert_eq!(hex.sepia(-1.0), hex);
+        assert_eq!(hex.sepia(f32::NAN), hex);
+        assert_eq!(hex.sepia(1.0).to_string(), "#C2AD86");
+        assert_eq!(hex.sepia(2.0), hex.sepia(1.0));
+        assert_eq!(hex.sepia(0.6).to_string(), "#85B688");
+        // the CSS sepia(1) of red, green and blue
+        let red: RGB = (255, 0, 0).try_into().unwrap();
+        assert_eq!(red.sepia(1.0).to_string(), "rgb(100,89,69)");
+        let green: RGB = (0, 255, 0).try_into().unwrap();
+        assert_eq!(green.sepia(1.0).to_string(), "rgb(196,175,136)");
+        let blue: RGB = (0, 0, 255).try_into().unwrap();
+        assert_eq!(blue.sepia(1.0).to_string(), "rgb(48,43,33)");
+        let white: RGB = (255, 255, 255).try_into().unwrap();
+        assert_eq!(white.sepia(1.0).to_string(), "rgb(255,255,239)");
+        let hsla: HSLA = (157, 64, 47, 0.4).try_into().unwrap();
+        assert_eq!(hsla.sepia(1.0).alpha(), 0.4);
+    }
 }
@@ -0,0 +1,128 @@
+use crate::common::rgb_to_linear_srgb;
+use crate::{ColorError, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+
+/// LinearRGB is sRGB without the gamma encoding, so the channels are proportional to light intensity,
+/// which makes mixing, scaling and averaging colors physically correct.
+/// It can be created from a tuple (r,g,b) or converted from RGB and RGBA.
+/// * r:f32 - red value(0~1)
+/// * g:f32 - green value(0~1)
+/// * b:f32 - blue value(0~1)
+///
+/// The conversion uses the exact sRGB transfer function, and converting back to RGB rounds to the nearest value,
+/// so every RGB color round trips losslessly.
+/// ### example
+/// ```rust
+/// use easy_color::{LinearRGB, RGB};
+/// let rgb:RGB = (128,128,128).try_into().unwrap();
+/// let linear:LinearRGB = rgb.into();
+/// assert_eq!(linear.to_string(), "color(srgb-linear 0.2159 0.2159 0.2159)");
+/// assert_eq!(RGB::from(linear), rgb);
+///
+/// let black:LinearRGB = (0.0, 0.0, 0.0).try_into().unwrap();
+/// let white:LinearRGB = (1.0, 1.0, 1.0).try_into().unwrap();
+/// let middle:RGB = black.lerp(white, 0.5).into();
+/// assert_eq!(middle.to_string(), "rgb(188,188,188)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct LinearRGB {
+    pub(crate) r: f32,
+    pub(crate) g: f32,
+    pub(crate) b: f32,
+}
+
+impl TryFrom<(f32, f32, f32)> for LinearRGB {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(0.0..=1.0).contains(&value.1)
+            || !(0.0..=1.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "LinearRGB: args ({},{},{}) value error, all value must between 0~1!",
+                    value.0, value.1, value.2
+                ),
+            })
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<RGB> for LinearRGB {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (r, g, b) = rgb_to_linear_srgb(r, g, b);
+        Self { r, g, b }
+    }
+}
+
+impl From<RGBA> for LinearRGB {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for LinearRGB {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "color(srgb-linear {:.4} {:.4} {:.4})",
+            self.r, self.g, self.b
+        )
+    }
+}
+
+impl LinearRGB {
+    pub fn red(&self) -> f32 {
+        self.r
+    }
+
+    pub fn green(&self) -> f32 {
+        self.g
+    }
+
+    pub fn blue(&self) -> f32 {
+        self.b
+    }
+
+    /// Linear interpolation between two colors in linear light.
+    /// * other - any struct that impl into LinearRGB
+    /// * t:f32 - 0 returns self, 1 returns other, the value is clamped between 0~1
+    pub fn lerp(&self, other: impl Into<Self>, t: f32) -> Self {
+        let other: LinearRGB = other.into();
+        let t = t.clamp(0.0, 1.0);
+        Self {
+            r: self.r + (other.r - self.r) * t,
+            g: self.g + (other.g - self.g) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+
+    /// Multiply every channel by the factor, like changing the exposure of the light.
+    /// The result is clamped between 0~1.
+    /// ```rust
+    /// use easy_color::LinearRGB;
+    /// let linear:LinearRGB = (0.2, 0.4, 0.8).try_into().unwrap();
+    /// assert_eq!(linear.scale(2.0).to_string(), "color(srgb-linear 0.4000 0.8000 1.0000)");
+    /// ```
+    pub fn scale(&self, factor: f32) -> Self {
+        Self {
+            r: (self.r * factor).clamp(0.0, 1.0),
+            g: (self.g * factor).clamp(0.0, 1.0),
+            b: (self.b * factor).clamp(0.0, 1.0),
+        }
+    }
+
+    /// Returns the relative luminance(0~1) of the color, using the Rec.709 coefficients.
+    pub fn luminance(&self) -> f32 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+}
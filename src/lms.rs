@@ -0,0 +1,179 @@
+use crate::common::{multiply_matrix, rgb_to_xyz};
+use crate::{ColorError, Hex, WhitePoint, RGB, RGBA, XYZ};
+use std::fmt::{Display, Formatter};
+
+/// The matrix used to convert CIE XYZ into the LMS cone responses.
+/// * HuntPointerEstevez - the Hunt-Pointer-Estevez matrix normalized to D65, it models the physiological cones
+///   and is used by color vision deficiency simulations, it is the default one
+/// * Cat02 - the sharpened matrix of CIECAM02, it is better suited for chromatic adaptation
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum ConeMatrix {
+    #[default]
+    HuntPointerEstevez,
+    Cat02,
+}
+
+impl ConeMatrix {
+    /// Returns the matrix converting CIE XYZ into LMS.
+    pub fn to_lms_matrix(&self) -> [[f32; 3]; 3] {
+        match self {
+            ConeMatrix::HuntPointerEstevez => [
+                [0.400_24, 0.707_6, -0.080_81],
+                [-0.226_3, 1.165_32, 0.045_7],
+                [0.0, 0.0, 0.918_22],
+            ],
+            ConeMatrix::Cat02 => [
+                [0.732_8, 0.429_6, -0.162_4],
+                [-0.703_6, 1.697_5, 0.006_1],
+                [0.003, 0.013_6, 0.983_4],
+            ],
+        }
+    }
+
+    /// Returns the matrix converting LMS into CIE XYZ.
+    pub fn from_lms_matrix(&self) -> [[f32; 3]; 3] {
+        match self {
+            ConeMatrix::HuntPointerEstevez => [
+                [1.859_936_4, -1.129_381_6, 0.219_897_4],
+                [0.361_191_44, 0.638_812_5, -0.000_006_370_597],
+                [0.0, 0.0, 1.089_063_6],
+            ],
+            ConeMatrix::Cat02 => [
+                [1.096_123_8, -0.278_869, 0.182_745_18],
+                [0.454_369_04, 0.473_533_15, 0.072_097_8],
+                [-0.009_627_609, -0.005_698_031, 1.015_325_6],
+            ],
+        }
+    }
+}
+
+/// LMS is the response of the long, medium and short wavelength cones of the eye.
+/// It can be created from a tuple (l,m,s), which uses the Hunt-Pointer-Estevez matrix, or converted from RGB, RGBA, Hex and XYZ.
+/// * l:f32 - long wavelength cone response
+/// * m:f32 - medium wavelength cone response
+/// * s:f32 - short wavelength cone response
+///
+/// The built-in conversions use `ConeMatrix::HuntPointerEstevez` on CIE XYZ(D65) where Y of white is 1.0,
+/// `from_rgb` and `to_matrix` use any other `ConeMatrix`, and the conversion back always honors the current matrix.
+/// Combined with `ConeMatrix::to_lms_matrix` and `from_lms_matrix`, it can be used to implement a von Kries style chromatic adaptation.
+/// ### example
+/// ```rust
+/// use easy_color::{ConeMatrix, LMS, RGB};
+/// let rgb:RGB = (255,255,255).try_into().unwrap();
+/// let lms:LMS = rgb.into();
+/// assert_eq!(lms.to_string(), "lms(1.0000, 1.0000, 1.0000)");
+///
+/// let rgb:RGB = (255,128,0).try_into().unwrap();
+/// let lms = LMS::from_rgb(rgb, ConeMatrix::Cat02);
+/// assert_eq!(lms.matrix(), ConeMatrix::Cat02);
+/// let rgb:RGB = lms.into();
+/// assert_eq!(rgb.to_string(), "rgb(255,128,0)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct LMS {
+    pub(crate) l: f32,
+    pub(crate) m: f32,
+    pub(crate) s: f32,
+    pub(crate) matrix: ConeMatrix,
+}
+
+impl TryFrom<(f32, f32, f32)> for LMS {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !value.0.is_finite() || !value.1.is_finite() || !value.2.is_finite() {
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "LMS: args ({},{},{}) value error, all value must be finite!",
+                    value.0, value.1, value.2
+                ),
+            })
+        } else {
+            Ok(Self {
+                l: value.0,
+                m: value.1,
+                s: value.2,
+                matrix: ConeMatrix::HuntPointerEstevez,
+            })
+        }
+    }
+}
+
+impl From<Hex> for LMS {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for LMS {
+    fn from(rgb: RGB) -> Self {
+        Self::from_rgb(rgb, ConeMatrix::HuntPointerEstevez)
+    }
+}
+
+impl From<RGBA> for LMS {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<XYZ> for LMS {
+    fn from(xyz: XYZ) -> Self {
+        let XYZ { x, y, z, .. } = xyz.to_white_point(WhitePoint::D65);
+        Self::from_xyz(
+            (x / 100.0, y / 100.0, z / 100.0),
+            ConeMatrix::HuntPointerEstevez,
+        )
+    }
+}
+
+impl Display for LMS {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "lms({:.4}, {:.4}, {:.4})", self.l, self.m, self.s)
+    }
+}
+
+impl LMS {
+    /// Convert a color into LMS with the given cone matrix.
+    pub fn from_rgb(rgb: impl Into<RGB>, matrix: ConeMatrix) -> Self {
+        let RGB { r, g, b } = rgb.into();
+        Self::from_xyz(rgb_to_xyz(r, g, b), matrix)
+    }
+
+    /// Create from CIE XYZ(D65) where Y of white is 1.0
+    fn from_xyz(xyz: (f32, f32, f32), matrix: ConeMatrix) -> Self {
+        let (l, m, s) = multiply_matrix(&matrix.to_lms_matrix(), xyz);
+        Self { l, m, s, matrix }
+    }
+
+    /// Returns CIE XYZ(D65) of the color, Y of white is 1.0
+    pub(crate) fn to_xyz_unit(self) -> (f32, f32, f32) {
+        multiply_matrix(&self.matrix.from_lms_matrix(), (self.l, self.m, self.s))
+    }
+
+    /// Returns the same color with the cone responses of another matrix.
+    pub fn to_matrix(&self, matrix: ConeMatrix) -> Self {
+        if matrix == self.matrix {
+            return *self;
+        }
+        Self::from_xyz(self.to_xyz_unit(), matrix)
+    }
+
+    pub fn l(&self) -> f32 {
+        self.l
+    }
+
+    pub fn m(&self) -> f32 {
+        self.m
+    }
+
+    pub fn s(&self) -> f32 {
+        self.s
+    }
+
+    pub fn matrix(&self) -> ConeMatrix {
+        self.matrix
+    }
+}
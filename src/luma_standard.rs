@@ -0,0 +1,34 @@
+use crate::RGB;
+
+/// The weighting of the channels for `is_dark_with`, the threshold is on the scale of the standard.
+/// * Rec601 - 0.299 * r + 0.587 * g + 0.114 * b of the gamma encoded channels(0~255), what `is_dark` uses
+/// * Rec709 - 0.2126 * r + 0.7152 * g + 0.0722 * b of the gamma encoded channels(0~255)
+/// * WcagRelativeLuminance - the relative luminance(0~1) of WCAG, the Rec.709 weights in linear light
+/// ### example
+/// ```rust
+/// use easy_color::{LumaStandard, RGB};
+/// let rgb:RGB = (43,196,138).try_into().unwrap();
+/// assert!(rgb.is_dark());
+/// assert!(!rgb.is_dark_with(LumaStandard::Rec601, 128.0));
+/// assert!(rgb.is_dark_with(LumaStandard::WcagRelativeLuminance, 0.5));
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum LumaStandard {
+    #[default]
+    Rec601,
+    Rec709,
+    WcagRelativeLuminance,
+}
+
+impl LumaStandard {
+    /// Returns the weighted value of the color on the scale of the standard
+    pub fn luma_of(&self, rgb: RGB) -> f32 {
+        let RGB { r, g, b } = rgb;
+        let (r, g, b) = (r as f32, g as f32, b as f32);
+        match self {
+            LumaStandard::Rec601 => r * 0.299 + g * 0.587 + b * 0.114,
+            LumaStandard::Rec709 => r * 0.2126 + g * 0.7152 + b * 0.0722,
+            LumaStandard::WcagRelativeLuminance => rgb.relative_luminance(),
+        }
+    }
+}
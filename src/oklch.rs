@@ -0,0 +1,250 @@
+use crate::common::{ab_to_chroma_hue, format_alpha, rgb_to_oklab, OKLAB_ACHROMATIC_CHROMA};
+use crate::{ColorError, Hex, CMYK, HSL, HSLA, HSV, HWB, LCH, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// OkLch is the cylindrical form of Oklab, the same space used by the CSS `oklch()` function.
+/// It can be parsed from a string in the format "oklch(l c h)" or "oklch(l c h / a)", or from a tuple (l,c,h) or (l,c,h,a).
+/// * l:f32 - lightness(0~1), in strings it can also be a percentage like `62.8%`
+/// * c:f32 - chroma(0~), usually not bigger than 0.4, in strings `100%` means 0.4
+/// * h:f32 - hue(0~360)
+/// * a:f32 - alpha(0~1), optional, default is 1
+///
+/// Achromatic colors, whose chroma is close to 0, always have a hue of 0.
+/// When the color is out of the sRGB gamut, its chroma is reduced, keeping lightness and hue, until it fits when converted to RGB.
+/// ### example
+/// ```rust
+/// use easy_color::{IntoRGB, OkLch, RGB};
+/// let oklch:OkLch = "oklch(62.8% 0.258 29.23)".try_into().unwrap();
+/// assert_eq!(oklch.to_rgb().to_string(), "rgb(255,0,0)");
+///
+/// let oklch:OkLch = "oklch(70% 0.1 200 / 0.5)".try_into().unwrap();
+/// assert_eq!(oklch.to_string(), "oklch(70.0% 0.100 200.00 / 0.50)");
+///
+/// let rgb:RGB = (43,196,138).try_into().unwrap();
+/// let oklch:OkLch = rgb.into();
+/// assert_eq!(oklch.to_string(), "oklch(73.0% 0.150 161.90)");
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct OkLch {
+    pub(crate) l: f32,
+    pub(crate) c: f32,
+    pub(crate) h: f32,
+    pub(crate) a: f32,
+}
+
+impl Default for OkLch {
+    fn default() -> Self {
+        Self {
+            l: 0.0,
+            c: 0.0,
+            h: 0.0,
+            a: 1.0,
+        }
+    }
+}
+
+impl TryFrom<&str> for OkLch {
+    type Error = ColorError;
+    fn try_from(oklch_str: &str) -> Result<Self, Self::Error> {
+        let mut color = oklch_str.trim().to_lowercase();
+        if color.starts_with("oklch(") && color.ends_with(')') {
+            color = color.replace("oklch(", "").replace(')', "");
+            let (channels, alpha) = match color.split_once('/') {
+                Some((channels, alpha)) => (channels, Some(alpha.trim())),
+                None => (color.as_str(), None),
+            };
+            let tmp = channels
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>();
+            let alpha = match alpha {
+                Some(s) => match s.strip_suffix('%') {
+                    Some(s) => s.parse::<f32>().ok().map(|v| v / 100.0),
+                    None => s.parse::<f32>().ok(),
+                },
+                None => Some(1.0),
+            };
+            if let (3, Some(alpha)) = (tmp.len(), alpha) {
+                let l = match tmp[0].strip_suffix('%') {
+                    Some(s) => s.parse::<f32>().ok().map(|v| v / 100.0),
+                    None => tmp[0].parse::<f32>().ok(),
+                };
+                let c = match tmp[1].strip_suffix('%') {
+                    Some(s) => s.parse::<f32>().ok().map(|v| v / 100.0 * 0.4),
+                    None => tmp[1].parse::<f32>().ok(),
+                };
+                let h = tmp[2].trim_end_matches("deg").parse::<f32>().ok();
+                if let (Some(l), Some(c), Some(h)) = (l, c, h) {
+                    return (l, c, h, alpha).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr {
+            input: oklch_str.to_string(),
+            reason: format!("OkLch: {} format error!", oklch_str),
+        })
+    }
+}
+
+impl FromStr for OkLch {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for OkLch {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        (value.0, value.1, value.2, 1.0).try_into()
+    }
+}
+
+impl TryFrom<(f32, f32, f32, f32)> for OkLch {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(0.0..=f32::MAX).contains(&value.1)
+            || !(0.0..=360.0).contains(&value.2)
+            || !(0.0..=1.0).contains(&value.3)
+        {
+            Err(ColorError::ValueErr { input: format!("{:?}", value), reason: format!("OkLch: args ({},{},{},{}) value error, lightness must between 0~1, chroma must not be negative, hue must between 0~360, and alpha must between 0~1!", value.0, value.1, value.2, value.3) })
+        } else {
+            Ok(Self {
+                l: value.0,
+                c: value.1,
+                h: value.2 % 360.0,
+                a: value.3,
+            })
+        }
+    }
+}
+
+impl From<Hex> for OkLch {
+    fn from(hex: Hex) -> Self {
+        let rgba: RGBA = hex.into();
+        rgba.into()
+    }
+}
+
+impl From<RGB> for OkLch {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (l, a, b) = rgb_to_oklab(r, g, b);
+        let (c, h) = ab_to_chroma_hue(a, b, OKLAB_ACHROMATIC_CHROMA);
+        Self { l, c, h, a: 1.0 }
+    }
+}
+
+impl From<RGBA> for OkLch {
+    fn from(rgba: RGBA) -> Self {
+        let RGBA { rgb, a } = rgba;
+        let oklch: OkLch = rgb.into();
+        Self { a, ..oklch }
+    }
+}
+
+impl From<HSL> for OkLch {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for OkLch {
+    fn from(hsla: HSLA) -> Self {
+        let rgba: RGBA = hsla.into();
+        rgba.into()
+    }
+}
+
+impl From<HSV> for OkLch {
+    fn from(hsv: HSV) -> Self {
+        let rgb: RGB = hsv.into();
+        rgb.into()
+    }
+}
+
+impl From<HWB> for OkLch {
+    fn from(hwb: HWB) -> Self {
+        let rgb: RGB = hwb.into();
+        rgb.into()
+    }
+}
+
+impl From<LCH> for OkLch {
+    fn from(lch: LCH) -> Self {
+        let rgb: RGB = lch.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYK> for OkLch {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl Display for OkLch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.a != 1.0 {
+            write!(
+                f,
+                "oklch({:.1}% {:.3} {:.2} / {})",
+                self.l * 100.0,
+                self.c,
+                self.h,
+                format_alpha(f, self.a)
+            )
+        } else {
+            write!(
+                f,
+                "oklch({:.1}% {:.3} {:.2})",
+                self.l * 100.0,
+                self.c,
+                self.h
+            )
+        }
+    }
+}
+
+impl OkLch {
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn chroma(&self) -> f32 {
+        self.c
+    }
+
+    pub fn set_chroma(&mut self, chroma: f32) -> &mut Self {
+        self.c = chroma.max(0.0);
+        self
+    }
+
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    /// Set the hue, the value wraps around 360, so 400 becomes 40 and -30 becomes 330.
+    pub fn set_hue(&mut self, hue: f32) -> &mut Self {
+        self.h = hue.rem_euclid(360.0);
+        self
+    }
+
+    pub fn alpha(&self) -> f32 {
+        self.a
+    }
+
+    pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
+        self.a = alpha.clamp(0.0, 1.0);
+        self
+    }
+}
@@ -0,0 +1,145 @@
+use crate::common::{
+    is_in_unit_range, linear_to_srgb, multiply_matrix, parse_color_space, rgb_to_linear_srgb,
+    srgb_to_linear, P3_TO_XYZ, SRGB_TO_XYZ, XYZ_TO_P3, XYZ_TO_SRGB,
+};
+use crate::{ColorError, Hex, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// P3 is the Display P3 color space used by Apple displays and design tools like Figma, its gamut is wider than sRGB.
+/// It can be parsed from a string in the CSS format "color(display-p3 r g b)" or from a tuple (r,g,b).
+/// * r:f32 - red value(0~1)
+/// * g:f32 - green value(0~1)
+/// * b:f32 - blue value(0~1)
+///
+/// Display P3 uses the same transfer curve and white point(D65) as sRGB, with wider primaries.
+/// Colors out of the sRGB gamut are clamped when converted to RGB, use `is_in_srgb_gamut` to check it first.
+/// ### example
+/// ```rust
+/// use easy_color::{Hex, P3, RGB};
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// let p3:P3 = rgb.into();
+/// assert_eq!(p3.to_string(), "color(display-p3 0.9175 0.2003 0.1386)");
+///
+/// let p3:P3 = "color(display-p3 0.17 0.77 0.54)".try_into().unwrap();
+/// assert!(!p3.is_in_srgb_gamut());
+/// let hex:Hex = p3.into();
+/// assert_eq!(hex.to_string(), "#00C885");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct P3 {
+    pub(crate) r: f32,
+    pub(crate) g: f32,
+    pub(crate) b: f32,
+}
+
+impl TryFrom<&str> for P3 {
+    type Error = ColorError;
+    fn try_from(p3_str: &str) -> Result<Self, Self::Error> {
+        parse_color_space(p3_str, "display-p3", "P3")?.try_into()
+    }
+}
+
+impl FromStr for P3 {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for P3 {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(0.0..=1.0).contains(&value.1)
+            || !(0.0..=1.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "P3: args ({},{},{}) value error, all value must between 0~1!",
+                    value.0, value.1, value.2
+                ),
+            })
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for P3 {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for P3 {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let xyz = multiply_matrix(&SRGB_TO_XYZ, rgb_to_linear_srgb(r, g, b));
+        let (r, g, b) = multiply_matrix(&XYZ_TO_P3, xyz);
+        Self {
+            r: linear_to_srgb(r.max(0.0)).min(1.0),
+            g: linear_to_srgb(g.max(0.0)).min(1.0),
+            b: linear_to_srgb(b.max(0.0)).min(1.0),
+        }
+    }
+}
+
+impl From<RGBA> for P3 {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for P3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "color(display-p3 {:.4} {:.4} {:.4})",
+            self.r, self.g, self.b
+        )
+    }
+}
+
+impl P3 {
+    pub fn red(&self) -> f32 {
+        self.r
+    }
+
+    pub fn green(&self) -> f32 {
+        self.g
+    }
+
+    pub fn blue(&self) -> f32 {
+        self.b
+    }
+
+    /// Returns the linear light sRGB channels of the color, they are out of 0~1 when the color is out of the sRGB gamut.
+    pub(crate) fn to_linear_srgb(self) -> (f32, f32, f32) {
+        let lin = (
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+        );
+        multiply_matrix(&XYZ_TO_SRGB, multiply_matrix(&P3_TO_XYZ, lin))
+    }
+
+    /// Check whether the color can be represented in sRGB without clamping.
+    /// ```rust
+    /// use easy_color::P3;
+    /// let p3:P3 = "color(display-p3 0.5 0.5 0.5)".try_into().unwrap();
+    /// assert!(p3.is_in_srgb_gamut());
+    /// let p3:P3 = "color(display-p3 0 1 0)".try_into().unwrap();
+    /// assert!(!p3.is_in_srgb_gamut());
+    /// ```
+    pub fn is_in_srgb_gamut(&self) -> bool {
+        is_in_unit_range(self.to_linear_srgb())
+    }
+}
@@ -0,0 +1,385 @@
+use crate::common::{parse_err, unit_to_u8};
+use crate::{AnyColor, ColorError, CMYK, RGB};
+use std::fmt::Write;
+
+/// A color of a palette with its optional name.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Swatch {
+    pub(crate) color: AnyColor,
+    pub(crate) name: Option<String>,
+}
+
+impl Swatch {
+    pub fn color(&self) -> AnyColor {
+        self.color
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+/// A color of a palette file that was skipped, since its color space is not supported, like Lab or a spot color.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SkippedSwatch {
+    pub(crate) name: Option<String>,
+    pub(crate) color_space: String,
+}
+
+impl SkippedSwatch {
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The color space of the file, like "lab" or "spot"
+    pub fn color_space(&self) -> &str {
+        &self.color_space
+    }
+}
+
+/// Reads the big endian values of a binary palette, running out of data is an error instead of a panic.
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    format: &'static str,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8], format: &'static str) -> Self {
+        Self {
+            data,
+            pos: 0,
+            format,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], ColorError> {
+        let bytes = self
+            .pos
+            .checked_add(len)
+            .and_then(|end| self.data.get(self.pos..end))
+            .ok_or_else(|| ColorError::FormatErr {
+                input: self.format.to_string(),
+                reason: format!(
+                    "Palette: the {} data is truncated at byte {}!",
+                    self.format, self.pos
+                ),
+            })?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16, ColorError> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, ColorError> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn f32(&mut self) -> Result<f32, ColorError> {
+        self.u32().map(f32::from_bits)
+    }
+
+    /// Reads a UTF-16 string of `len` code units, the terminating 0 is dropped
+    fn utf16(&mut self, len: usize) -> Result<Option<String>, ColorError> {
+        let units = (0..len)
+            .map(|_| self.u16())
+            .collect::<Result<Vec<_>, _>>()?;
+        let name = String::from_utf16_lossy(&units);
+        let name = name.trim_end_matches('\0');
+        Ok((!name.is_empty()).then(|| name.to_string()))
+    }
+}
+
+/// Palette is a list of named colors, which can be read from and written to palette files.
+/// * .gpl - the text format of GIMP, Inkscape and Aseprite, see `from_gpl` and `to_gpl`
+/// * .aco - the color swatches of Photoshop, see `from_aco`
+/// * .ase - the swatch exchange format of Adobe, see `from_ase`
+///
+/// Colors of the binary formats in an unsupported color space are kept in `skipped`.
+/// ### example
+/// ```rust
+/// use easy_color::{Palette, RGB};
+/// let gpl = "GIMP Palette\nName: Brand\nColumns: 2\n#\n 43 196 138\tGreen\n255 125  55\tOrange\n";
+/// let palette = Palette::from_gpl(gpl).unwrap();
+/// assert_eq!(palette.name(), Some("Brand"));
+/// assert_eq!(palette.swatches()[1].name(), Some("Orange"));
+/// let colors: Vec<RGB> = palette.to_rgb_vec();
+/// assert_eq!(colors[0].to_string(), "rgb(43,196,138)");
+/// assert_eq!(palette.to_gpl(), gpl);
+/// ```
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Palette {
+    pub(crate) name: Option<String>,
+    pub(crate) columns: Option<u32>,
+    pub(crate) swatches: Vec<Swatch>,
+    pub(crate) skipped: Vec<SkippedSwatch>,
+}
+
+impl Palette {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn set_name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// The number of columns a palette editor should show, if the file has it
+    pub fn columns(&self) -> Option<u32> {
+        self.columns
+    }
+
+    pub fn set_columns(&mut self, columns: u32) -> &mut Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    pub fn swatches(&self) -> &[Swatch] {
+        &self.swatches
+    }
+
+    /// The colors of the file that were skipped, since their color space is not supported
+    pub fn skipped(&self) -> &[SkippedSwatch] {
+        &self.skipped
+    }
+
+    pub fn len(&self) -> usize {
+        self.swatches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.swatches.is_empty()
+    }
+
+    /// Append a color with an optional name
+    pub fn push(&mut self, color: AnyColor, name: Option<&str>) -> &mut Self {
+        self.swatches.push(Swatch {
+            color,
+            name: name.map(|name| name.to_string()),
+        });
+        self
+    }
+
+    /// Returns every color converted into RGB
+    pub fn to_rgb_vec(&self) -> Vec<RGB> {
+        self.swatches.iter().map(|s| s.color.into()).collect()
+    }
+
+    /// Parse a GIMP palette, it starts with a "GIMP Palette" line, followed by the optional "Name:" and "Columns:" lines
+    /// and a row of "R G B name" for every color, the name is optional. Empty lines and lines starting with '#' are skipped.
+    /// The error of a malformed row names its line number, starting at 1.
+    pub fn from_gpl(gpl: &str) -> Result<Self, ColorError> {
+        let mut lines = gpl
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| (idx + 1, line.trim()));
+        match lines.find(|(_, line)| !line.is_empty()) {
+            Some((_, "GIMP Palette")) => {}
+            _ => {
+                return Err(ColorError::FormatErr {
+                    input: gpl.to_string(),
+                    reason: "Palette: the gpl format error, the first line must be 'GIMP Palette'!"
+                        .to_string(),
+                })
+            }
+        }
+        let mut palette = Self::new();
+        for (line_number, line) in lines {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix("Name:") {
+                palette.name = Some(name.trim().to_string());
+                continue;
+            }
+            if let Some(columns) = line.strip_prefix("Columns:") {
+                let columns = columns.trim().parse::<u32>().map_err(|_| {
+                    parse_err(
+                        line,
+                        (None, format!("line {}, invalid columns", line_number)),
+                    )
+                })?;
+                palette.columns = Some(columns);
+                continue;
+            }
+            let mut rest = line;
+            let mut val = [0; 3];
+            for (idx, v) in val.iter_mut().enumerate() {
+                let (channel, tail) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                *v = channel.parse::<u8>().map_err(|_| {
+                    let reason = if channel.is_empty() {
+                        format!("line {}, expected 3 components, found {}", line_number, idx)
+                    } else {
+                        format!("line {}, invalid number '{}'", line_number, channel)
+                    };
+                    parse_err(line, (Some(idx), reason))
+                })?;
+                rest = tail.trim_start();
+            }
+            let color = AnyColor::RGB(RGB {
+                r: val[0],
+                g: val[1],
+                b: val[2],
+            });
+            let name = (!rest.is_empty()).then_some(rest);
+            palette.push(color, name);
+        }
+        Ok(palette)
+    }
+
+    /// Returns the palette in the GIMP palette format, colors that are not RGB are converted into RGB.
+    /// `from_gpl` of the result returns the same palette.
+    pub fn to_gpl(&self) -> String {
+        let mut gpl = String::from("GIMP Palette\n");
+        if let Some(name) = &self.name {
+            let _ = writeln!(gpl, "Name: {}", name);
+        }
+        if let Some(columns) = self.columns {
+            let _ = writeln!(gpl, "Columns: {}", columns);
+        }
+        gpl.push_str("#\n");
+        for swatch in &self.swatches {
+            let RGB { r, g, b } = swatch.color.into();
+            let _ = write!(gpl, "{:>3} {:>3} {:>3}", r, g, b);
+            if let Some(name) = &swatch.name {
+                let _ = write!(gpl, "\t{}", name);
+            }
+            gpl.push('\n');
+        }
+        gpl
+    }
+
+    /// Parse the Photoshop color swatches(.aco), the RGB colors are RGB and the CMYK colors are CMYK,
+    /// colors in other color spaces like Lab and HSB are skipped.
+    /// The names of the version 2 section are used when the file has one.
+    pub fn from_aco(data: &[u8]) -> Result<Self, ColorError> {
+        let mut reader = Reader::new(data, "aco");
+        let mut palette = Self::new();
+        while !reader.is_empty() {
+            let version = reader.u16()?;
+            if version != 1 && version != 2 {
+                return Err(ColorError::FormatErr {
+                    input: "aco".to_string(),
+                    reason: format!("Palette: the aco version {} is not supported!", version),
+                });
+            }
+            // the version 2 section repeats the colors of version 1 with their names
+            palette.swatches.clear();
+            palette.skipped.clear();
+            let count = reader.u16()?;
+            for _ in 0..count {
+                let space = reader.u16()?;
+                let [w, x, y, z] = [reader.u16()?, reader.u16()?, reader.u16()?, reader.u16()?];
+                let name = if version == 2 {
+                    let _zero = reader.u16()?;
+                    let len = reader.u16()? as usize;
+                    reader.utf16(len)?
+                } else {
+                    None
+                };
+                // RGB channels are 0~65535, and CMYK inks are inverted, 0 is 100%
+                let color = match space {
+                    0 => AnyColor::RGB(RGB {
+                        r: (w as f32 / 257.0).round() as u8,
+                        g: (x as f32 / 257.0).round() as u8,
+                        b: (y as f32 / 257.0).round() as u8,
+                    }),
+                    2 => {
+                        let ink = |v: u16| ((65535 - v) as f32 / 655.35).round() as u8;
+                        AnyColor::CMYK(CMYK {
+                            c: ink(w),
+                            m: ink(x),
+                            y: ink(y),
+                            k: ink(z),
+                        })
+                    }
+                    _ => {
+                        let color_space = match space {
+                            1 => "hsb".to_string(),
+                            7 => "lab".to_string(),
+                            8 => "gray".to_string(),
+                            _ => format!("color space {}", space),
+                        };
+                        palette.skipped.push(SkippedSwatch { name, color_space });
+                        continue;
+                    }
+                };
+                palette.swatches.push(Swatch { color, name });
+            }
+        }
+        Ok(palette)
+    }
+
+    /// Parse the Adobe swatch exchange(.ase), the RGB colors are RGB and the CMYK colors are CMYK,
+    /// Lab, gray and spot colors are skipped. The colors of groups are flattened into the palette.
+    pub fn from_ase(data: &[u8]) -> Result<Self, ColorError> {
+        let mut reader = Reader::new(data, "ase");
+        if reader.bytes(4)? != b"ASEF" {
+            return Err(ColorError::FormatErr {
+                input: "ase".to_string(),
+                reason: "Palette: the ase format error, the data must start with 'ASEF'!"
+                    .to_string(),
+            });
+        }
+        let _version = (reader.u16()?, reader.u16()?);
+        let count = reader.u32()?;
+        let mut palette = Self::new();
+        for _ in 0..count {
+            let kind = reader.u16()?;
+            let len = reader.u32()? as usize;
+            let data = reader.bytes(len)?;
+            // 0xC001 and 0xC002 are the start and the end of a group
+            if kind != 0x0001 {
+                continue;
+            }
+            let mut block = Reader::new(data, "ase");
+            let name_len = block.u16()? as usize;
+            let name = block.utf16(name_len)?;
+            let model = block.bytes(4)?;
+            let color = match model {
+                b"RGB " => {
+                    let [r, g, b] = [block.f32()?, block.f32()?, block.f32()?].map(unit_to_u8);
+                    Some(AnyColor::RGB(RGB { r, g, b }))
+                }
+                b"CMYK" => {
+                    let [c, m, y, k] = [block.f32()?, block.f32()?, block.f32()?, block.f32()?]
+                        .map(|v| (v.clamp(0.0, 1.0) * 100.0).round() as u8);
+                    Some(AnyColor::CMYK(CMYK { c, m, y, k }))
+                }
+                _ => None,
+            };
+            // the color type ends the block, 0 is global, 1 is spot and 2 is normal,
+            // it is read from the end when the values of the color space are unknown
+            let color_type = match color {
+                Some(_) => block.u16()?,
+                None => Reader::new(&data[data.len().saturating_sub(2)..], "ase").u16()?,
+            };
+            let spot = color_type == 1;
+            match color {
+                Some(color) if !spot => palette.swatches.push(Swatch { color, name }),
+                _ => {
+                    let color_space = if spot {
+                        "spot".to_string()
+                    } else {
+                        String::from_utf8_lossy(model).trim().to_lowercase()
+                    };
+                    palette.skipped.push(SkippedSwatch { name, color_space });
+                }
+            }
+        }
+        Ok(palette)
+    }
+}
@@ -0,0 +1,69 @@
+/// The options of `parse_with`, which control how tolerant the string parsing is.
+/// * clamp_out_of_range - clamp the values out of range, like "rgb(300,0,0)", instead of returning an error
+/// * allow_percent_omission - accept the saturation and lightness of hsl() and hsv() without the `%` sign
+/// * allow_named_colors - accept the CSS named colors like "tomato" and the "transparent" keyword
+/// * require_exact_syntax - reject whitespace around the string, an uppercase function name and a Hex without `#`
+///
+/// The default options are what `TryFrom<&str>` uses, `strict()` is for machine generated strings
+/// and `lenient()` is for user entered strings.
+/// ### example
+/// ```rust
+/// use easy_color::{ParseOptions, RGB};
+/// let rgb = RGB::parse_with("rgb(300, 128, 0)", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(rgb.to_string(), "rgb(255,128,0)");
+/// assert!(RGB::parse_with("rgb(300, 128, 0)", &ParseOptions::default()).is_err());
+/// assert!(RGB::parse_with("tomato", &ParseOptions::strict()).is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseOptions {
+    pub clamp_out_of_range: bool,
+    pub allow_percent_omission: bool,
+    pub allow_named_colors: bool,
+    pub require_exact_syntax: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            clamp_out_of_range: false,
+            allow_percent_omission: true,
+            allow_named_colors: true,
+            require_exact_syntax: false,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Validate everything, for machine generated strings
+    pub fn strict() -> Self {
+        Self {
+            clamp_out_of_range: false,
+            allow_percent_omission: false,
+            allow_named_colors: false,
+            require_exact_syntax: true,
+        }
+    }
+
+    /// Accept as much as possible, for user entered strings
+    pub fn lenient() -> Self {
+        Self {
+            clamp_out_of_range: true,
+            allow_percent_omission: true,
+            allow_named_colors: true,
+            require_exact_syntax: false,
+        }
+    }
+
+    /// Returns the trimmed string to parse, or None if the syntax is not exact enough.
+    /// The parsers match it case insensitively, so it is not lowercased.
+    pub(crate) fn normalize<'a>(&self, color_str: &'a str) -> Option<&'a str> {
+        if self.require_exact_syntax {
+            let name = color_str.split_once('(').map(|(name, _)| name);
+            let uppercase = name.is_some_and(|name| name.chars().any(|c| c.is_uppercase()));
+            if color_str.trim() != color_str || uppercase {
+                return None;
+            }
+        }
+        Some(color_str.trim())
+    }
+}
@@ -0,0 +1,160 @@
+use crate::common::{
+    is_in_unit_range, linear_to_prophoto, multiply_matrix, parse_color_space, prophoto_to_linear,
+    rgb_to_linear_srgb, D50_TO_D65, D65_TO_D50, PROPHOTO_RGB_TO_XYZ, SRGB_TO_XYZ,
+    XYZ_TO_PROPHOTO_RGB, XYZ_TO_SRGB,
+};
+use crate::{ColorError, Hex, WhitePoint, RGB, RGBA, XYZ};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// ProPhotoRGB is the ROMM RGB color space used by photo editors like Lightroom, it is wide enough to contain nearly every visible color.
+/// It can be parsed from a string in the CSS format "color(prophoto-rgb r g b)" or from a tuple (r,g,b).
+/// * r:f32 - red value(0~1)
+/// * g:f32 - green value(0~1)
+/// * b:f32 - blue value(0~1)
+///
+/// The channels are encoded with the ROMM curve(gamma 1.8), the white point is D50,
+/// so it is adapted to D65 with the Bradford transform when converted to or from RGB.
+/// Its green and blue primaries are imaginary, they and many other colors are clamped when converted to RGB,
+/// use `is_in_srgb_gamut` to check it first.
+/// ### example
+/// ```rust
+/// use easy_color::{ProPhotoRGB, RGB};
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// let prophoto:ProPhotoRGB = rgb.into();
+/// assert_eq!(prophoto.to_string(), "color(prophoto-rgb 0.7022 0.2757 0.1035)");
+///
+/// let prophoto:ProPhotoRGB = "color(prophoto-rgb 0 1 0)".try_into().unwrap();
+/// assert!(!prophoto.is_in_srgb_gamut());
+/// let rgb:RGB = prophoto.into();
+/// assert_eq!(rgb.to_string(), "rgb(0,255,0)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct ProPhotoRGB {
+    pub(crate) r: f32,
+    pub(crate) g: f32,
+    pub(crate) b: f32,
+}
+
+impl TryFrom<&str> for ProPhotoRGB {
+    type Error = ColorError;
+    fn try_from(prophoto_str: &str) -> Result<Self, Self::Error> {
+        parse_color_space(prophoto_str, "prophoto-rgb", "ProPhotoRGB")?.try_into()
+    }
+}
+
+impl FromStr for ProPhotoRGB {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for ProPhotoRGB {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(0.0..=1.0).contains(&value.1)
+            || !(0.0..=1.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "ProPhotoRGB: args ({},{},{}) value error, all value must between 0~1!",
+                    value.0, value.1, value.2
+                ),
+            })
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for ProPhotoRGB {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for ProPhotoRGB {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let xyz = multiply_matrix(&SRGB_TO_XYZ, rgb_to_linear_srgb(r, g, b));
+        let (x, y, z) = multiply_matrix(&D65_TO_D50, xyz);
+        Self::from_xyz_d50(x, y, z)
+    }
+}
+
+impl From<RGBA> for ProPhotoRGB {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<XYZ> for ProPhotoRGB {
+    fn from(xyz: XYZ) -> Self {
+        let XYZ { x, y, z, .. } = xyz.to_white_point(WhitePoint::D50);
+        Self::from_xyz_d50(x / 100.0, y / 100.0, z / 100.0)
+    }
+}
+
+impl Display for ProPhotoRGB {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "color(prophoto-rgb {:.4} {:.4} {:.4})",
+            self.r, self.g, self.b
+        )
+    }
+}
+
+impl ProPhotoRGB {
+    pub fn red(&self) -> f32 {
+        self.r
+    }
+
+    pub fn green(&self) -> f32 {
+        self.g
+    }
+
+    pub fn blue(&self) -> f32 {
+        self.b
+    }
+
+    /// Create from CIE XYZ(D50) where Y of white is 1.0, channels out of the ProPhoto gamut are clamped.
+    fn from_xyz_d50(x: f32, y: f32, z: f32) -> Self {
+        let (r, g, b) = multiply_matrix(&XYZ_TO_PROPHOTO_RGB, (x, y, z));
+        Self {
+            r: linear_to_prophoto(r.clamp(0.0, 1.0)),
+            g: linear_to_prophoto(g.clamp(0.0, 1.0)),
+            b: linear_to_prophoto(b.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// Returns CIE XYZ(D50) of the color, Y of white is 1.0
+    pub(crate) fn to_xyz_d50(self) -> (f32, f32, f32) {
+        let lin = (
+            prophoto_to_linear(self.r),
+            prophoto_to_linear(self.g),
+            prophoto_to_linear(self.b),
+        );
+        multiply_matrix(&PROPHOTO_RGB_TO_XYZ, lin)
+    }
+
+    /// Returns the linear light sRGB channels of the color, they are out of 0~1 when the color is out of the sRGB gamut.
+    pub(crate) fn to_linear_srgb(self) -> (f32, f32, f32) {
+        let xyz = multiply_matrix(&D50_TO_D65, self.to_xyz_d50());
+        multiply_matrix(&XYZ_TO_SRGB, xyz)
+    }
+
+    /// Check whether the color can be represented in sRGB without clamping.
+    pub fn is_in_srgb_gamut(&self) -> bool {
+        is_in_unit_range(self.to_linear_srgb())
+    }
+}
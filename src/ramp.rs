@@ -0,0 +1,105 @@
+use crate::{Hex, RGBA};
+use std::fmt::Write;
+
+/// A shade of a ramp with its key, like "500".
+#[derive(Debug, PartialEq, Clone)]
+pub struct RampShade {
+    pub(crate) key: String,
+    pub(crate) color: RGBA,
+}
+
+impl RampShade {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn color(&self) -> RGBA {
+        self.color
+    }
+}
+
+/// Ramp is a list of named shades of a color, like the 50~900 scale of a design system,
+/// which can be written as CSS custom properties or SCSS variables with Hex values.
+/// The shades keep the order they were pushed in.
+/// ### example
+/// ```rust
+/// use easy_color::{Hex, Ramp};
+/// let mut ramp = Ramp::new();
+/// ramp.push("100", Hex::try_from("#D5F3E8").unwrap())
+///     .push("500", Hex::try_from("#2BC48A").unwrap());
+/// assert_eq!(
+///     ramp.to_css_variables("brand"),
+///     ":root {\n  --brand-100: #D5F3E8;\n  --brand-500: #2BC48A;\n}\n"
+/// );
+/// assert_eq!(ramp.to_scss_variables("brand"), "$brand-100: #D5F3E8;\n$brand-500: #2BC48A;\n");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Ramp {
+    pub(crate) shades: Vec<RampShade>,
+}
+
+impl Ramp {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn shades(&self) -> &[RampShade] {
+        &self.shades
+    }
+
+    pub fn len(&self) -> usize {
+        self.shades.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shades.is_empty()
+    }
+
+    /// Returns the color of the key
+    pub fn get(&self, key: &str) -> Option<RGBA> {
+        self.shades.iter().find(|s| s.key == key).map(|s| s.color)
+    }
+
+    /// Append a shade, the color of an existing key is replaced in place
+    pub fn push<C: Into<RGBA>>(&mut self, key: &str, color: C) -> &mut Self {
+        let color = color.into();
+        match self.shades.iter_mut().find(|s| s.key == key) {
+            Some(shade) => shade.color = color,
+            None => self.shades.push(RampShade {
+                key: key.to_string(),
+                color,
+            }),
+        }
+        self
+    }
+
+    /// Returns a ":root" block with a "--prefix-key: #hex;" line per shade
+    pub fn to_css_variables(&self, prefix: &str) -> String {
+        let mut css = String::from(":root {\n");
+        for shade in &self.shades {
+            let _ = writeln!(
+                css,
+                "  --{}-{}: {};",
+                prefix,
+                shade.key,
+                Hex::from(shade.color)
+            );
+        }
+        css.push_str("}\n");
+        css
+    }
+
+    /// Returns a "$prefix-key: #hex;" line per shade
+    pub fn to_scss_variables(&self, prefix: &str) -> String {
+        self.shades.iter().fold(String::new(), |mut scss, shade| {
+            let _ = writeln!(
+                scss,
+                "${}-{}: {};",
+                prefix,
+                shade.key,
+                Hex::from(shade.color)
+            );
+            scss
+        })
+    }
+}
@@ -0,0 +1,164 @@
+use crate::common::{
+    is_in_unit_range, linear_srgb_to_rgb, linear_to_rec2020, multiply_matrix, parse_color_space,
+    rec2020_to_linear, rgb_to_linear_srgb, REC2020_TO_XYZ, SRGB_TO_XYZ, XYZ_TO_REC2020,
+    XYZ_TO_SRGB,
+};
+use crate::{ColorError, Hex, WhitePoint, RGB, RGBA, XYZ};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Rec2020 is the ITU-R BT.2020 color space used by UHD and HDR video, its gamut is much wider than sRGB.
+/// It can be parsed from a string in the CSS format "color(rec2020 r g b)" or from a tuple (r,g,b).
+/// * r:f32 - red value(0~1)
+/// * g:f32 - green value(0~1)
+/// * b:f32 - blue value(0~1)
+///
+/// The channels are encoded with the BT.2020 transfer curve, the white point is D65.
+/// Most Rec2020 colors can't be represented by RGB, use `is_in_srgb_gamut` to find them,
+/// converting them into RGB with `clip_to_srgb` or `into()` clamps the channels.
+/// ### example
+/// ```rust
+/// use easy_color::{Rec2020, RGB};
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// let rec2020:Rec2020 = rgb.into();
+/// assert_eq!(rec2020.to_string(), "color(rec2020 0.7920 0.2310 0.0738)");
+///
+/// let rec2020:Rec2020 = "color(rec2020 0 1 0)".try_into().unwrap();
+/// assert!(!rec2020.is_in_srgb_gamut());
+/// assert_eq!(rec2020.clip_to_srgb().to_string(), "rgb(0,255,0)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Rec2020 {
+    pub(crate) r: f32,
+    pub(crate) g: f32,
+    pub(crate) b: f32,
+}
+
+impl TryFrom<&str> for Rec2020 {
+    type Error = ColorError;
+    fn try_from(rec2020_str: &str) -> Result<Self, Self::Error> {
+        parse_color_space(rec2020_str, "rec2020", "Rec2020")?.try_into()
+    }
+}
+
+impl FromStr for Rec2020 {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for Rec2020 {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(0.0..=1.0).contains(&value.1)
+            || !(0.0..=1.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "Rec2020: args ({},{},{}) value error, all value must between 0~1!",
+                    value.0, value.1, value.2
+                ),
+            })
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Rec2020 {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for Rec2020 {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (x, y, z) = multiply_matrix(&SRGB_TO_XYZ, rgb_to_linear_srgb(r, g, b));
+        Self::from_xyz(x, y, z)
+    }
+}
+
+impl From<RGBA> for Rec2020 {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<XYZ> for Rec2020 {
+    fn from(xyz: XYZ) -> Self {
+        let XYZ { x, y, z, .. } = xyz.to_white_point(WhitePoint::D65);
+        Self::from_xyz(x / 100.0, y / 100.0, z / 100.0)
+    }
+}
+
+impl Display for Rec2020 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "color(rec2020 {:.4} {:.4} {:.4})",
+            self.r, self.g, self.b
+        )
+    }
+}
+
+impl Rec2020 {
+    pub fn red(&self) -> f32 {
+        self.r
+    }
+
+    pub fn green(&self) -> f32 {
+        self.g
+    }
+
+    pub fn blue(&self) -> f32 {
+        self.b
+    }
+
+    /// Create from CIE XYZ(D65) where Y of white is 1.0, channels out of the Rec.2020 gamut are clamped.
+    fn from_xyz(x: f32, y: f32, z: f32) -> Self {
+        let (r, g, b) = multiply_matrix(&XYZ_TO_REC2020, (x, y, z));
+        Self {
+            r: linear_to_rec2020(r.clamp(0.0, 1.0)),
+            g: linear_to_rec2020(g.clamp(0.0, 1.0)),
+            b: linear_to_rec2020(b.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// Returns CIE XYZ(D65) of the color, Y of white is 1.0
+    pub(crate) fn to_xyz_unit(self) -> (f32, f32, f32) {
+        let lin = (
+            rec2020_to_linear(self.r),
+            rec2020_to_linear(self.g),
+            rec2020_to_linear(self.b),
+        );
+        multiply_matrix(&REC2020_TO_XYZ, lin)
+    }
+
+    /// Check whether the color can be represented in sRGB without clamping.
+    pub fn is_in_srgb_gamut(&self) -> bool {
+        is_in_unit_range(multiply_matrix(&XYZ_TO_SRGB, self.to_xyz_unit()))
+    }
+
+    /// Convert the color into RGB, the channels out of the sRGB gamut are clamped.
+    /// ```rust
+    /// use easy_color::Rec2020;
+    /// let rec2020:Rec2020 = (0.5, 0.5, 0.5).try_into().unwrap();
+    /// assert!(rec2020.is_in_srgb_gamut());
+    /// assert_eq!(rec2020.clip_to_srgb().to_string(), "rgb(139,139,139)");
+    /// ```
+    pub fn clip_to_srgb(&self) -> RGB {
+        let (r, g, b) = multiply_matrix(&XYZ_TO_SRGB, self.to_xyz_unit());
+        let (r, g, b) = linear_srgb_to_rgb(r, g, b);
+        RGB { r, g, b }
+    }
+}
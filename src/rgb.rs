@@ -1,11 +1,31 @@
-use crate::common::{calc_rgb_with_alpha, cmyk_to_rgb, hsl_to_rgb, hsv_to_rgb};
-use crate::{ColorError, Hex, CMYK, HSL, HSLA, HSV, RGBA};
-use std::fmt::{Display, Formatter};
+use crate::ansi256::ANSI_RESET;
+use crate::common::{
+    calc_rgb_with_alpha, checked_channels, chroma_hue_to_ab, cmyk_to_rgb, color_function_args,
+    delta_e2000, hsi_to_rgb, hsl_to_rgb, hsluv_to_rgb, hsv_to_rgb, hwb_to_rgb, kelvin_to_rgb,
+    lab_to_rgb, linear_srgb_to_rgb, linear_to_srgb, oklch_to_rgb, parse_color_function_args,
+    parse_err, parse_gray_level, parse_rgb_channels, redmean_distance_squared, rgb_to_lab,
+    rgb_to_xyz, split_color_args, unit_to_u8, xyz_to_rgb, xyz_to_uv,
+};
+use crate::named::named_color;
+use crate::{
+    AdobeRGB, Ansi256, AnsiMode, ByteOrder, Cam16, ColorError, ColorRange, CssFilter,
+    DistanceMetric, EnsuredContrast, Gray, HSLuv, Hex, Kelvin, LinearRGB, LumaStandard, OkLch,
+    PaletteMatcher, ParseOptions, ProPhotoRGB, RGBf, Rec2020, TextSize, ViewingConditions,
+    WcagLevel, WhitePoint, Xyy, YCbCr, CMYK, CMYKA, HSI, HSL, HSLA, HSV, HWB, LCH, LMS, P3, RGB16,
+    RGB565, RGBA, XYZ, YIQ,
+};
+use std::fmt::{Display, Formatter, LowerHex, UpperHex};
+use std::str::FromStr;
 
-/// RGB can be parsed from a string in the format "rgb(r,g,b)" or from a tuple (r,g,b).
+/// RGB can be parsed from a string in the format "rgb(r,g,b)", from a CSS named color like "tomato" or from a tuple (r,g,b).
 /// * r:u8 - red value(0~255)
 /// * g:u8 - green value(0~255)
 /// * b:u8 - blue value(0~255)
+///
+/// In the string form the channels can also be percentages like "rgb(100%, 50%, 0%)", but not a mix of both.
+/// The CSS `color()` function like "color(srgb 0.17 0.77 0.54)" is accepted too, in the color spaces
+/// srgb, srgb-linear, display-p3, rec2020, a98-rgb and prophoto-rgb, wider gamuts are clamped into sRGB.
+/// "gray(50%)" or "gray(128)" is a neutral gray, out of range values are clamped.
 /// The red, green, and blue values can be individually set or retrieved.
 /// ### example
 /// ```rust
@@ -18,6 +38,9 @@ use std::fmt::{Display, Formatter};
 /// let rgb:RGB = (43, 196, 138).try_into().unwrap();
 /// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
 ///
+/// let named:RGB = "rebeccapurple".try_into().unwrap();
+/// assert_eq!(named.to_string(), "rgb(102,51,153)");
+///
 /// let hex:Hex = rgb.into();
 /// assert_eq!(hex.to_string(), "#2BC48A");
 /// ```
@@ -31,25 +54,14 @@ pub struct RGB {
 impl TryFrom<&str> for RGB {
     type Error = ColorError;
     fn try_from(rgb_str: &str) -> Result<Self, Self::Error> {
-        let mut color = rgb_str.trim().to_lowercase();
-        if color.starts_with("rgb(") && color.ends_with(')') {
-            color = color.replace("rgb(", "").replace(')', "");
-            let tmp = color.split(',').collect::<Vec<_>>();
-            if tmp.len() == 3 {
-                let val = tmp
-                    .iter()
-                    .map(|s| s.trim().parse::<u8>())
-                    .filter_map(|v| v.ok())
-                    .collect::<Vec<_>>();
-                if val.len() == 3 {
-                    return (val[0], val[1], val[2]).try_into();
-                }
-            }
-        }
-        Err(ColorError::FormatErr(format!(
-            "RGB:{} format error!",
-            rgb_str
-        )))
+        Self::parse_with(rgb_str, &ParseOptions::default())
+    }
+}
+
+impl FromStr for RGB {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
     }
 }
 
@@ -64,13 +76,48 @@ impl TryFrom<(u8, u8, u8)> for RGB {
     }
 }
 
+impl TryFrom<(i32, i32, i32)> for RGB {
+    type Error = ColorError;
+    /// Create from computed channels, a channel out of 0~255 is an error instead of wrapping like `as u8`
+    fn try_from(value: (i32, i32, i32)) -> Result<Self, Self::Error> {
+        let (r, g, b) = checked_channels("RGB", value)?;
+        Ok(Self { r, g, b })
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for RGB {
+    type Error = ColorError;
+    /// Create from normalized channels(0~1) like the colors of shaders, each channel is rounded half up,
+    /// so 1.0 is 255 and 0.5 is 128. NaN and values out of 0~1 are errors.
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(0.0..=1.0).contains(&value.1)
+            || !(0.0..=1.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "RGB: args ({},{},{}) value error, all value must between 0~1!",
+                    value.0, value.1, value.2
+                ),
+            })
+        } else {
+            Ok(Self {
+                r: unit_to_u8(value.0),
+                g: unit_to_u8(value.1),
+                b: unit_to_u8(value.2),
+            })
+        }
+    }
+}
+
 impl From<Hex> for RGB {
     fn from(hex: Hex) -> Self {
         let (r, g, b, a) = hex.rgba;
         let r = calc_rgb_with_alpha(r, a) as u8;
         let g = calc_rgb_with_alpha(g, a) as u8;
         let b = calc_rgb_with_alpha(b, a) as u8;
-        (r, g, b).try_into().unwrap()
+        Self { r, g, b }
     }
 }
 
@@ -79,7 +126,7 @@ impl From<RGBA> for RGB {
         let r = calc_rgb_with_alpha(rgba.r, rgba.a) as u8;
         let g = calc_rgb_with_alpha(rgba.g, rgba.a) as u8;
         let b = calc_rgb_with_alpha(rgba.b, rgba.a) as u8;
-        (r, g, b).try_into().unwrap()
+        Self { r, g, b }
     }
 }
 
@@ -119,43 +166,745 @@ impl From<CMYK> for RGB {
     }
 }
 
+impl From<HWB> for RGB {
+    fn from(hwb: HWB) -> Self {
+        let HWB { h, w, b } = hwb;
+        let (r, g, b) = hwb_to_rgb(h, w, b);
+        Self { r, g, b }
+    }
+}
+
+impl From<LCH> for RGB {
+    fn from(lch: LCH) -> Self {
+        let LCH { l, c, h } = lch;
+        let (a, b) = chroma_hue_to_ab(c, h);
+        let (r, g, b) = lab_to_rgb(l, a, b);
+        Self { r, g, b }
+    }
+}
+
+impl From<OkLch> for RGB {
+    fn from(oklch: OkLch) -> Self {
+        let OkLch { l, c, h, a } = oklch;
+        let (mut r, mut g, mut b) = oklch_to_rgb(l, c, h);
+        r = calc_rgb_with_alpha(r, a) as u8;
+        g = calc_rgb_with_alpha(g, a) as u8;
+        b = calc_rgb_with_alpha(b, a) as u8;
+        Self { r, g, b }
+    }
+}
+
+impl From<XYZ> for RGB {
+    fn from(xyz: XYZ) -> Self {
+        let XYZ { x, y, z, .. } = xyz.to_white_point(WhitePoint::D65);
+        let (r, g, b) = xyz_to_rgb(x / 100.0, y / 100.0, z / 100.0);
+        Self { r, g, b }
+    }
+}
+
+impl From<YCbCr> for RGB {
+    fn from(ycbcr: YCbCr) -> Self {
+        let YCbCr {
+            y,
+            cb,
+            cr,
+            standard,
+            range,
+        } = ycbcr;
+        let (kr, kb) = standard.coefficients();
+        let (luma, pb, pr) = match range {
+            ColorRange::Full => (
+                y as f32 / 255.0,
+                (cb as f32 - 128.0) / 255.0,
+                (cr as f32 - 128.0) / 255.0,
+            ),
+            ColorRange::Studio => (
+                (y.clamp(16, 235) as f32 - 16.0) / 219.0,
+                (cb.clamp(16, 240) as f32 - 128.0) / 224.0,
+                (cr.clamp(16, 240) as f32 - 128.0) / 224.0,
+            ),
+        };
+        let r = luma + 2.0 * (1.0 - kr) * pr;
+        let b = luma + 2.0 * (1.0 - kb) * pb;
+        let g = (luma - kr * r - kb * b) / (1.0 - kr - kb);
+        Self {
+            r: unit_to_u8(r),
+            g: unit_to_u8(g),
+            b: unit_to_u8(b),
+        }
+    }
+}
+
+impl From<YIQ> for RGB {
+    fn from(yiq: YIQ) -> Self {
+        let YIQ { y, i, q } = yiq;
+        let r = y + 0.9563 * i + 0.6210 * q;
+        let g = y - 0.2721 * i - 0.6474 * q;
+        let b = y - 1.1070 * i + 1.7046 * q;
+        Self {
+            r: unit_to_u8(r),
+            g: unit_to_u8(g),
+            b: unit_to_u8(b),
+        }
+    }
+}
+
+impl From<Xyy> for RGB {
+    fn from(xyy: Xyy) -> Self {
+        let xyz: XYZ = xyy.into();
+        xyz.into()
+    }
+}
+
+impl From<HSI> for RGB {
+    fn from(hsi: HSI) -> Self {
+        let HSI { h, s, i } = hsi;
+        let (r, g, b) = hsi_to_rgb(h, s, i);
+        Self { r, g, b }
+    }
+}
+
+impl From<LinearRGB> for RGB {
+    fn from(linear: LinearRGB) -> Self {
+        Self {
+            r: unit_to_u8(linear_to_srgb(linear.r)),
+            g: unit_to_u8(linear_to_srgb(linear.g)),
+            b: unit_to_u8(linear_to_srgb(linear.b)),
+        }
+    }
+}
+
+impl From<P3> for RGB {
+    fn from(p3: P3) -> Self {
+        let (r, g, b) = p3.to_linear_srgb();
+        let (r, g, b) = linear_srgb_to_rgb(r, g, b);
+        Self { r, g, b }
+    }
+}
+
+impl From<Rec2020> for RGB {
+    fn from(rec2020: Rec2020) -> Self {
+        rec2020.clip_to_srgb()
+    }
+}
+
+impl From<AdobeRGB> for RGB {
+    fn from(adobe: AdobeRGB) -> Self {
+        let (r, g, b) = adobe.to_linear_srgb();
+        let (r, g, b) = linear_srgb_to_rgb(r, g, b);
+        Self { r, g, b }
+    }
+}
+
+impl From<ProPhotoRGB> for RGB {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let (r, g, b) = prophoto.to_linear_srgb();
+        let (r, g, b) = linear_srgb_to_rgb(r, g, b);
+        Self { r, g, b }
+    }
+}
+
+impl From<HSLuv> for RGB {
+    fn from(hsluv: HSLuv) -> Self {
+        let HSLuv { h, s, l } = hsluv;
+        let (r, g, b) = hsluv_to_rgb(h, s, l);
+        Self { r, g, b }
+    }
+}
+
+impl From<Cam16> for RGB {
+    fn from(cam16: Cam16) -> Self {
+        cam16.to_rgb_with(&ViewingConditions::default())
+    }
+}
+
+impl From<LMS> for RGB {
+    fn from(lms: LMS) -> Self {
+        let (x, y, z) = lms.to_xyz_unit();
+        let (r, g, b) = xyz_to_rgb(x, y, z);
+        Self { r, g, b }
+    }
+}
+
+impl From<Gray> for RGB {
+    fn from(gray: Gray) -> Self {
+        let v = gray.v;
+        Self { r: v, g: v, b: v }
+    }
+}
+
+impl From<RGB16> for RGB {
+    fn from(rgb16: RGB16) -> Self {
+        let to_u8 = |v: u16| ((v as u32 + 128) / 257) as u8;
+        Self {
+            r: to_u8(rgb16.r),
+            g: to_u8(rgb16.g),
+            b: to_u8(rgb16.b),
+        }
+    }
+}
+
+impl From<RGBf> for RGB {
+    fn from(rgbf: RGBf) -> Self {
+        Self {
+            r: unit_to_u8(rgbf.r),
+            g: unit_to_u8(rgbf.g),
+            b: unit_to_u8(rgbf.b),
+        }
+    }
+}
+
+impl From<RGB565> for RGB {
+    fn from(rgb565: RGB565) -> Self {
+        let (r, g, b) = rgb565.fields();
+        Self {
+            r: r << 3 | r >> 2,
+            g: g << 2 | g >> 4,
+            b: b << 3 | b >> 2,
+        }
+    }
+}
+
+impl From<Ansi256> for RGB {
+    fn from(ansi: Ansi256) -> Self {
+        let (r, g, b) = ansi.to_tuple();
+        Self { r, g, b }
+    }
+}
+
+impl From<CMYKA> for RGB {
+    fn from(cmyka: CMYKA) -> Self {
+        let rgba: RGBA = cmyka.into();
+        rgba.into()
+    }
+}
+
+impl From<Kelvin> for RGB {
+    fn from(kelvin: Kelvin) -> Self {
+        let (r, g, b) = kelvin_to_rgb(kelvin.0);
+        Self { r, g, b }
+    }
+}
+
+impl From<[u8; 3]> for RGB {
+    fn from(value: [u8; 3]) -> Self {
+        let [r, g, b] = value;
+        Self { r, g, b }
+    }
+}
+
+impl TryFrom<&[u8]> for RGB {
+    type Error = ColorError;
+    /// Create from a slice of 3 bytes, like a pixel of an RGB image buffer
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 3] = value.try_into().map_err(|_| ColorError::ValueErr {
+            input: format!("{:?}", value),
+            reason: format!(
+                "RGB: {:?} value error, the slice must have 3 bytes, found {}!",
+                value,
+                value.len()
+            ),
+        })?;
+        Ok(bytes.into())
+    }
+}
+
+impl From<RGB> for [u8; 3] {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_array()
+    }
+}
+
+impl From<RGB> for (u8, u8, u8) {
+    fn from(rgb: RGB) -> Self {
+        rgb.as_tuple()
+    }
+}
+
 impl Display for RGB {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "rgb({},{},{})", self.r, self.g, self.b)
     }
 }
 
+/// `{:x}` and `{:X}` write the 6 digits like "2bc48a", `{:#x}` adds the "0x" prefix
+impl LowerHex for RGB {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(true, "0x", &format!("{:06x}", self.to_u32()))
+    }
+}
+
+impl UpperHex for RGB {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(true, "0x", &format!("{:06X}", self.to_u32()))
+    }
+}
+
 impl RGB {
+    /// Parse a string like `TryFrom<&str>` does, with the given options.
+    /// `TryFrom<&str>` is the same as this with `ParseOptions::default()`.
+    pub fn parse_with(rgb_str: &str, options: &ParseOptions) -> Result<Self, ColorError> {
+        let Some(color) = options.normalize(rgb_str) else {
+            return Err(ColorError::FormatErr {
+                input: rgb_str.to_string(),
+                reason: format!("'{}' format error, the syntax is not exact!", rgb_str),
+            });
+        };
+        if let Some((r, g, b)) = named_color(color).filter(|_| options.allow_named_colors) {
+            return Ok(Self { r, g, b });
+        }
+        if let Some(args) = color_function_args(color, &["rgb", "rgba"]) {
+            let (tmp, alpha) = split_color_args(args).map_err(|e| parse_err(rgb_str, e))?;
+            if alpha.is_some() {
+                return Err(parse_err(
+                    rgb_str,
+                    (
+                        Some(3),
+                        "RGB has no alpha, parse it into RGBA instead".to_string(),
+                    ),
+                ));
+            }
+            let (r, g, b) = parse_rgb_channels(&tmp, options).map_err(|e| parse_err(rgb_str, e))?;
+            return Ok(Self { r, g, b });
+        }
+        if let Some(arg) = color_function_args(color, &["gray"]) {
+            let v = parse_gray_level(arg).map_err(|e| parse_err(rgb_str, e))?;
+            return Ok(Self { r: v, g: v, b: v });
+        }
+        if let Some(args) = color_function_args(color, &["color"]) {
+            let (space, channels, alpha) =
+                parse_color_function_args(args, options).map_err(|e| parse_err(rgb_str, e))?;
+            if alpha.is_some() {
+                return Err(parse_err(
+                    rgb_str,
+                    (
+                        Some(3),
+                        "RGB has no alpha, parse it into RGBA instead".to_string(),
+                    ),
+                ));
+            }
+            return Ok(Self::from_color_space(space, channels));
+        }
+        Err(ColorError::FormatErr {
+            input: rgb_str.to_string(),
+            reason: format!("RGB:{} format error!", rgb_str),
+        })
+    }
+
+    /// Convert the channels(0~1) of a CSS `color()` function in one of `COLOR_SPACES`,
+    /// colors out of the sRGB gamut are clamped
+    pub(crate) fn from_color_space(space: &str, channels: (f32, f32, f32)) -> Self {
+        let (r, g, b) = channels;
+        match space {
+            "srgb-linear" => LinearRGB { r, g, b }.into(),
+            "display-p3" => P3 { r, g, b }.into(),
+            "rec2020" => Rec2020 { r, g, b }.into(),
+            "a98-rgb" => AdobeRGB { r, g, b }.into(),
+            "prophoto-rgb" => ProPhotoRGB { r, g, b }.into(),
+            _ => RGBf { r, g, b }.into(),
+        }
+    }
+
+    /// Create from a packed 0xRRGGBB value, the highest byte is ignored.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb = RGB::from_u32(0x2BC48A);
+    /// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+    /// assert_eq!(rgb.to_u32(), 0x2BC48A);
+    /// ```
+    pub fn from_u32(value: u32) -> Self {
+        Self::from_u32_with(value, ByteOrder::Rgb)
+    }
+
+    /// Returns the packed 0xRRGGBB value, the highest byte is 0.
+    pub fn to_u32(&self) -> u32 {
+        self.to_u32_with(ByteOrder::Rgb)
+    }
+
+    /// Create from a packed value in the given byte order, the alpha byte is ignored.
+    pub fn from_u32_with(value: u32, order: ByteOrder) -> Self {
+        let [r, g, b, _] = order.unpack(value);
+        Self { r, g, b }
+    }
+
+    /// Returns the packed value in the given byte order, the alpha byte is 255.
+    pub fn to_u32_with(&self, order: ByteOrder) -> u32 {
+        order.pack([self.r, self.g, self.b, 255])
+    }
+
+    /// Create from a Win32 COLORREF, which is 0x00BBGGRR, the highest byte is ignored.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb = RGB::from_colorref(0x008AC42B);
+    /// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+    /// assert_eq!(rgb.to_colorref(), 0x008AC42B);
+    /// ```
+    pub fn from_colorref(value: u32) -> Self {
+        Self::from_u32_with(value, ByteOrder::Bgr)
+    }
+
+    /// Returns the Win32 COLORREF, which is 0x00BBGGRR, the highest byte is 0.
+    pub fn to_colorref(&self) -> u32 {
+        self.to_u32_with(ByteOrder::Bgr)
+    }
+
+    /// Returns (r, g, b), `try_from` of the tuple returns the same color.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (43,196,138).try_into().unwrap();
+    /// let (r, g, b) = rgb.into();
+    /// assert_eq!((r, g, b), rgb.as_tuple());
+    /// ```
+    pub fn as_tuple(&self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
+
+    /// Returns [r, g, b]
+    pub fn to_array(&self) -> [u8; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    /// Returns the normalized channels(0~1), `try_from` of the tuple returns the same color.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (1.0, 0.5, 0.0).try_into().unwrap();
+    /// assert_eq!(rgb.to_string(), "rgb(255,128,0)");
+    /// assert_eq!(rgb.to_f32_tuple(), (1.0, 128.0 / 255.0, 0.0));
+    /// ```
+    pub fn to_f32_tuple(&self) -> (f32, f32, f32) {
+        (
+            self.r as f32 / 255.0,
+            self.g as f32 / 255.0,
+            self.b as f32 / 255.0,
+        )
+    }
+
+    /// Returns the channels as percentages(0~100), 255 is exactly 100 and 0 is exactly 0.
+    pub fn to_percentage_tuple(&self) -> (f32, f32, f32) {
+        let percent = |v: u8| v as f32 * 100.0 / 255.0;
+        (percent(self.r), percent(self.g), percent(self.b))
+    }
+
+    /// Returns a string with the channels as percentages, like "rgb(16.9%, 76.9%, 54.1%)",
+    /// the precision is the number of decimal places.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (43,196,138).try_into().unwrap();
+    /// assert_eq!(rgb.to_percentage_string(1), "rgb(16.9%, 76.9%, 54.1%)");
+    /// let rgb:RGB = (255,0,128).try_into().unwrap();
+    /// assert_eq!(rgb.to_percentage_string(0), "rgb(100%, 0%, 50%)");
+    /// ```
+    pub fn to_percentage_string(&self, precision: usize) -> String {
+        let (r, g, b) = self.to_percentage_tuple();
+        format!(
+            "rgb({:.*}%, {:.*}%, {:.*}%)",
+            precision, r, precision, g, precision, b
+        )
+    }
+
     pub fn red(&self) -> u8 {
         self.r
     }
     pub fn set_red(&mut self, red: u8) -> &mut Self {
-        self.r = red.min(255);
+        self.r = red;
         self
     }
     pub fn green(&self) -> u8 {
         self.g
     }
     pub fn set_green(&mut self, green: u8) -> &mut Self {
-        self.g = green.min(255);
+        self.g = green;
         self
     }
     pub fn blue(&self) -> u8 {
         self.b
     }
     pub fn set_blue(&mut self, blue: u8) -> &mut Self {
-        self.b = blue.min(255);
+        self.b = blue;
         self
     }
 
+    /// Returns true if no channel differs by more than the tolerance
+    /// ```rust
+    /// use easy_color::{HSL, RGB};
+    /// let rgb:RGB = (43,196,138).try_into().unwrap();
+    /// let round_trip:RGB = HSL::from(rgb).into();
+    /// assert!(rgb.approx_eq(round_trip, 2));
+    /// ```
+    pub fn approx_eq(&self, other: impl Into<Self>, tolerance: u8) -> bool {
+        let other: RGB = other.into();
+        self.r.abs_diff(other.r) <= tolerance
+            && self.g.abs_diff(other.g) <= tolerance
+            && self.b.abs_diff(other.b) <= tolerance
+    }
+
+    /// Returns true if the Rec.601 luma of the gamma encoded channels, 0.299 * r + 0.587 * g + 0.114 * b, is below 192.
+    /// It is the same as `is_dark_with(LumaStandard::Rec601, 192.0)`, the threshold is higher than the 128 of most libraries,
+    /// so only the quite light colors are not dark.
     pub fn is_dark(&self) -> bool {
-        self.r as f32 * 0.299 + self.g as f32 * 0.587 + self.b as f32 * 0.114 < 192.0
+        self.is_dark_with(LumaStandard::Rec601, 192.0)
+    }
+
+    /// Returns true if the luma of the standard is below the threshold,
+    /// which is 0~255 for Rec601 and Rec709, and 0~1 for WcagRelativeLuminance
+    pub fn is_dark_with(&self, standard: LumaStandard, threshold: f32) -> bool {
+        standard.luma_of(*self) < threshold
     }
 
     pub fn is_light(&self) -> bool {
         !self.is_dark()
     }
 
+    /// Returns the Rec.601 luma(0~255) of the gamma encoded channels, 0.299 * r + 0.587 * g + 0.114 * b rounded,
+    /// the weighted sum `is_dark` compares
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (43,196,138).try_into().unwrap();
+    /// assert_eq!(rgb.luma(), 144);
+    /// ```
+    pub fn luma(&self) -> u8 {
+        LumaStandard::Rec601.luma_of(*self).round() as u8
+    }
+
+    /// Returns the euclidean distance of the channels(0~255), 0 for the same colors
+    /// and at most 441.67(255 * √3) for black and white
+    /// * other - any struct that impl into RGB
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let black:RGB = (0,0,0).try_into().unwrap();
+    /// let rgb:RGB = (3,4,0).try_into().unwrap();
+    /// assert_eq!(black.distance(rgb), 5.0);
+    /// ```
+    pub fn distance(&self, other: impl Into<RGB>) -> f32 {
+        let other: RGB = other.into();
+        let dr = self.r as f32 - other.r as f32;
+        let dg = self.g as f32 - other.g as f32;
+        let db = self.b as f32 - other.b as f32;
+        (dr * dr + dg * dg + db * db).sqrt()
+    }
+
+    /// Returns the "redmean" distance, the euclidean distance with the weights of red and blue depending on the mean red,
+    /// which is cheap and close to the perceived difference, 0 for the same colors and at most 764.83 for black and white
+    /// * other - any struct that impl into RGB
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let black:RGB = (0,0,0).try_into().unwrap();
+    /// let green:RGB = (0,10,0).try_into().unwrap();
+    /// assert_eq!(black.distance_redmean(green), 20.0);
+    /// ```
+    pub fn distance_redmean(&self, other: impl Into<RGB>) -> f32 {
+        let other: RGB = other.into();
+        redmean_distance_squared((self.r, self.g, self.b), (other.r, other.g, other.b)).sqrt()
+    }
+
+    /// Returns the color difference CIEDE2000 of the colors in CIE Lab(D50), a value about 1 is a just noticeable difference
+    /// * other - any struct that impl into RGB
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (43,196,138).try_into().unwrap();
+    /// assert_eq!(rgb.delta_e2000(rgb), 0.0);
+    /// assert!(rgb.delta_e2000(RGB::try_from((43,196,140)).unwrap()) < 1.0);
+    /// ```
+    pub fn delta_e2000(&self, other: impl Into<RGB>) -> f32 {
+        let other: RGB = other.into();
+        delta_e2000(
+            rgb_to_lab(self.r, self.g, self.b),
+            rgb_to_lab(other.r, other.g, other.b),
+        )
+    }
+
+    /// Returns the index, the color and the distance of the nearest color of the palette, None when the palette is empty.
+    /// The first color wins a tie, see `PaletteMatcher` for many lookups against the same palette.
+    /// * palette - the colors of any struct that impl into RGB
+    /// * metric:DistanceMetric - Redmean, WeightedRgb or DeltaE2000
+    /// ```rust
+    /// use easy_color::{DistanceMetric, Hex, RGB};
+    /// let rgb:RGB = (40,180,130).try_into().unwrap();
+    /// let palette = ["#000000", "#2BC48A", "#FFFFFF"].map(|s| Hex::try_from(s).unwrap());
+    /// let (idx, color, _) = rgb.nearest_in(&palette, DistanceMetric::Redmean).unwrap();
+    /// assert_eq!((idx, color.to_string()), (1, "rgb(43,196,138)".to_string()));
+    /// ```
+    pub fn nearest_in<C: Into<RGB> + Copy>(
+        &self,
+        palette: &[C],
+        metric: DistanceMetric,
+    ) -> Option<(usize, RGB, f32)> {
+        PaletteMatcher::new(palette, metric).nearest(*self)
+    }
+
+    /// Returns the relative luminance(0~1) of WCAG 2.x, the Rec.709 weighted sum of the linearized channels
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (255,255,255).try_into().unwrap();
+    /// assert_eq!(rgb.relative_luminance(), 1.0);
+    /// ```
+    pub fn relative_luminance(&self) -> f32 {
+        LinearRGB::from(*self).luminance()
+    }
+
+    /// Returns the contrast ratio(1~21) of WCAG 2.x between the colors, the order of the colors does not matter
+    /// * other - any struct that impl into RGB
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let black:RGB = (0,0,0).try_into().unwrap();
+    /// let white:RGB = (255,255,255).try_into().unwrap();
+    /// assert_eq!(black.contrast_ratio(white), 21.0);
+    /// assert_eq!(white.contrast_ratio(white), 1.0);
+    /// ```
+    pub fn contrast_ratio(&self, other: impl Into<RGB>) -> f32 {
+        let l1 = self.relative_luminance() as f64;
+        let l2 = other.into().relative_luminance() as f64;
+        // in f64, so black and white are 21 exactly
+        ((l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)) as f32
+    }
+
+    /// Darken or lighten the color by the smallest step that reaches the contrast ratio on the background,
+    /// the color is returned unchanged when it already does, see `EnsuredContrast`
+    /// * background - any struct that impl into RGB
+    /// * ratio:f32 - the target contrast ratio, like the 4.5 of WCAG AA
+    pub fn ensure_contrast(&self, background: impl Into<RGB>, ratio: f32) -> EnsuredContrast {
+        EnsuredContrast::solve(*self, background.into(), ratio)
+    }
+
+    /// Returns true if the contrast ratio of the color as text on the background meets the WCAG level for the text size
+    /// ```rust
+    /// use easy_color::{Hex, RGB, TextSize, WcagLevel};
+    /// let gray:RGB = Hex::try_from("#767676").unwrap().into();
+    /// let white = Hex::try_from("#FFFFFF").unwrap();
+    /// assert!(gray.is_readable_on(white, WcagLevel::AA, TextSize::Normal));
+    /// assert!(!gray.is_readable_on(white, WcagLevel::AAA, TextSize::Normal));
+    /// ```
+    pub fn is_readable_on(
+        &self,
+        background: impl Into<RGB>,
+        level: WcagLevel,
+        size: TextSize,
+    ) -> bool {
+        level.is_met_by(self.contrast_ratio(background), size)
+    }
+
+    /// Returns black or white, the one with the higher contrast ratio on the color, black when they are the same
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (43,196,138).try_into().unwrap();
+    /// assert_eq!(rgb.contrast_text().to_string(), "rgb(0,0,0)");
+    /// ```
+    pub fn contrast_text(&self) -> RGB {
+        self.contrast_text_custom(
+            RGB { r: 0, g: 0, b: 0 },
+            RGB {
+                r: 255,
+                g: 255,
+                b: 255,
+            },
+        )
+    }
+
+    /// Returns the dark or the light text color, the one with the higher contrast ratio on the color,
+    /// the dark one when they are the same
+    pub fn contrast_text_custom(&self, dark: impl Into<RGB>, light: impl Into<RGB>) -> RGB {
+        let (dark, light) = (dark.into(), light.into());
+        if self.contrast_ratio(dark) >= self.contrast_ratio(light) {
+            dark
+        } else {
+            light
+        }
+    }
+
+    /// Returns the CIE xy chromaticity coordinates of the color, relative to D65.
+    /// Black has no chromaticity, so the one of the white point is returned.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (0,0,0).try_into().unwrap();
+    /// assert_eq!(rgb.chromaticity(), (0.3127, 0.3290));
+    /// ```
+    pub fn chromaticity(&self) -> (f32, f32) {
+        let xyy: Xyy = (*self).into();
+        xyy.chromaticity()
+    }
+
+    /// Estimate the correlated color temperature of the color.
+    /// Returns None for black and for colors far from the Planckian locus, like green or purple.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (255,137,14).try_into().unwrap();
+    /// let kelvin = rgb.estimate_cct().unwrap();
+    /// assert!((1800..2200).contains(&kelvin.value()));
+    ///
+    /// let rgb:RGB = (0,255,0).try_into().unwrap();
+    /// assert_eq!(rgb.estimate_cct(), None);
+    /// ```
+    pub fn estimate_cct(&self) -> Option<Kelvin> {
+        let (x, y, z) = rgb_to_xyz(self.r, self.g, self.b);
+        let (u, v) = xyz_to_uv(x, y, z)?;
+        Kelvin::from_uv(u, v)
+    }
+
+    /// Returns the 24 bit escape sequence that sets the foreground color of a terminal, like "\x1b[38;2;43;196;138m"
+    pub fn to_ansi_fg(&self) -> String {
+        self.to_ansi_fg_with(AnsiMode::TrueColor)
+    }
+
+    /// Returns the 24 bit escape sequence that sets the background color of a terminal, like "\x1b[48;2;43;196;138m"
+    pub fn to_ansi_bg(&self) -> String {
+        self.to_ansi_bg_with(AnsiMode::TrueColor)
+    }
+
+    /// Returns the escape sequence of the nearest xterm 256 color for the foreground, like "\x1b[38;5;36m"
+    pub fn to_ansi256_fg(&self) -> String {
+        self.to_ansi_fg_with(AnsiMode::Ansi256)
+    }
+
+    /// Returns the escape sequence of the nearest xterm 256 color for the background, like "\x1b[48;5;36m"
+    pub fn to_ansi256_bg(&self) -> String {
+        self.to_ansi_bg_with(AnsiMode::Ansi256)
+    }
+
+    /// Returns the escape sequence that sets the foreground color in the encoding of the mode
+    pub fn to_ansi_fg_with(&self, mode: AnsiMode) -> String {
+        mode.escape(*self, false)
+    }
+
+    /// Returns the escape sequence that sets the background color in the encoding of the mode
+    pub fn to_ansi_bg_with(&self, mode: AnsiMode) -> String {
+        mode.escape(*self, true)
+    }
+
+    /// Wrap the text in the 24 bit foreground color and reset the colors after it.
+    /// ```rust
+    /// use easy_color::{AnsiMode, RGB};
+    /// let rgb:RGB = (43,196,138).try_into().unwrap();
+    /// assert_eq!(rgb.paint("ok"), "\x1b[38;2;43;196;138mok\x1b[0m");
+    /// assert_eq!(rgb.paint_with("ok", AnsiMode::Ansi16), "\x1b[36mok\x1b[0m");
+    /// ```
+    pub fn paint(&self, text: &str) -> String {
+        self.paint_with(text, AnsiMode::TrueColor)
+    }
+
+    /// Wrap the text in the foreground color encoded for the mode and reset the colors after it
+    pub fn paint_with(&self, text: &str, mode: AnsiMode) -> String {
+        format!("{}{}{}", self.to_ansi_fg_with(mode), text, ANSI_RESET)
+    }
+
+    /// Returns the CSS filter chain which recolors a black element, like an SVG icon, into this color,
+    /// searched with a fixed seed, see `CssFilter` for the accuracy.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (255,125,55).try_into().unwrap();
+    /// let filter = rgb.to_css_filter();
+    /// assert!(filter.delta_e() < 5.0);
+    /// // filter: invert(..%) sepia(..%) saturate(..%) hue-rotate(..deg) brightness(..%) contrast(..%);
+    /// let css = format!("filter: {};", filter);
+    /// ```
+    pub fn to_css_filter(&self) -> CssFilter {
+        self.to_css_filter_with_seed(0)
+    }
+
+    /// The same as `to_css_filter`, with the seed of the random search, another seed can find a better filter.
+    pub fn to_css_filter_with_seed(&self, seed: u64) -> CssFilter {
+        CssFilter::solve(*self, seed)
+    }
+
     pub fn random() -> Self {
         let r = rand::random::<u8>();
         let g = rand::random::<u8>();
@@ -0,0 +1,138 @@
+use crate::common::process_hex16;
+use crate::{ColorError, Hex, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// RGB16 is a RGB color with 16 bit channels, like the ones of 16 bit PNG and TIFF images.
+/// It can be parsed from a 12 digit hexadecimal string like "#FFFF80000000", from a tuple (r,g,b) or converted from RGB, RGBA and Hex.
+/// * r:u16 - red value(0~65535)
+/// * g:u16 - green value(0~65535)
+/// * b:u16 - blue value(0~65535)
+///
+/// Converting from 8 bit channels multiplies them by 257, so 255 becomes 65535,
+/// and converting back to 8 bit rounds to the nearest value.
+/// The conversions into HSL and HSV are computed from the 16 bit channels.
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, RGB16};
+/// let rgb16:RGB16 = "#FFFF80000000".try_into().unwrap();
+/// assert_eq!(rgb16.red(), 65535);
+/// assert_eq!(rgb16.to_string(), "#FFFF80000000");
+///
+/// let rgb:RGB = rgb16.into();
+/// assert_eq!(rgb.to_string(), "rgb(255,128,0)");
+/// let rgb16:RGB16 = rgb.into();
+/// assert_eq!(rgb16.to_string(), "#FFFF80800000");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct RGB16 {
+    pub(crate) r: u16,
+    pub(crate) g: u16,
+    pub(crate) b: u16,
+}
+
+impl TryFrom<&str> for RGB16 {
+    type Error = ColorError;
+    fn try_from(rgb16_str: &str) -> Result<Self, Self::Error> {
+        let color = rgb16_str.trim().trim_start_matches('#');
+        if let Some([r, g, b, _]) = Some(color)
+            .filter(|c| c.len() == 12)
+            .and_then(process_hex16)
+        {
+            return Ok(Self { r, g, b });
+        }
+        Err(ColorError::FormatErr {
+            input: rgb16_str.to_string(),
+            reason: format!("RGB16: {} format error!", rgb16_str),
+        })
+    }
+}
+
+impl FromStr for RGB16 {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(u16, u16, u16)> for RGB16 {
+    type Error = ColorError;
+    fn try_from(value: (u16, u16, u16)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            r: value.0,
+            g: value.1,
+            b: value.2,
+        })
+    }
+}
+
+impl From<Hex> for RGB16 {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for RGB16 {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        Self {
+            r: r as u16 * 257,
+            g: g as u16 * 257,
+            b: b as u16 * 257,
+        }
+    }
+}
+
+impl From<RGBA> for RGB16 {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for RGB16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:04X}{:04X}{:04X}", self.r, self.g, self.b)
+    }
+}
+
+impl RGB16 {
+    pub fn red(&self) -> u16 {
+        self.r
+    }
+    pub fn set_red(&mut self, red: u16) -> &mut Self {
+        self.r = red;
+        self
+    }
+    pub fn green(&self) -> u16 {
+        self.g
+    }
+    pub fn set_green(&mut self, green: u16) -> &mut Self {
+        self.g = green;
+        self
+    }
+    pub fn blue(&self) -> u16 {
+        self.b
+    }
+    pub fn set_blue(&mut self, blue: u16) -> &mut Self {
+        self.b = blue;
+        self
+    }
+
+    /// Returns the channels as fractions(0~1)
+    pub(crate) fn to_unit(self) -> (f32, f32, f32) {
+        (
+            self.r as f32 / 65535.0,
+            self.g as f32 / 65535.0,
+            self.b as f32 / 65535.0,
+        )
+    }
+
+    pub fn random() -> Self {
+        let r = rand::random::<u16>();
+        let g = rand::random::<u16>();
+        let b = rand::random::<u16>();
+        Self { r, g, b }
+    }
+}
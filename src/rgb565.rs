@@ -0,0 +1,84 @@
+use crate::{ColorError, Hex, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+
+/// RGB565 is a RGB color packed into 16 bits, 5 bits of red, 6 bits of green and 5 bits of blue, like embedded displays use.
+/// It can be created from a u16 or converted from RGB, RGBA and Hex.
+///
+/// Converting from RGB rounds every channel to the nearest value,
+/// converting back to RGB replicates the high bits into the low bits, so 0x1F becomes 255 and 0 becomes 0.
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, RGB565};
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// let rgb565:RGB565 = rgb.into();
+/// assert_eq!(rgb565.value(), 0xF800);
+/// assert_eq!(rgb565.to_be_bytes(), [0xF8, 0x00]);
+///
+/// let rgb565:RGB565 = 0x07E0.try_into().unwrap();
+/// let rgb:RGB = rgb565.into();
+/// assert_eq!(rgb.to_string(), "rgb(0,255,0)");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct RGB565(pub(crate) u16);
+
+impl TryFrom<u16> for RGB565 {
+    type Error = ColorError;
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Ok(Self(value))
+    }
+}
+
+impl From<Hex> for RGB565 {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for RGB565 {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let scale = |v: u8, max: u32| (v as u32 * max + 127) / 255;
+        let value = scale(r, 31) << 11 | scale(g, 63) << 5 | scale(b, 31);
+        Self(value as u16)
+    }
+}
+
+impl From<RGBA> for RGB565 {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for RGB565 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rgb565(0x{:04X})", self.0)
+    }
+}
+
+impl RGB565 {
+    /// Returns the packed value
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns the 5 bit red, 6 bit green and 5 bit blue fields
+    pub(crate) fn fields(self) -> (u8, u8, u8) {
+        (
+            (self.0 >> 11) as u8,
+            (self.0 >> 5 & 0x3F) as u8,
+            (self.0 & 0x1F) as u8,
+        )
+    }
+
+    /// Returns the packed value as big endian bytes
+    pub fn to_be_bytes(&self) -> [u8; 2] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the packed value as little endian bytes
+    pub fn to_le_bytes(&self) -> [u8; 2] {
+        self.0.to_le_bytes()
+    }
+}
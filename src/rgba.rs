@@ -1,13 +1,25 @@
-use crate::common::hsl_to_rgb;
-use crate::{ColorError, Hex, CMYK, HSL, HSLA, HSV, RGB};
-use std::fmt::{Display, Formatter};
+use crate::common::{
+    checked_channels, color_function_args, format_alpha, hsl_to_rgb, oklch_to_rgb,
+    parse_alpha_component, parse_color_function_args, parse_err, parse_rgb_channels,
+    split_color_args, unit_to_u8,
+};
+use crate::named::named_color;
+use crate::{
+    AdobeRGB, Ansi256, BlendMode, ByteOrder, Cam16, ColorBlindness, ColorError, Easing, Gray,
+    HSLuv, Hex, Kelvin, LinearRGB, OkLch, ParseOptions, ProPhotoRGB, RGBAf, RGBf, Rec2020, CMYK,
+    CMYKA, HSI, HSL, HSLA, HSV, HWB, LCH, LMS, P3, RGB, RGB16, RGB565, XYZ, YIQ,
+};
+use std::fmt::{Display, Formatter, LowerHex, UpperHex};
 use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
 
-/// RGBA can be parsed from a string in the format "rgba(r,g,b,a)" or from a tuple (r,g,b,a).
+/// RGBA can be parsed from a string in the format "rgba(r,g,b,a)", "rgb(r g b / a)" or from a tuple (r,g,b,a).
 /// * r:u8 - red value(0~255)
 /// * g:u8 - green value(0~255)
 /// * b:u8 - blue value(0~255)
 /// * a:f32 - alpha(0~1)
+///
+/// Like RGB, the CSS `color()` function is accepted, with an optional alpha like "color(srgb 0.17 0.77 0.54 / 0.5)".
 /// The red, green, and blue values can be individually set or retrieved.
 /// ### example
 /// ```rust
@@ -16,6 +28,15 @@ use std::ops::{Deref, DerefMut};
 /// rgba.set_alpha(0.5);
 /// assert_eq!(rgba.to_string(), "rgba(125,60,98,0.50)");
 ///
+/// let rgba:RGBA = "rgb(43 196 138 / 0.85)".try_into().unwrap();
+/// assert_eq!(rgba.to_string(), "rgba(43,196,138,0.85)");
+///
+/// // the alpha has 2 decimals, a precision changes that and `{:#}` drops the trailing zeros
+/// let rgba:RGBA = (43,196,138,0.333).try_into().unwrap();
+/// assert_eq!(format!("{:.3}", rgba), "rgba(43,196,138,0.333)");
+/// let rgba:RGBA = (43,196,138,1.0).try_into().unwrap();
+/// assert_eq!(format!("{:#}", rgba), "rgba(43,196,138,1)");
+///
 /// let rgba:RGBA = (125,60,240,0.5).try_into().unwrap();
 /// let hsl:HSL = rgba.into();
 /// assert_eq!(hsl.to_string(), "hsl(262,85%,79%)");
@@ -29,33 +50,14 @@ pub struct RGBA {
 impl TryFrom<&str> for RGBA {
     type Error = ColorError;
     fn try_from(rgba_str: &str) -> Result<Self, Self::Error> {
-        let mut color = rgba_str.trim().to_lowercase();
-        if color.starts_with("rgba(") && color.ends_with(')') {
-            let mut val = vec![];
-            let mut alpha = None;
-            color = color.replace("rgba(", "").replace(')', "");
-            let tmp = color.split(',').collect::<Vec<_>>();
-            if tmp.len() == 4 {
-                for (idx, s) in tmp.iter().enumerate() {
-                    if idx == 3 {
-                        alpha = s.trim().parse::<f32>().ok();
-                    } else if let Ok(v) = s.trim().parse::<u8>() {
-                        val.push(v);
-                    }
-                }
-            }
-            if val.len() != 3 || alpha.is_none() {
-                return Err(ColorError::FormatErr(format!(
-                    "RGBA:{} format error!",
-                    rgba_str
-                )));
-            }
-            return (val[0], val[1], val[2], alpha.unwrap()).try_into();
-        }
-        Err(ColorError::FormatErr(format!(
-            "RGBA:{} format error!",
-            rgba_str
-        )))
+        Self::parse_with(rgba_str, &ParseOptions::default())
+    }
+}
+
+impl FromStr for RGBA {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
     }
 }
 
@@ -63,10 +65,13 @@ impl TryFrom<(u8, u8, u8, f32)> for RGBA {
     type Error = ColorError;
     fn try_from(value: (u8, u8, u8, f32)) -> Result<Self, Self::Error> {
         if !(0.0..=1.0).contains(&value.3) {
-            Err(ColorError::ValueErr(format!(
-                "RGBA: the alpha value must between 0~1, but got {}.",
-                value.3
-            )))
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "RGBA: the alpha value must between 0~1, but got {}.",
+                    value.3
+                ),
+            })
         } else {
             let rgb = RGB {
                 r: value.0,
@@ -78,6 +83,30 @@ impl TryFrom<(u8, u8, u8, f32)> for RGBA {
     }
 }
 
+impl TryFrom<(u8, u8, u8, u8)> for RGBA {
+    type Error = ColorError;
+    /// Create from the bytes of a pixel, the alpha(0~255) is divided by 255 and `alpha_u8` returns the same byte.
+    fn try_from(value: (u8, u8, u8, u8)) -> Result<Self, Self::Error> {
+        Ok([value.0, value.1, value.2, value.3].into())
+    }
+}
+
+impl TryFrom<(f32, f32, f32, f32)> for RGBA {
+    type Error = ColorError;
+    /// Create from normalized channels(0~1) and alpha, the channels are rounded half up like `RGB` does.
+    fn try_from(value: (f32, f32, f32, f32)) -> Result<Self, Self::Error> {
+        let rgb = RGB::try_from((value.0, value.1, value.2)).map_err(|_| ColorError::ValueErr {
+            input: format!("{:?}", value),
+            reason: format!(
+                "RGBA: args ({},{},{},{}) value error, all value must between 0~1!",
+                value.0, value.1, value.2, value.3
+            ),
+        })?;
+        let RGB { r, g, b } = rgb;
+        (r, g, b, value.3).try_into()
+    }
+}
+
 impl From<Hex> for RGBA {
     fn from(hex: Hex) -> Self {
         let (r, g, b, a) = hex.rgba;
@@ -136,26 +165,395 @@ impl DerefMut for RGBA {
     }
 }
 
+impl From<HWB> for RGBA {
+    fn from(hwb: HWB) -> Self {
+        let rgb: RGB = hwb.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<LCH> for RGBA {
+    fn from(lch: LCH) -> Self {
+        let rgb: RGB = lch.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<OkLch> for RGBA {
+    fn from(oklch: OkLch) -> Self {
+        let OkLch { l, c, h, a } = oklch;
+        let (r, g, b) = oklch_to_rgb(l, c, h);
+        let rgb = RGB { r, g, b };
+        Self { rgb, a }
+    }
+}
+
+impl From<XYZ> for RGBA {
+    fn from(xyz: XYZ) -> Self {
+        let rgb: RGB = xyz.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<YIQ> for RGBA {
+    fn from(yiq: YIQ) -> Self {
+        let rgb: RGB = yiq.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<HSI> for RGBA {
+    fn from(hsi: HSI) -> Self {
+        let rgb: RGB = hsi.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<P3> for RGBA {
+    fn from(p3: P3) -> Self {
+        let rgb: RGB = p3.into();
+        rgb.into()
+    }
+}
+
+impl From<Rec2020> for RGBA {
+    fn from(rec2020: Rec2020) -> Self {
+        let rgb: RGB = rec2020.into();
+        rgb.into()
+    }
+}
+
+impl From<AdobeRGB> for RGBA {
+    fn from(adobe: AdobeRGB) -> Self {
+        let rgb: RGB = adobe.into();
+        rgb.into()
+    }
+}
+
+impl From<ProPhotoRGB> for RGBA {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let rgb: RGB = prophoto.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLuv> for RGBA {
+    fn from(hsluv: HSLuv) -> Self {
+        let rgb: RGB = hsluv.into();
+        rgb.into()
+    }
+}
+
+impl From<Cam16> for RGBA {
+    fn from(cam16: Cam16) -> Self {
+        let rgb: RGB = cam16.into();
+        rgb.into()
+    }
+}
+
+impl From<LMS> for RGBA {
+    fn from(lms: LMS) -> Self {
+        let rgb: RGB = lms.into();
+        rgb.into()
+    }
+}
+
+impl From<Gray> for RGBA {
+    fn from(gray: Gray) -> Self {
+        let rgb: RGB = gray.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB16> for RGBA {
+    fn from(rgb16: RGB16) -> Self {
+        let rgb: RGB = rgb16.into();
+        rgb.into()
+    }
+}
+
+impl From<RGBf> for RGBA {
+    fn from(rgbf: RGBf) -> Self {
+        let rgb: RGB = rgbf.into();
+        rgb.into()
+    }
+}
+
+impl From<RGBAf> for RGBA {
+    fn from(rgbaf: RGBAf) -> Self {
+        let RGBAf { rgb, a } = rgbaf;
+        Self { rgb: rgb.into(), a }
+    }
+}
+
+impl From<RGB565> for RGBA {
+    fn from(rgb565: RGB565) -> Self {
+        let rgb: RGB = rgb565.into();
+        rgb.into()
+    }
+}
+
+impl From<Ansi256> for RGBA {
+    fn from(ansi: Ansi256) -> Self {
+        let rgb: RGB = ansi.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYKA> for RGBA {
+    fn from(cmyka: CMYKA) -> Self {
+        let (r, g, b, a) = cmyka.to_rgba_tuple();
+        let rgb = RGB { r, g, b };
+        Self { rgb, a }
+    }
+}
+
+impl From<Kelvin> for RGBA {
+    fn from(kelvin: Kelvin) -> Self {
+        let rgb: RGB = kelvin.into();
+        rgb.into()
+    }
+}
+
+impl From<[u8; 4]> for RGBA {
+    /// The alpha is the last byte divided by 255, `to_array` returns the same byte
+    fn from(value: [u8; 4]) -> Self {
+        let [r, g, b, a] = value;
+        let rgb = RGB { r, g, b };
+        Self {
+            rgb,
+            a: a as f32 / 255.0,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for RGBA {
+    type Error = ColorError;
+    /// Create from a slice of 4 bytes, like a pixel of an RGBA image buffer
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 4] = value.try_into().map_err(|_| ColorError::ValueErr {
+            input: format!("{:?}", value),
+            reason: format!(
+                "RGBA: {:?} value error, the slice must have 4 bytes, found {}!",
+                value,
+                value.len()
+            ),
+        })?;
+        Ok(bytes.into())
+    }
+}
+
+impl From<RGBA> for [u8; 4] {
+    fn from(rgba: RGBA) -> Self {
+        rgba.to_array()
+    }
+}
+
+impl From<RGBA> for (u8, u8, u8, f32) {
+    fn from(rgba: RGBA) -> Self {
+        rgba.as_tuple()
+    }
+}
+
 impl Display for RGBA {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let RGB { r, g, b } = self.rgb;
-        write!(f, "rgba({},{},{},{:.2})", r, g, b, self.a)
+        write!(f, "rgba({},{},{},{})", r, g, b, format_alpha(f, self.a))
+    }
+}
+
+/// `{:x}` and `{:X}` write the 8 digits of 0xRRGGBBAA, the alpha is rounded to the nearest byte
+impl LowerHex for RGBA {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(true, "0x", &format!("{:08x}", self.to_u32()))
+    }
+}
+
+impl UpperHex for RGBA {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(true, "0x", &format!("{:08X}", self.to_u32()))
     }
 }
 
 impl RGBA {
+    /// Parse a string like `TryFrom<&str>` does, with the given options.
+    /// `TryFrom<&str>` is the same as this with `ParseOptions::default()`.
+    pub fn parse_with(rgba_str: &str, options: &ParseOptions) -> Result<Self, ColorError> {
+        let Some(color) = options.normalize(rgba_str) else {
+            return Err(ColorError::FormatErr {
+                input: rgba_str.to_string(),
+                reason: format!("'{}' format error, the syntax is not exact!", rgba_str),
+            });
+        };
+        if options.allow_named_colors && color.eq_ignore_ascii_case("transparent") {
+            return Ok(Self::TRANSPARENT);
+        }
+        if let Some((r, g, b)) = named_color(color).filter(|_| options.allow_named_colors) {
+            let rgb = RGB { r, g, b };
+            return Ok(Self { rgb, a: 1.0 });
+        }
+        if let Some(args) = color_function_args(color, &["rgb", "rgba"]) {
+            let (r, g, b, a) = split_color_args(args)
+                .and_then(|(tmp, alpha)| {
+                    let (r, g, b) = parse_rgb_channels(&tmp, options)?;
+                    Ok((r, g, b, parse_alpha_component(alpha, 3, options)?))
+                })
+                .map_err(|e| parse_err(rgba_str, e))?;
+            let rgb = RGB { r, g, b };
+            return Ok(Self { rgb, a });
+        }
+        if let Some(args) = color_function_args(color, &["color"]) {
+            let (rgb, a) = parse_color_function_args(args, options)
+                .and_then(|(space, channels, alpha)| {
+                    let a = parse_alpha_component(alpha, 3, options)?;
+                    Ok((RGB::from_color_space(space, channels), a))
+                })
+                .map_err(|e| parse_err(rgba_str, e))?;
+            return Ok(Self { rgb, a });
+        }
+        Err(ColorError::FormatErr {
+            input: rgba_str.to_string(),
+            reason: format!("RGBA:{} format error!", rgba_str),
+        })
+    }
+
+    /// Create from computed channels, a channel out of 0~255 is an error instead of wrapping like `as u8`,
+    /// and the alpha must be between 0~1.
+    /// It is not a `TryFrom<(i32, i32, i32, f32)>`, since a second tuple impl would make `(255, 0, 0, 0.5).try_into()` ambiguous.
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let rgba = RGBA::new_checked(43, 196, 138, 0.5).unwrap();
+    /// assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+    /// assert!(RGBA::new_checked(300, 196, 138, 0.5).is_err());
+    /// ```
+    pub fn new_checked(red: i32, green: i32, blue: i32, alpha: f32) -> Result<Self, ColorError> {
+        let (r, g, b) = checked_channels("RGBA", (red, green, blue))?;
+        (r, g, b, alpha).try_into()
+    }
+
+    /// Create from a packed 0xRRGGBBAA value, the alpha is the lowest byte divided by 255.
+    /// Use `RGB::from_u32` for a 0xRRGGBB value, and `from_argb_u32` when the alpha is the highest byte.
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let rgba = RGBA::from_u32(0x2BC48AFF);
+    /// assert_eq!(rgba.to_string(), "rgba(43,196,138,1.00)");
+    /// assert_eq!(rgba.to_u32(), 0x2BC48AFF);
+    /// ```
+    pub fn from_u32(value: u32) -> Self {
+        Self::from_u32_with(value, ByteOrder::Rgba)
+    }
+
+    /// Create from a packed 0xAARRGGBB value, like Android and .NET colors.
+    pub fn from_argb_u32(value: u32) -> Self {
+        Self::from_u32_with(value, ByteOrder::Argb)
+    }
+
+    /// Returns the packed 0xRRGGBBAA value, the alpha is rounded to the nearest byte.
+    pub fn to_u32(&self) -> u32 {
+        self.to_u32_with(ByteOrder::Rgba)
+    }
+
+    /// Returns the packed 0xAARRGGBB value.
+    pub fn to_argb_u32(&self) -> u32 {
+        self.to_u32_with(ByteOrder::Argb)
+    }
+
+    /// Create from a packed value in the given byte order, the alpha is 1 for the orders without alpha.
+    pub fn from_u32_with(value: u32, order: ByteOrder) -> Self {
+        order.unpack(value).into()
+    }
+
+    /// Returns the packed value in the given byte order, the alpha is rounded to the nearest byte
+    /// and dropped for the orders without alpha.
+    pub fn to_u32_with(&self, order: ByteOrder) -> u32 {
+        order.pack(self.to_array())
+    }
+
+    /// Returns (r, g, b, a), `try_from` of the tuple returns the same color.
+    pub fn as_tuple(&self) -> (u8, u8, u8, f32) {
+        let RGB { r, g, b } = self.rgb;
+        (r, g, b, self.a)
+    }
+
+    /// Returns [r, g, b, a], the alpha is rounded to the nearest byte.
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let pixels = [43u8, 196, 138, 255, 0, 0, 255, 128];
+    /// let colors = pixels
+    ///     .chunks_exact(4)
+    ///     .map(|px| RGBA::try_from(px).unwrap())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(colors[1].to_string(), "rgba(0,0,255,0.50)");
+    /// assert_eq!(colors[1].to_array(), [0, 0, 255, 128]);
+    /// ```
+    pub fn to_array(&self) -> [u8; 4] {
+        let RGB { r, g, b } = self.rgb;
+        [r, g, b, self.alpha_u8()]
+    }
+
+    /// Returns the normalized channels(0~1) and the alpha.
+    pub fn to_f32_tuple(&self) -> (f32, f32, f32, f32) {
+        let (r, g, b) = self.rgb.to_f32_tuple();
+        (r, g, b, self.a)
+    }
+
+    /// Returns a string with the channels as percentages and the alpha, like "rgba(16.9%, 76.9%, 54.1%, 0.50)",
+    /// the precision is the number of decimal places of the channels.
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (43,196,138,0.5).try_into().unwrap();
+    /// assert_eq!(rgba.to_percentage_string(1), "rgba(16.9%, 76.9%, 54.1%, 0.50)");
+    /// ```
+    pub fn to_percentage_string(&self, precision: usize) -> String {
+        let (r, g, b) = self.rgb.to_percentage_tuple();
+        format!(
+            "rgba({:.*}%, {:.*}%, {:.*}%, {:.2})",
+            precision, r, precision, g, precision, b, self.a
+        )
+    }
+
+    /// The fully transparent black, which is what the CSS "transparent" keyword means
+    pub const TRANSPARENT: Self = Self {
+        rgb: RGB { r: 0, g: 0, b: 0 },
+        a: 0.0,
+    };
+
     pub fn alpha(&self) -> f32 {
         self.a
     }
+    /// Returns the alpha as a byte(0~255), rounded to the nearest value.
+    /// ```rust
+    /// use easy_color::{Hex, RGBA};
+    /// let rgba:RGBA = (43, 196, 138, 128).try_into().unwrap();
+    /// assert_eq!(rgba.alpha_u8(), 128);
+    /// assert_eq!(Hex::from(rgba).to_string(), "#2BC48A80");
+    /// ```
+    pub fn alpha_u8(&self) -> u8 {
+        unit_to_u8(self.a)
+    }
+
     pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
-        self.a = alpha.max(0.0).min(1.0);
+        self.a = alpha.clamp(0.0, 1.0);
         self
     }
 
+    /// Returns true if no channel differs by more than the tolerance and the alpha by more than the alpha epsilon
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (43,196,138,0.5).try_into().unwrap();
+    /// let other:RGBA = (44,195,138,0.505).try_into().unwrap();
+    /// assert!(rgba.approx_eq(other, 1, 0.01));
+    /// assert!(!rgba.approx_eq(other, 0, 0.01));
+    /// ```
+    pub fn approx_eq(&self, other: impl Into<Self>, tolerance: u8, alpha_epsilon: f32) -> bool {
+        let other: RGBA = other.into();
+        self.rgb.approx_eq(other.rgb, tolerance) && (self.a - other.a).abs() <= alpha_epsilon
+    }
+
     /// mix color
     /// ### Arguments
     /// * other - any struct that impl into RGBA
-    /// * weight: Option<f32> the mixed color`s weight
+    /// * weight: `Option<f32>` the mixed color`s weight
     /// ### Example
     /// ```rust
     /// use easy_color::{HSL, RGBA, ColorMix};
@@ -169,22 +567,183 @@ impl RGBA {
     pub fn mix(&self, other: impl Into<Self>, weight: Option<f32>) -> Self {
         let rgba: RGBA = other.into();
         let p = weight.unwrap_or(0.5);
-        let w = 2.0 * p - 1.0;
-        let a = rgba.a - self.a;
-        let w1 = if w * a == -1.0 {
-            (w + 1.0) / 2.0
-        } else {
-            ((w + a) / (1.0 + w * a) + 1.0) / 2.0
-        };
+        let w1 = self.mix_weight(&rgba, p);
         let w2 = 1.0 - w1;
         let r = (w1 * rgba.r as f32 + w2 * self.r as f32) as u8;
         let g = (w1 * rgba.g as f32 + w2 * self.g as f32) as u8;
         let b = (w1 * rgba.b as f32 + w2 * self.b as f32) as u8;
         let a = rgba.a * p + self.a * (1.0 - p);
-        let rgb: RGB = (r, g, b).try_into().unwrap();
+        let rgb = RGB { r, g, b };
+        Self { rgb, a }
+    }
+
+    /// mix color in linear light, the midpoints are brighter and more natural than `mix`,
+    /// which mixes the gamma encoded values.
+    /// ### Arguments
+    /// * other - any struct that impl into RGBA
+    /// * weight: `Option<f32>` the mixed color`s weight
+    /// ### Example
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let red:RGBA = (255,0,0,1.0).try_into().unwrap();
+    /// let green:RGBA = (0,255,0,1.0).try_into().unwrap();
+    /// assert_eq!(red.mix(green, None).to_string(), "rgba(127,127,0,1.00)");
+    /// assert_eq!(red.mix_linear(green, None).to_string(), "rgba(188,188,0,1.00)");
+    /// ```
+    pub fn mix_linear(&self, other: impl Into<Self>, weight: Option<f32>) -> Self {
+        let rgba: RGBA = other.into();
+        let p = weight.unwrap_or(0.5);
+        let w1 = self.mix_weight(&rgba, p);
+        let start: LinearRGB = self.rgb.into();
+        let rgb: RGB = start.lerp(rgba.rgb, w1).into();
+        let a = rgba.a * p + self.a * (1.0 - p);
+        Self { rgb, a }
+    }
+
+    /// Linear interpolation of every channel and the alpha, without the alpha weighting of `mix`,
+    /// so 0 returns the color and 1 returns the other color exactly.
+    /// * other - any struct that impl into RGBA
+    /// * t:f32 - the value is clamped between 0~1, NaN is 0
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (43,196,138,1.0).try_into().unwrap();
+    /// let other:RGBA = (255,125,55,0.0).try_into().unwrap();
+    /// assert_eq!(rgba.lerp(other, 0.25).to_string(), "rgba(96,178,117,0.75)");
+    /// assert_eq!(rgba.lerp(other, 1.0), other);
+    /// ```
+    pub fn lerp(&self, other: impl Into<Self>, t: f32) -> Self {
+        let other: RGBA = other.into();
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let channel =
+            |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * t).round() as u8;
+        let rgb = RGB {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+        };
+        let a = self.a * (1.0 - t) + other.a * t;
+        Self { rgb, a }
+    }
+
+    /// `lerp` with t remapped by the easing curve
+    /// ```rust
+    /// use easy_color::{Easing, RGBA};
+    /// let black:RGBA = (0,0,0,1.0).try_into().unwrap();
+    /// let white:RGBA = (255,255,255,1.0).try_into().unwrap();
+    /// assert_eq!(black.lerp_eased(white, 0.5, Easing::EaseInOut), black.lerp(white, 0.5));
+    /// assert_eq!(black.lerp_eased(white, 0.25, Easing::EaseIn).to_string(), "rgba(24,24,24,1.00)");
+    /// ```
+    pub fn lerp_eased(&self, other: impl Into<Self>, t: f32, easing: Easing) -> Self {
+        self.lerp(other, easing.ease(t))
+    }
+
+    /// Blend the color onto the backdrop with a separable blend mode of the CSS Compositing spec,
+    /// the blended color is composited onto the backdrop with the alpha of the color(source-over).
+    /// * backdrop - any struct that impl into RGBA
+    /// * mode:BlendMode - Multiply, Screen, Overlay and so on
+    /// ```rust
+    /// use easy_color::{BlendMode, RGBA};
+    /// let source:RGBA = (43,196,138,1.0).try_into().unwrap();
+    /// let backdrop:RGBA = (255,125,55,1.0).try_into().unwrap();
+    /// assert_eq!(source.blend(backdrop, BlendMode::Multiply).to_string(), "rgba(43,96,30,1.00)");
+    /// let mut half = source;
+    /// half.set_alpha(0.5);
+    /// assert_eq!(half.blend(backdrop, BlendMode::Difference).to_string(), "rgba(234,98,69,1.00)");
+    /// ```
+    pub fn blend(&self, backdrop: impl Into<Self>, mode: BlendMode) -> Self {
+        let backdrop: RGBA = backdrop.into();
+        let (source_a, backdrop_a) = (self.a, backdrop.a);
+        let a = source_a + backdrop_a * (1.0 - source_a);
+        if a == 0.0 {
+            return Self::default();
+        }
+        // nothing shows through, or there is nothing to show, saves the rounding
+        if mode == BlendMode::Normal && (source_a == 1.0 || backdrop_a == 0.0) {
+            return *self;
+        }
+        if source_a == 0.0 {
+            return backdrop;
+        }
+        let channel = |cb: u8, cs: u8| {
+            let (cb, cs) = (cb as f32 / 255.0, cs as f32 / 255.0);
+            let blended = (1.0 - backdrop_a) * cs + backdrop_a * mode.apply(cb, cs);
+            unit_to_u8((source_a * blended + backdrop_a * cb * (1.0 - source_a)) / a)
+        };
+        let rgb = RGB {
+            r: channel(backdrop.r, self.r),
+            g: channel(backdrop.g, self.g),
+            b: channel(backdrop.b, self.b),
+        };
         Self { rgb, a }
     }
 
+    /// Composite the color over the backdrop, the source-over operator of Porter-Duff.
+    /// Unlike `mix` the colors are not averaged, the backdrop shows through by the transparency of the color,
+    /// a transparent color returns the backdrop and an opaque color returns the color.
+    /// * backdrop - any struct that impl into RGBA
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let fg:RGBA = (43,196,138,0.5).try_into().unwrap();
+    /// let bg:RGBA = (255,125,55,0.5).try_into().unwrap();
+    /// assert_eq!(fg.over(bg).to_string(), "rgba(114,172,110,0.75)");
+    /// ```
+    pub fn over(&self, backdrop: impl Into<Self>) -> Self {
+        self.blend(backdrop, BlendMode::Normal)
+    }
+
+    /// `over` in linear light, the edges of a translucent color look like they do in a physically based renderer
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let fg:RGBA = (255,0,0,0.5).try_into().unwrap();
+    /// let bg:RGBA = (0,255,0,1.0).try_into().unwrap();
+    /// assert_eq!(fg.over(bg).to_string(), "rgba(128,128,0,1.00)");
+    /// assert_eq!(fg.over_linear(bg).to_string(), "rgba(188,188,0,1.00)");
+    /// ```
+    pub fn over_linear(&self, backdrop: impl Into<Self>) -> Self {
+        let backdrop: RGBA = backdrop.into();
+        let a = self.a + backdrop.a * (1.0 - self.a);
+        if self.a == 1.0 || backdrop.a == 0.0 {
+            return *self;
+        }
+        if self.a == 0.0 {
+            return backdrop;
+        }
+        let source: LinearRGB = self.rgb.into();
+        let back: LinearRGB = backdrop.rgb.into();
+        let channel = |cs: f32, cb: f32| (self.a * cs + backdrop.a * cb * (1.0 - self.a)) / a;
+        let rgb: RGB = LinearRGB {
+            r: channel(source.r, back.r),
+            g: channel(source.g, back.g),
+            b: channel(source.b, back.b),
+        }
+        .into();
+        Self { rgb, a }
+    }
+
+    /// Composite the color over the opaque background, like the color looks on it.
+    /// `From<RGBA> for RGB` composites onto white, this works with any background, like the one of a dark mode.
+    /// * background - any struct that impl into RGB
+    /// ```rust
+    /// use easy_color::{Hex, RGBA};
+    /// let rgba:RGBA = (43,196,138,0.4).try_into().unwrap();
+    /// assert_eq!(rgba.flatten_onto(Hex::try_from("#121212").unwrap()).to_string(), "rgb(28,89,66)");
+    /// ```
+    pub fn flatten_onto(&self, background: impl Into<RGB>) -> RGB {
+        let rgb: RGB = background.into();
+        self.over(RGBA { rgb, a: 1.0 }).rgb
+    }
+
+    /// Returns the weight of the other color when mixing, taking the alpha of both colors into account.
+    fn mix_weight(&self, other: &RGBA, p: f32) -> f32 {
+        let w = 2.0 * p - 1.0;
+        let a = other.a - self.a;
+        if w * a == -1.0 {
+            (w + 1.0) / 2.0
+        } else {
+            ((w + a) / (1.0 + w * a) + 1.0) / 2.0
+        }
+    }
+
     /// fade color
     /// * ratio:f32 - the ratio of fading, a value between 0.0 and 1.0
     ///
@@ -197,7 +756,7 @@ impl RGBA {
     /// assert_eq!(rgba.to_string(), "rgba(255,255,255,0.40)");
     /// ```
     pub fn fade(&mut self, ratio: f32) -> &mut Self {
-        self.a = (self.a - self.a * ratio).max(0.0).min(1.0);
+        self.a = (self.a - self.a * ratio).clamp(0.0, 1.0);
         self
     }
 
@@ -212,7 +771,7 @@ impl RGBA {
     /// assert_eq!(rgba.to_string(), "rgba(255,255,255,0.96)");
     /// ```
     pub fn opaquer(&mut self, ratio: f32) -> &mut Self {
-        self.a = (self.a + self.a * ratio).max(0.0).min(1.0);
+        self.a = (self.a + self.a * ratio).clamp(0.0, 1.0);
         self
     }
 
@@ -225,7 +784,107 @@ impl RGBA {
     /// ```
     pub fn grayscale(&self) -> Self {
         let v = (self.r as f32 * 0.3 + self.g as f32 * 0.59 + self.b as f32 * 0.11) as u8;
-        (v, v, v, self.a).try_into().unwrap()
+        Self {
+            rgb: RGB { r: v, g: v, b: v },
+            a: self.a,
+        }
+    }
+
+    /// Returns the grayscale mode of the color, keeping the relative luminance of the color in linear light.
+    /// ``` rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (95,45,155,0.8).try_into().unwrap();
+    /// let gray = rgba.grayscale_linear();
+    /// assert_eq!(gray.to_string(), "rgba(73,73,73,0.80)");
+    /// ```
+    pub fn grayscale_linear(&self) -> Self {
+        let linear: LinearRGB = self.rgb.into();
+        let v = linear.luminance();
+        let rgb: RGB = LinearRGB { r: v, g: v, b: v }.into();
+        Self { rgb, a: self.a }
+    }
+
+    /// Returns the color filtered like `filter: sepia(amount)` of CSS, the alpha is kept
+    /// * amount:f32 - 0.0 returns the color and 1.0 is full sepia, the value is clamped between 0~1, NaN is 0
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (255,0,0,0.8).try_into().unwrap();
+    /// assert_eq!(rgba.sepia(1.0).to_string(), "rgba(100,89,69,0.80)");
+    /// ```
+    pub fn sepia(&self, amount: f32) -> Self {
+        let amount = if amount.is_nan() {
+            0.0
+        } else {
+            amount.clamp(0.0, 1.0)
+        };
+        let v = 1.0 - amount;
+        let m = [
+            [0.393 + 0.607 * v, 0.769 - 0.769 * v, 0.189 - 0.189 * v],
+            [0.349 - 0.349 * v, 0.686 + 0.314 * v, 0.168 - 0.168 * v],
+            [0.272 - 0.272 * v, 0.534 - 0.534 * v, 0.131 + 0.869 * v],
+        ];
+        let (r, g, b) = (self.r as f32, self.g as f32, self.b as f32);
+        let [r, g, b] = m.map(|row| unit_to_u8((row[0] * r + row[1] * g + row[2] * b) / 255.0));
+        Self {
+            rgb: RGB { r, g, b },
+            a: self.a,
+        }
+    }
+
+    /// Returns the color as it is seen with the color vision deficiency, the alpha is kept, see `ColorBlindness`
+    /// ```rust
+    /// use easy_color::{ColorBlindness, RGBA};
+    /// let rgba:RGBA = (43,196,138,0.8).try_into().unwrap();
+    /// assert_eq!(rgba.simulate(ColorBlindness::Deuteranopia).to_string(), "rgba(175,168,142,0.80)");
+    /// ```
+    pub fn simulate(&self, deficiency: ColorBlindness) -> Self {
+        let linear: LinearRGB = self.rgb.into();
+        let rgb: RGB = deficiency.apply(linear).into();
+        Self { rgb, a: self.a }
+    }
+
+    /// Mix the color with white, like `tint()` of Sass
+    /// * weight:f32 - the weight of white, a value between 0.0 and 1.0, 0.0 returns the color and 1.0 returns white
+    ///
+    /// The white has the alpha of the color, so the alpha is kept instead of moving towards 1.0.
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (43,196,138,0.8).try_into().unwrap();
+    /// assert_eq!(rgba.tint(0.5).to_string(), "rgba(149,225,196,0.80)");
+    /// assert_eq!(rgba.tint(1.0).to_string(), "rgba(255,255,255,0.80)");
+    /// ```
+    pub fn tint(&self, weight: f32) -> Self {
+        self.mix_keeping_alpha(
+            RGB {
+                r: 255,
+                g: 255,
+                b: 255,
+            },
+            weight,
+        )
+    }
+
+    /// Mix the color with black, like `shade()` of Sass, the alpha is kept like `tint`
+    /// * weight:f32 - the weight of black, a value between 0.0 and 1.0, 0.0 returns the color and 1.0 returns black
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (43,196,138,0.8).try_into().unwrap();
+    /// assert_eq!(rgba.shade(0.5).to_string(), "rgba(21,98,69,0.80)");
+    /// ```
+    pub fn shade(&self, weight: f32) -> Self {
+        self.mix_keeping_alpha(RGB { r: 0, g: 0, b: 0 }, weight)
+    }
+
+    /// `mix` with a color of the same alpha, the weight is clamped and NaN is 0
+    fn mix_keeping_alpha(&self, rgb: RGB, weight: f32) -> Self {
+        let weight = if weight.is_nan() {
+            0.0
+        } else {
+            weight.clamp(0.0, 1.0)
+        };
+        let mut mixed = self.mix(RGBA { rgb, a: self.a }, Some(weight));
+        mixed.a = self.a;
+        mixed
     }
 
     /// Invert color
@@ -244,7 +903,7 @@ impl RGBA {
         r = 255 - r;
         g = 255 - g;
         b = 255 - b;
-        let rgb: RGB = (r, g, b).try_into().unwrap();
+        let rgb = RGB { r, g, b };
         Self { rgb, a: self.a }
     }
 
@@ -0,0 +1,121 @@
+use crate::common::format_alpha;
+use crate::{ColorError, Hex, RGBf, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+use std::ops::{Deref, DerefMut};
+
+/// RGBAf is a RGBf color with an alpha channel, like the `vec4` colors of shaders.
+/// It can be created from a tuple (r,g,b,a) or converted from RGB, RGBA, RGBf and Hex.
+/// * r:f32 - red value(0~1)
+/// * g:f32 - green value(0~1)
+/// * b:f32 - blue value(0~1)
+/// * a:f32 - alpha(0~1)
+///
+/// Values out of 0~1 are rejected, and the alpha is kept when converted to and from RGBA.
+/// ### example
+/// ```rust
+/// use easy_color::{RGBA, RGBAf};
+/// let rgba:RGBA = (255,128,0,0.5).try_into().unwrap();
+/// let rgbaf:RGBAf = rgba.into();
+/// assert_eq!(rgbaf.to_string(), "color(srgb 1.0000 0.5020 0.0000 / 0.50)");
+/// assert_eq!(rgbaf.to_array(), [1.0, 128.0 / 255.0, 0.0, 0.5]);
+/// assert_eq!(RGBA::from(rgbaf), rgba);
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct RGBAf {
+    pub(crate) rgb: RGBf,
+    pub(crate) a: f32,
+}
+
+impl TryFrom<(f32, f32, f32, f32)> for RGBAf {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.3) {
+            return Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "RGBAf: the alpha value must between 0~1, but got {}.",
+                    value.3
+                ),
+            });
+        }
+        let rgb: RGBf = (value.0, value.1, value.2).try_into()?;
+        Ok(Self { rgb, a: value.3 })
+    }
+}
+
+impl From<Hex> for RGBAf {
+    fn from(hex: Hex) -> Self {
+        let rgba: RGBA = hex.into();
+        rgba.into()
+    }
+}
+
+impl From<RGB> for RGBAf {
+    fn from(rgb: RGB) -> Self {
+        Self {
+            rgb: rgb.into(),
+            a: 1.0,
+        }
+    }
+}
+
+impl From<RGBA> for RGBAf {
+    fn from(rgba: RGBA) -> Self {
+        let RGBA { rgb, a } = rgba;
+        Self { rgb: rgb.into(), a }
+    }
+}
+
+impl From<RGBf> for RGBAf {
+    fn from(rgb: RGBf) -> Self {
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl Deref for RGBAf {
+    type Target = RGBf;
+    fn deref(&self) -> &Self::Target {
+        &self.rgb
+    }
+}
+
+impl DerefMut for RGBAf {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.rgb
+    }
+}
+
+impl Display for RGBAf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let RGBf { r, g, b } = self.rgb;
+        let a = format_alpha(f, self.a);
+        write!(f, "color(srgb {:.4} {:.4} {:.4} / {})", r, g, b, a)
+    }
+}
+
+impl RGBAf {
+    pub fn alpha(&self) -> f32 {
+        self.a
+    }
+    pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
+        self.a = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Linear interpolation between two colors, including the alpha.
+    /// * other - any struct that impl into RGBAf
+    /// * t:f32 - 0 returns self, 1 returns other, the value is clamped between 0~1, NaN is 0
+    pub fn lerp(&self, other: impl Into<Self>, t: f32) -> Self {
+        let other: RGBAf = other.into();
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        Self {
+            rgb: self.rgb.lerp(other.rgb, t),
+            a: self.a * (1.0 - t) + other.a * t,
+        }
+    }
+
+    /// Returns the channels as an array, ready to be uploaded to the GPU.
+    pub fn to_array(&self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
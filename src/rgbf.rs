@@ -0,0 +1,158 @@
+use crate::common::parse_color_space;
+use crate::{ColorError, Hex, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// RGBf is a RGB color with normalized f32 channels, like the colors of shaders and GPU code.
+/// It can be parsed from a string in the CSS format "color(srgb r g b)", from a tuple (r,g,b) or converted from RGB, RGBA and Hex.
+/// * r:f32 - red value(0~1)
+/// * g:f32 - green value(0~1)
+/// * b:f32 - blue value(0~1)
+///
+/// The channels are gamma encoded like RGB, use `LinearRGB` for linear light.
+/// Values out of 0~1, like HDR colors, are rejected, and the arithmetic methods clamp their results into 0~1.
+/// Converting into RGB rounds to the nearest value.
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, RGBf};
+/// let rgb:RGB = (255,128,0).try_into().unwrap();
+/// let rgbf:RGBf = rgb.into();
+/// assert_eq!(rgbf.to_string(), "color(srgb 1.0000 0.5020 0.0000)");
+///
+/// let black:RGBf = (0.0, 0.0, 0.0).try_into().unwrap();
+/// let rgb:RGB = black.lerp(rgbf, 0.5).into();
+/// assert_eq!(rgb.to_string(), "rgb(128,64,0)");
+///
+/// assert!(RGBf::try_from((1.5, 0.0, 0.0)).is_err());
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct RGBf {
+    pub(crate) r: f32,
+    pub(crate) g: f32,
+    pub(crate) b: f32,
+}
+
+impl TryFrom<&str> for RGBf {
+    type Error = ColorError;
+    fn try_from(rgbf_str: &str) -> Result<Self, Self::Error> {
+        parse_color_space(rgbf_str, "srgb", "RGBf")?.try_into()
+    }
+}
+
+impl FromStr for RGBf {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for RGBf {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(0.0..=1.0).contains(&value.1)
+            || !(0.0..=1.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "RGBf: args ({},{},{}) value error, all value must between 0~1!",
+                    value.0, value.1, value.2
+                ),
+            })
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for RGBf {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for RGBf {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        Self {
+            r: r as f32 / 255.0,
+            g: g as f32 / 255.0,
+            b: b as f32 / 255.0,
+        }
+    }
+}
+
+impl From<RGBA> for RGBf {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for RGBf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "color(srgb {:.4} {:.4} {:.4})", self.r, self.g, self.b)
+    }
+}
+
+impl RGBf {
+    pub fn red(&self) -> f32 {
+        self.r
+    }
+    pub fn set_red(&mut self, red: f32) -> &mut Self {
+        self.r = red.clamp(0.0, 1.0);
+        self
+    }
+    pub fn green(&self) -> f32 {
+        self.g
+    }
+    pub fn set_green(&mut self, green: f32) -> &mut Self {
+        self.g = green.clamp(0.0, 1.0);
+        self
+    }
+    pub fn blue(&self) -> f32 {
+        self.b
+    }
+    pub fn set_blue(&mut self, blue: f32) -> &mut Self {
+        self.b = blue.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Linear interpolation between two colors.
+    /// * other - any struct that impl into RGBf
+    /// * t:f32 - 0 returns self, 1 returns other, the value is clamped between 0~1, NaN is 0
+    pub fn lerp(&self, other: impl Into<Self>, t: f32) -> Self {
+        let other: RGBf = other.into();
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        Self {
+            r: self.r * (1.0 - t) + other.r * t,
+            g: self.g * (1.0 - t) + other.g * t,
+            b: self.b * (1.0 - t) + other.b * t,
+        }
+    }
+
+    /// Multiply every channel by the factor, the result is clamped between 0~1.
+    /// ```rust
+    /// use easy_color::RGBf;
+    /// let rgbf:RGBf = (0.2, 0.4, 0.8).try_into().unwrap();
+    /// assert_eq!(rgbf.scale(1.5).to_string(), "color(srgb 0.3000 0.6000 1.0000)");
+    /// ```
+    pub fn scale(&self, factor: f32) -> Self {
+        Self {
+            r: (self.r * factor).clamp(0.0, 1.0),
+            g: (self.g * factor).clamp(0.0, 1.0),
+            b: (self.b * factor).clamp(0.0, 1.0),
+        }
+    }
+
+    /// Returns the channels as an array, ready to be uploaded to the GPU.
+    pub fn to_array(&self) -> [f32; 3] {
+        [self.r, self.g, self.b]
+    }
+}
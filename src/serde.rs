@@ -0,0 +1,177 @@
+//! The wire formats for `#[serde(with = "...")]`, when a field should not use the `to_string()` of the color.
+//! * hex_string - "#2BC48A", or "#2BC48A80" when the color is transparent
+//! * rgb_struct - {"r":43,"g":196,"b":138}, with an "a" when the color is transparent
+//! * packed_u32 - 2868362, the 0xRRGGBB of RGB, or the 0xRRGGBBAA of RGBA and Hex
+//!
+//! hex_string and rgb_struct work with every type that converts from and into Hex and RGBA,
+//! the alpha is dropped when it is read into RGB.
+//! ### example
+//! ```rust
+//! use easy_color::{Hex, RGB, RGBA};
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Theme {
+//!     #[serde(with = "easy_color::serde::hex_string")]
+//!     primary: RGB,
+//!     #[serde(with = "easy_color::serde::rgb_struct")]
+//!     overlay: RGBA,
+//!     #[serde(with = "easy_color::serde::packed_u32")]
+//!     text: RGB,
+//! }
+//! let json = r##"{"primary":"#2BC48A","overlay":{"r":0,"g":0,"b":0,"a":0.5},"text":2868362}"##;
+//! let theme: Theme = serde_json::from_str(json).unwrap();
+//! assert_eq!(theme.primary.to_string(), "rgb(43,196,138)");
+//! assert_eq!(theme.overlay.to_string(), "rgba(0,0,0,0.50)");
+//! assert_eq!(serde_json::to_string(&theme).unwrap(), json);
+//! ```
+use crate::{Hex, RGB, RGBA};
+
+/// The types `packed_u32` reads and writes, RGB is packed without the alpha byte
+pub trait PackedU32: Sized {
+    fn to_packed_u32(&self) -> u32;
+    fn from_packed_u32(value: u32) -> Self;
+}
+
+impl PackedU32 for RGB {
+    fn to_packed_u32(&self) -> u32 {
+        self.to_u32()
+    }
+
+    fn from_packed_u32(value: u32) -> Self {
+        Self::from_u32(value)
+    }
+}
+
+impl PackedU32 for RGBA {
+    fn to_packed_u32(&self) -> u32 {
+        self.to_u32()
+    }
+
+    fn from_packed_u32(value: u32) -> Self {
+        Self::from_u32(value)
+    }
+}
+
+impl PackedU32 for Hex {
+    fn to_packed_u32(&self) -> u32 {
+        RGBA::from(*self).to_u32()
+    }
+
+    fn from_packed_u32(value: u32) -> Self {
+        RGBA::from_u32(value).into()
+    }
+}
+
+/// "#2BC48A", deserialized from any string `Hex` accepts
+pub mod hex_string {
+    use crate::serde_impl::ColorVisitor;
+    use crate::Hex;
+    use ::serde::{Deserializer, Serializer};
+    use std::marker::PhantomData;
+
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Into<Hex> + Copy,
+        S: Serializer,
+    {
+        serializer.collect_str(&(*color).into())
+    }
+
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: From<Hex>,
+        D: Deserializer<'de>,
+    {
+        let hex: Hex = deserializer.deserialize_str(ColorVisitor(PhantomData))?;
+        Ok(hex.into())
+    }
+}
+
+/// {"r":43,"g":196,"b":138,"a":0.5}, the alpha is omitted when it is 1 and is 1 when it is missing
+pub mod rgb_struct {
+    use crate::{RGB, RGBA};
+    use ::serde::de::{Error, MapAccess, Visitor};
+    use ::serde::ser::SerializeStruct;
+    use ::serde::{Deserializer, Serializer};
+    use std::fmt::Formatter;
+
+    const FIELDS: [&str; 4] = ["r", "g", "b", "a"];
+
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Into<RGBA> + Copy,
+        S: Serializer,
+    {
+        let rgba: RGBA = (*color).into();
+        let RGBA {
+            rgb: RGB { r, g, b },
+            a,
+        } = rgba;
+        let len = if a == 1.0 { 3 } else { 4 };
+        let mut state = serializer.serialize_struct("RGBA", len)?;
+        state.serialize_field("r", &r)?;
+        state.serialize_field("g", &g)?;
+        state.serialize_field("b", &b)?;
+        if len == 4 {
+            state.serialize_field("a", &a)?;
+        }
+        state.end()
+    }
+
+    struct RGBAVisitor;
+
+    impl<'de> Visitor<'de> for RGBAVisitor {
+        type Value = RGBA;
+
+        fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+            write!(f, "a struct with the fields r, g, b and an optional a")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let (mut r, mut g, mut b, mut a) = (None, None, None, None);
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "r" => r = Some(map.next_value::<u8>()?),
+                    "g" => g = Some(map.next_value::<u8>()?),
+                    "b" => b = Some(map.next_value::<u8>()?),
+                    "a" => a = Some(map.next_value::<f32>()?),
+                    _ => return Err(A::Error::unknown_field(&key, &FIELDS)),
+                }
+            }
+            let r = r.ok_or_else(|| A::Error::missing_field("r"))?;
+            let g = g.ok_or_else(|| A::Error::missing_field("g"))?;
+            let b = b.ok_or_else(|| A::Error::missing_field("b"))?;
+            RGBA::try_from((r, g, b, a.unwrap_or(1.0))).map_err(A::Error::custom)
+        }
+    }
+
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: From<RGBA>,
+        D: Deserializer<'de>,
+    {
+        let rgba = deserializer.deserialize_struct("RGBA", &FIELDS, RGBAVisitor)?;
+        Ok(rgba.into())
+    }
+}
+
+/// 2868362, see `PackedU32` for the byte order of each type
+pub mod packed_u32 {
+    use super::PackedU32;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: PackedU32,
+        S: Serializer,
+    {
+        serializer.serialize_u32(color.to_packed_u32())
+    }
+
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: PackedU32,
+        D: Deserializer<'de>,
+    {
+        u32::deserialize(deserializer).map(C::from_packed_u32)
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{Hex, CMYK, HSL, HSLA, HSV, RGB, RGBA};
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+/// Deserialize a color from any string its `TryFrom<&str>` accepts, the error carries the message of `ColorError`.
+pub(crate) struct ColorVisitor<T>(pub(crate) PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for ColorVisitor<T>
+where
+    T: for<'a> TryFrom<&'a str, Error = crate::ColorError>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "a color string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        T::try_from(v).map_err(E::custom)
+    }
+}
+
+/// Serialize as `to_string()` and deserialize from the same strings as `TryFrom<&str>`
+macro_rules! impl_serde {
+    ($($color:ty),*) => {
+        $(
+            impl Serialize for $color {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $color {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.deserialize_str(ColorVisitor(PhantomData))
+                }
+            }
+        )*
+    };
+}
+
+impl_serde!(Hex, RGB, RGBA, HSL, HSLA, HSV, CMYK);
@@ -0,0 +1,48 @@
+use crate::{
+    AdobeRGB, ColorError, Gray, HSLuv, Hex, OkLch, ProPhotoRGB, RGBf, Rec2020, CMYK, CMYKA, HSI,
+    HSL, HSLA, HSV, HWB, LCH, P3, RGB, RGB16, RGBA,
+};
+
+/// Parse an owned `String` like `TryFrom<&str>`, for generic code and crates that hand out `String`s
+macro_rules! impl_try_from_string {
+    ($($color:ty),*) => {
+        $(
+            impl TryFrom<String> for $color {
+                type Error = ColorError;
+                fn try_from(color_str: String) -> Result<Self, Self::Error> {
+                    color_str.as_str().try_into()
+                }
+            }
+
+            impl TryFrom<&String> for $color {
+                type Error = ColorError;
+                fn try_from(color_str: &String) -> Result<Self, Self::Error> {
+                    color_str.as_str().try_into()
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_string!(
+    Hex,
+    RGB,
+    RGBA,
+    HSL,
+    HSLA,
+    HSV,
+    HSI,
+    HWB,
+    CMYK,
+    CMYKA,
+    LCH,
+    OkLch,
+    HSLuv,
+    Gray,
+    RGB16,
+    RGBf,
+    P3,
+    Rec2020,
+    AdobeRGB,
+    ProPhotoRGB
+);
@@ -1,8 +1,10 @@
-use crate::{RGB, RGBA, HSL, Hex, HSLA, CMYK, HSV};
+use crate::{RGB, RGBA, HSL, Hex, HSLA, CMYK, HSV, HWB, LCH, OkLch, XYZ, YIQ, HSI, P3, Rec2020, AdobeRGB, ProPhotoRGB, HSLuv, Cam16, LMS, Gray, RGB16, RGBf, RGBAf, RGB565, Ansi256, CMYKA, ColorDescription, Easing, LumaStandard, EnsuredContrast, WcagLevel, TextSize, DistanceMetric, ColorBlindness};
+use crate::common::wrap_hue;
 
 pub trait Color {
     fn is_dark(&self) -> bool;
     fn is_light(&self) -> bool;
+    fn is_dark_with(&self, standard:LumaStandard, threshold:f32) -> bool;
 }
 
 impl<T:Into<RGB>+Copy> Color for T {
@@ -15,6 +17,105 @@ impl<T:Into<RGB>+Copy> Color for T {
         !self.is_dark()
     }
 
+    fn is_dark_with(&self, standard:LumaStandard, threshold:f32) -> bool {
+        let rgb:RGB=(*self).into();
+        rgb.is_dark_with(standard, threshold)
+    }
+
+}
+
+pub trait Contrast {
+    fn relative_luminance(&self) -> f32;
+    fn contrast_ratio<C:Into<RGB>>(&self, other:C) -> f32;
+    fn contrast_text(&self) -> RGB;
+    fn contrast_text_custom<D:Into<RGB>, L:Into<RGB>>(&self, dark:D, light:L) -> RGB;
+    fn ensure_contrast<C:Into<RGB>>(&self, background:C, ratio:f32) -> EnsuredContrast;
+    fn is_readable_on<C:Into<RGB>>(&self, background:C, level:WcagLevel, size:TextSize) -> bool;
+}
+
+impl<T:Into<RGB>+Copy> Contrast for T {
+    fn relative_luminance(&self) -> f32 {
+        let rgb:RGB=(*self).into();
+        rgb.relative_luminance()
+    }
+
+    fn contrast_ratio<C:Into<RGB>>(&self, other:C) -> f32 {
+        let rgb:RGB=(*self).into();
+        rgb.contrast_ratio(other)
+    }
+
+    fn contrast_text(&self) -> RGB {
+        let rgb:RGB=(*self).into();
+        rgb.contrast_text()
+    }
+
+    fn contrast_text_custom<D:Into<RGB>, L:Into<RGB>>(&self, dark:D, light:L) -> RGB {
+        let rgb:RGB=(*self).into();
+        rgb.contrast_text_custom(dark, light)
+    }
+
+    fn ensure_contrast<C:Into<RGB>>(&self, background:C, ratio:f32) -> EnsuredContrast {
+        let rgb:RGB=(*self).into();
+        rgb.ensure_contrast(background, ratio)
+    }
+
+    fn is_readable_on<C:Into<RGB>>(&self, background:C, level:WcagLevel, size:TextSize) -> bool {
+        let rgb:RGB=(*self).into();
+        rgb.is_readable_on(background, level, size)
+    }
+}
+
+pub trait Distance {
+    fn distance<C:Into<RGB>>(&self, other:C) -> f32;
+    fn distance_redmean<C:Into<RGB>>(&self, other:C) -> f32;
+}
+
+impl<T:Into<RGB>+Copy> Distance for T {
+    fn distance<C:Into<RGB>>(&self, other:C) -> f32 {
+        let rgb:RGB=(*self).into();
+        rgb.distance(other)
+    }
+
+    fn distance_redmean<C:Into<RGB>>(&self, other:C) -> f32 {
+        let rgb:RGB=(*self).into();
+        rgb.distance_redmean(other)
+    }
+}
+
+pub trait DeltaE {
+    fn delta_e2000<C:Into<RGB>>(&self, other:C) -> f32;
+    fn nearest_in<C:Into<RGB> + Copy>(&self, palette:&[C], metric:DistanceMetric) -> Option<(usize, RGB, f32)>;
+}
+
+impl<T:Into<RGB>+Copy> DeltaE for T {
+    fn delta_e2000<C:Into<RGB>>(&self, other:C) -> f32 {
+        let rgb:RGB=(*self).into();
+        rgb.delta_e2000(other)
+    }
+
+    fn nearest_in<C:Into<RGB> + Copy>(&self, palette:&[C], metric:DistanceMetric) -> Option<(usize, RGB, f32)> {
+        let rgb:RGB=(*self).into();
+        rgb.nearest_in(palette, metric)
+    }
+}
+
+pub trait Luminance {
+    /// The relative luminance(0~1) of WCAG in linear light, the same as `relative_luminance`
+    fn luminance(&self) -> f32;
+    /// The Rec.601 luma(0~255) of the gamma encoded channels
+    fn luma(&self) -> u8;
+}
+
+impl<T:Into<RGB>+Copy> Luminance for T {
+    fn luminance(&self) -> f32 {
+        let rgb:RGB=(*self).into();
+        rgb.relative_luminance()
+    }
+
+    fn luma(&self) -> u8 {
+        let rgb:RGB=(*self).into();
+        rgb.luma()
+    }
 }
 
 pub trait Grayscale {
@@ -28,6 +129,28 @@ impl<T: Into<RGBA> + From<RGBA> + Copy >  Grayscale for T {
     }
 }
 
+pub trait Simulate {
+    fn simulate(&self, deficiency:ColorBlindness) -> Self;
+}
+
+impl<T: Into<RGBA> + From<RGBA> + Copy >  Simulate for T {
+    fn simulate(&self, deficiency:ColorBlindness) -> Self {
+        let rgba:RGBA=(*self).into();
+        rgba.simulate(deficiency).into()
+    }
+}
+
+pub trait Sepia {
+    fn sepia(&self, amount:f32) -> Self;
+}
+
+impl<T: Into<RGBA> + From<RGBA> + Copy >  Sepia for T {
+    fn sepia(&self, amount:f32) -> Self {
+        let rgba:RGBA=(*self).into();
+        rgba.sepia(amount).into()
+    }
+}
+
 pub trait Negate {
     fn negate(&self) -> Self;
 }
@@ -49,6 +172,60 @@ impl<T:Into<RGBA> + Copy, U: Into<RGBA> + From<RGBA> + Copy> ColorMix<T> for U {
     }
 }
 
+pub trait Lerp<T> {
+    fn lerp(&self, other:T, t:f32) -> Self;
+    fn lerp_eased(&self, other:T, t:f32, easing:Easing) -> Self;
+}
+impl<T:Into<RGBA> + Copy, U: Into<RGBA> + From<RGBA> + Copy> Lerp<T> for U {
+    fn lerp(&self, other: T, t: f32) -> Self {
+        let rgba:RGBA = (*self).into();
+        rgba.lerp(other, t).into()
+    }
+
+    fn lerp_eased(&self, other: T, t: f32, easing: Easing) -> Self {
+        let rgba:RGBA = (*self).into();
+        rgba.lerp_eased(other, t, easing).into()
+    }
+}
+
+pub trait StepsTo<T> {
+    /// n colors from the color to the other color evenly interpolated by `lerp`, including both of them,
+    /// 1 returns the color only
+    fn steps_to(&self, other:T, n:usize) -> Vec<Self> where Self: Sized;
+}
+impl<T:Into<RGBA> + Copy, U: Into<RGBA> + From<RGBA> + Copy> StepsTo<T> for U {
+    fn steps_to(&self, other: T, n: usize) -> Vec<Self> {
+        let rgba:RGBA = (*self).into();
+        let other:RGBA = other.into();
+        match n {
+            0 => Vec::new(),
+            1 => vec![*self],
+            _ => (0..n).map(|i| match i {
+                0 => *self,
+                // the end is the other color itself, not the color converted back and forth
+                i if i == n - 1 => other.into(),
+                i => rgba.lerp(other, i as f32 / (n - 1) as f32).into(),
+            }).collect(),
+        }
+    }
+}
+
+pub trait Over<T> {
+    fn over(&self, backdrop:T) -> Self;
+    fn over_linear(&self, backdrop:T) -> Self;
+}
+impl<T:Into<RGBA> + Copy, U: Into<RGBA> + From<RGBA> + Copy> Over<T> for U {
+    fn over(&self, backdrop: T) -> Self {
+        let rgba:RGBA = (*self).into();
+        rgba.over(backdrop).into()
+    }
+
+    fn over_linear(&self, backdrop: T) -> Self {
+        let rgba:RGBA = (*self).into();
+        rgba.over_linear(backdrop).into()
+    }
+}
+
 
 pub trait Darken {
     fn darken(&mut self, ratio:f32) -> Self;
@@ -141,3 +318,385 @@ impl<T:Into<CMYK> + Copy> IntoCMYK for T {
         (*self).into()
     }
 }
+
+pub trait IntoHWB {
+    fn to_hwb(&self) -> HWB;
+}
+
+impl<T:Into<HWB> + Copy> IntoHWB for T {
+    fn to_hwb(&self) -> HWB {
+        (*self).into()
+    }
+}
+
+pub trait IntoLCH {
+    fn to_lch(&self) -> LCH;
+}
+
+impl<T:Into<LCH> + Copy> IntoLCH for T {
+    fn to_lch(&self) -> LCH {
+        (*self).into()
+    }
+}
+
+pub trait IntoOkLch {
+    fn to_oklch(&self) -> OkLch;
+}
+
+impl<T:Into<OkLch> + Copy> IntoOkLch for T {
+    fn to_oklch(&self) -> OkLch {
+        (*self).into()
+    }
+}
+
+pub trait IntoXYZ {
+    fn to_xyz(&self) -> XYZ;
+}
+
+impl<T:Into<XYZ> + Copy> IntoXYZ for T {
+    fn to_xyz(&self) -> XYZ {
+        (*self).into()
+    }
+}
+
+pub trait IntoYIQ {
+    fn to_yiq(&self) -> YIQ;
+}
+
+impl<T:Into<YIQ> + Copy> IntoYIQ for T {
+    fn to_yiq(&self) -> YIQ {
+        (*self).into()
+    }
+}
+
+pub trait IntoHSI {
+    fn to_hsi(&self) -> HSI;
+}
+
+impl<T:Into<HSI> + Copy> IntoHSI for T {
+    fn to_hsi(&self) -> HSI {
+        (*self).into()
+    }
+}
+
+pub trait IntoP3 {
+    fn to_p3(&self) -> P3;
+}
+
+impl<T:Into<P3> + Copy> IntoP3 for T {
+    fn to_p3(&self) -> P3 {
+        (*self).into()
+    }
+}
+
+pub trait IntoRec2020 {
+    fn to_rec2020(&self) -> Rec2020;
+}
+
+impl<T:Into<Rec2020> + Copy> IntoRec2020 for T {
+    fn to_rec2020(&self) -> Rec2020 {
+        (*self).into()
+    }
+}
+
+pub trait IntoAdobeRGB {
+    fn to_adobe_rgb(&self) -> AdobeRGB;
+}
+
+impl<T:Into<AdobeRGB> + Copy> IntoAdobeRGB for T {
+    fn to_adobe_rgb(&self) -> AdobeRGB {
+        (*self).into()
+    }
+}
+
+pub trait IntoProPhotoRGB {
+    fn to_prophoto_rgb(&self) -> ProPhotoRGB;
+}
+
+impl<T:Into<ProPhotoRGB> + Copy> IntoProPhotoRGB for T {
+    fn to_prophoto_rgb(&self) -> ProPhotoRGB {
+        (*self).into()
+    }
+}
+
+pub trait IntoHSLuv {
+    fn to_hsluv(&self) -> HSLuv;
+}
+
+impl<T:Into<HSLuv> + Copy> IntoHSLuv for T {
+    fn to_hsluv(&self) -> HSLuv {
+        (*self).into()
+    }
+}
+
+pub trait IntoCam16 {
+    fn to_cam16(&self) -> Cam16;
+}
+
+impl<T:Into<Cam16> + Copy> IntoCam16 for T {
+    fn to_cam16(&self) -> Cam16 {
+        (*self).into()
+    }
+}
+
+pub trait IntoLMS {
+    fn to_lms(&self) -> LMS;
+}
+
+impl<T:Into<LMS> + Copy> IntoLMS for T {
+    fn to_lms(&self) -> LMS {
+        (*self).into()
+    }
+}
+
+pub trait IntoGray {
+    fn to_gray(&self) -> Gray;
+}
+
+impl<T:Into<Gray> + Copy> IntoGray for T {
+    fn to_gray(&self) -> Gray {
+        (*self).into()
+    }
+}
+
+pub trait IntoRGB16 {
+    fn to_rgb16(&self) -> RGB16;
+}
+
+impl<T:Into<RGB16> + Copy> IntoRGB16 for T {
+    fn to_rgb16(&self) -> RGB16 {
+        (*self).into()
+    }
+}
+
+pub trait IntoRGBf {
+    fn to_rgbf(&self) -> RGBf;
+}
+
+impl<T:Into<RGBf> + Copy> IntoRGBf for T {
+    fn to_rgbf(&self) -> RGBf {
+        (*self).into()
+    }
+}
+
+pub trait IntoRGBAf {
+    fn to_rgbaf(&self) -> RGBAf;
+}
+
+impl<T:Into<RGBAf> + Copy> IntoRGBAf for T {
+    fn to_rgbaf(&self) -> RGBAf {
+        (*self).into()
+    }
+}
+
+pub trait IntoRGB565 {
+    fn to_rgb565(&self) -> RGB565;
+}
+
+impl<T:Into<RGB565> + Copy> IntoRGB565 for T {
+    fn to_rgb565(&self) -> RGB565 {
+        (*self).into()
+    }
+}
+
+pub trait IntoAnsi256 {
+    fn to_ansi256(&self) -> Ansi256;
+}
+
+impl<T:Into<Ansi256> + Copy> IntoAnsi256 for T {
+    fn to_ansi256(&self) -> Ansi256 {
+        (*self).into()
+    }
+}
+
+pub trait IntoCMYKA {
+    fn to_cmyka(&self) -> CMYKA;
+}
+
+impl<T:Into<CMYKA> + Copy> IntoCMYKA for T {
+    fn to_cmyka(&self) -> CMYKA {
+        (*self).into()
+    }
+}
+
+pub trait Describe {
+    fn describe(&self) -> ColorDescription;
+}
+
+impl<T:Into<RGB> + Copy> Describe for T {
+    fn describe(&self) -> ColorDescription {
+        let rgb:RGB = (*self).into();
+        rgb.into()
+    }
+}
+
+pub trait RotateHue {
+    fn rotate_hue(&self, degrees:i32) -> Self;
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> RotateHue for T {
+    fn rotate_hue(&self, degrees:i32) -> Self {
+        let mut hsla:HSLA = (*self).into();
+        let h = hsla.hsl.h % 360;
+        hsla.hsl.h = wrap_hue(h as i32 + degrees % 360);
+        hsla.into()
+    }
+}
+
+pub trait Whiten {
+    fn whiten(&mut self, ratio:f32) -> Self;
+}
+
+impl<T:Into<RGBA> + From<RGBA> + Copy> Whiten for T {
+    fn whiten(&mut self, ratio:f32) -> Self {
+        let rgba:RGBA = (*self).into();
+        let mut hwb:HWB = rgba.rgb.into();
+        RGBA { rgb: (*hwb.whiten(ratio)).into(), a: rgba.a }.into()
+    }
+}
+
+pub trait Blacken {
+    fn blacken(&mut self, ratio:f32) -> Self;
+}
+
+impl<T:Into<RGBA> + From<RGBA> + Copy> Blacken for T {
+    fn blacken(&mut self, ratio:f32) -> Self {
+        let rgba:RGBA = (*self).into();
+        let mut hwb:HWB = rgba.rgb.into();
+        RGBA { rgb: (*hwb.blacken(ratio)).into(), a: rgba.a }.into()
+    }
+}
+
+pub trait Tint {
+    fn tint(&self, weight:f32) -> Self;
+}
+
+impl<T: Into<RGBA> + From<RGBA> + Copy > Tint for T {
+    fn tint(&self, weight:f32) -> Self {
+        let rgba:RGBA=(*self).into();
+        rgba.tint(weight).into()
+    }
+}
+
+pub trait Shade {
+    fn shade(&self, weight:f32) -> Self;
+}
+
+impl<T: Into<RGBA> + From<RGBA> + Copy > Shade for T {
+    fn shade(&self, weight:f32) -> Self {
+        let rgba:RGBA=(*self).into();
+        rgba.shade(weight).into()
+    }
+}
+
+pub trait Complement {
+    fn complement(&self) -> Self;
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> Complement for T {
+    fn complement(&self) -> Self {
+        let hsla:HSLA = (*self).into();
+        // a gray has no hue to rotate
+        if hsla.hsl.s == 0 {
+            return *self;
+        }
+        self.rotate_hue(180)
+    }
+}
+
+pub trait Triadic: Sized {
+    fn triadic(&self) -> [Self; 3];
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> Triadic for T {
+    fn triadic(&self) -> [Self; 3] {
+        [*self, self.rotate_hue(120), self.rotate_hue(240)]
+    }
+}
+
+pub trait Tetradic: Sized {
+    /// The square scheme, the hues are 90 degrees apart
+    fn tetradic(&self) -> [Self; 4];
+    /// The rectangle scheme, two complementary pairs with the offset between them
+    fn rectangle(&self, offset_degrees:i32) -> [Self; 4];
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> Tetradic for T {
+    fn tetradic(&self) -> [Self; 4] {
+        self.rectangle(90)
+    }
+
+    fn rectangle(&self, offset_degrees:i32) -> [Self; 4] {
+        [*self, self.rotate_hue(offset_degrees), self.rotate_hue(180), self.rotate_hue(180 + offset_degrees % 360)]
+    }
+}
+
+pub trait Analogous: Sized {
+    /// The colors spaced evenly across the spread, centered on the hue of the color,
+    /// so 5 colors over 60 degrees are -30, -15, 0, 15 and 30, and an even count does not include the color itself
+    fn analogous(&self, count:usize, spread_degrees:u32) -> Vec<Self>;
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> Analogous for T {
+    fn analogous(&self, count:usize, spread_degrees:u32) -> Vec<Self> {
+        if count == 1 {
+            return vec![*self];
+        }
+        let spread = spread_degrees.min(360) as f32;
+        let step = spread / count.saturating_sub(1) as f32;
+        (0..count).map(|i| {
+            let offset = -spread / 2.0 + step * i as f32;
+            self.rotate_hue(offset.round() as i32)
+        }).collect()
+    }
+}
+
+pub trait SplitComplementary: Sized {
+    /// The color and the two hues flanking its complement by the offset, 30 is the usual offset.
+    /// The offset is clamped to 90, beyond it the flanking hues would be closer to the color than to its complement,
+    /// and an offset of 0 repeats the complement.
+    fn split_complementary(&self, offset_degrees:u32) -> [Self; 3];
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> SplitComplementary for T {
+    fn split_complementary(&self, offset_degrees:u32) -> [Self; 3] {
+        let offset = offset_degrees.min(90) as i32;
+        [*self, self.rotate_hue(180 - offset), self.rotate_hue(180 + offset)]
+    }
+}
+
+pub trait Monochromatic: Sized {
+    /// The colors of the same hue and saturation with the lightness spread evenly from dark to light,
+    /// without black and white, the slot nearest to the color is the color itself
+    fn monochromatic(&self, count:usize) -> Vec<Self>;
+    /// The same as `monochromatic`, the first and last colors are black and white when `include_black_and_white` is true
+    fn monochromatic_with(&self, count:usize, include_black_and_white:bool) -> Vec<Self>;
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> Monochromatic for T {
+    fn monochromatic(&self, count:usize) -> Vec<Self> {
+        self.monochromatic_with(count, false)
+    }
+
+    fn monochromatic_with(&self, count:usize, include_black_and_white:bool) -> Vec<Self> {
+        let hsla:HSLA = (*self).into();
+        let lightness = |i:usize| if include_black_and_white && count > 1 {
+            100.0 * i as f32 / (count - 1) as f32
+        } else {
+            100.0 * (i + 1) as f32 / (count + 1) as f32
+        };
+        let nearest = (0..count).min_by(|&i, &j| {
+            let di = (lightness(i) - hsla.hsl.l as f32).abs();
+            let dj = (lightness(j) - hsla.hsl.l as f32).abs();
+            di.total_cmp(&dj)
+        });
+        (0..count).map(|i| {
+            if Some(i) == nearest {
+                return *self;
+            }
+            let mut shade = hsla;
+            shade.hsl.l = lightness(i).round() as u32;
+            shade.into()
+        }).collect()
+    }
+}
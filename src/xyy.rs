@@ -0,0 +1,108 @@
+use crate::{ColorError, WhitePoint, RGB, XYZ};
+use std::fmt::{Display, Formatter};
+
+/// Xyy is the CIE xyY color space, the chromaticity coordinates (x,y) plus the luminance Y,
+/// it can be created from a tuple (x,y,Y) or converted from RGB and XYZ.
+/// * x:f32 - chromaticity x(0~1)
+/// * y:f32 - chromaticity y(0~1)
+/// * Y:f32 - luminance(0~1)
+///
+/// The chromaticity is relative to D65. Black has no chromaticity, so it gets the one of the white point instead.
+/// Chromaticities out of the sRGB gamut are clamped when converted back to RGB.
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, Xyy};
+/// let rgb:RGB = (255,255,255).try_into().unwrap();
+/// let xyy:Xyy = rgb.into();
+/// assert_eq!(xyy.to_string(), "xyY(0.3127, 0.3290, 1.0000)");
+///
+/// let xyy:Xyy = (0.64, 0.33, 0.2126).try_into().unwrap();
+/// let rgb:RGB = xyy.into();
+/// assert_eq!(rgb.to_string(), "rgb(255,0,0)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Xyy {
+    pub(crate) x: f32,
+    pub(crate) y: f32,
+    pub(crate) luminance: f32,
+}
+
+impl TryFrom<(f32, f32, f32)> for Xyy {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(0.0..=1.0).contains(&value.1)
+            || !(0.0..=1.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "xyY: args ({},{},{}) value error, all value must between 0~1!",
+                    value.0, value.1, value.2
+                ),
+            })
+        } else {
+            Ok(Self {
+                x: value.0,
+                y: value.1,
+                luminance: value.2,
+            })
+        }
+    }
+}
+
+impl From<RGB> for Xyy {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZ = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<XYZ> for Xyy {
+    fn from(xyz: XYZ) -> Self {
+        let XYZ { x, y, z, .. } = xyz.to_white_point(WhitePoint::D65);
+        let sum = x + y + z;
+        if sum <= 0.0 {
+            let (x, y) = WhitePoint::D65.chromaticity();
+            return Self {
+                x,
+                y,
+                luminance: 0.0,
+            };
+        }
+        Self {
+            x: x / sum,
+            y: y / sum,
+            luminance: y / 100.0,
+        }
+    }
+}
+
+impl Display for Xyy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "xyY({:.4}, {:.4}, {:.4})",
+            self.x, self.y, self.luminance
+        )
+    }
+}
+
+impl Xyy {
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+
+    pub fn luminance(&self) -> f32 {
+        self.luminance
+    }
+
+    /// Returns the chromaticity coordinates (x,y)
+    pub fn chromaticity(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+}
@@ -0,0 +1,213 @@
+use crate::common::{multiply_matrix, rgb_to_xyz, D50_TO_D65, D50_WHITE, D65_TO_D50, D65_WHITE};
+use crate::{ColorError, Hex, ProPhotoRGB, Rec2020, Xyy, LMS, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+
+/// The reference white of a CIE XYZ value.
+/// * D65 - the white point of sRGB, it is the default one
+/// * D50 - the white point of CIE Lab and of most print and ICC workflows
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum WhitePoint {
+    #[default]
+    D65,
+    D50,
+}
+
+impl WhitePoint {
+    /// Returns the XYZ tristimulus values of the white point, Y is 100.
+    /// ```rust
+    /// use easy_color::WhitePoint;
+    /// let (x, y, z) = WhitePoint::D65.tristimulus();
+    /// assert!((x - 95.046).abs() < 0.01 && y == 100.0 && (z - 108.906).abs() < 0.01);
+    /// ```
+    pub fn tristimulus(&self) -> (f32, f32, f32) {
+        let white = match self {
+            WhitePoint::D65 => D65_WHITE,
+            WhitePoint::D50 => D50_WHITE,
+        };
+        (white[0] * 100.0, white[1] * 100.0, white[2] * 100.0)
+    }
+
+    /// Returns the chromaticity coordinates (x,y) of the white point.
+    pub fn chromaticity(&self) -> (f32, f32) {
+        match self {
+            WhitePoint::D65 => (0.3127, 0.3290),
+            WhitePoint::D50 => (0.3457, 0.3585),
+        }
+    }
+}
+
+/// XYZ is the CIE 1931 XYZ tristimulus value of a color, scaled so that Y of the white point is 100.
+/// It can be created from a tuple (x,y,z), which is relative to D65, or converted from RGB, RGBA and Hex.
+/// * x:f32 - X(0~)
+/// * y:f32 - Y(0~), the luminance
+/// * z:f32 - Z(0~)
+///
+/// RGB is converted with the sRGB matrix after gamma linearization, relative to D65.
+/// `to_white_point` adapts the value to another white point with the Bradford transform,
+/// and the conversion back to RGB always honors the current white point.
+/// Values out of the sRGB gamut are clamped when converted back to RGB.
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, WhitePoint, XYZ};
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// let xyz:XYZ = rgb.into();
+/// assert_eq!(xyz.to_string(), "xyz(41.24 21.26 1.93)");
+///
+/// let d50 = xyz.to_white_point(WhitePoint::D50);
+/// assert_eq!(d50.white_point(), WhitePoint::D50);
+/// assert_eq!(d50.to_string(), "xyz(43.61 22.25 1.39)");
+///
+/// let rgb:RGB = d50.into();
+/// assert_eq!(rgb.to_string(), "rgb(255,0,0)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct XYZ {
+    pub(crate) x: f32,
+    pub(crate) y: f32,
+    pub(crate) z: f32,
+    pub(crate) white_point: WhitePoint,
+}
+
+impl TryFrom<(f32, f32, f32)> for XYZ {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=f32::MAX).contains(&value.0)
+            || !(0.0..=f32::MAX).contains(&value.1)
+            || !(0.0..=f32::MAX).contains(&value.2)
+        {
+            Err(ColorError::ValueErr {
+                input: format!("{:?}", value),
+                reason: format!(
+                    "XYZ: args ({},{},{}) value error, all value must not be negative!",
+                    value.0, value.1, value.2
+                ),
+            })
+        } else {
+            Ok(Self {
+                x: value.0,
+                y: value.1,
+                z: value.2,
+                white_point: WhitePoint::D65,
+            })
+        }
+    }
+}
+
+impl From<Hex> for XYZ {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for XYZ {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (x, y, z) = rgb_to_xyz(r, g, b);
+        Self {
+            x: x * 100.0,
+            y: y * 100.0,
+            z: z * 100.0,
+            white_point: WhitePoint::D65,
+        }
+    }
+}
+
+impl From<RGBA> for XYZ {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<Xyy> for XYZ {
+    fn from(xyy: Xyy) -> Self {
+        let Xyy { x, y, luminance } = xyy;
+        if y <= 0.0 {
+            return Self::default();
+        }
+        let big_y = luminance * 100.0;
+        Self {
+            x: x * big_y / y,
+            y: big_y,
+            z: (1.0 - x - y) * big_y / y,
+            white_point: WhitePoint::D65,
+        }
+    }
+}
+
+impl From<Rec2020> for XYZ {
+    fn from(rec2020: Rec2020) -> Self {
+        let (x, y, z) = rec2020.to_xyz_unit();
+        Self {
+            x: x * 100.0,
+            y: y * 100.0,
+            z: z * 100.0,
+            white_point: WhitePoint::D65,
+        }
+    }
+}
+
+impl From<ProPhotoRGB> for XYZ {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let (x, y, z) = prophoto.to_xyz_d50();
+        Self {
+            x: x * 100.0,
+            y: y * 100.0,
+            z: z * 100.0,
+            white_point: WhitePoint::D50,
+        }
+    }
+}
+
+impl From<LMS> for XYZ {
+    fn from(lms: LMS) -> Self {
+        let (x, y, z) = lms.to_xyz_unit();
+        Self {
+            x: x * 100.0,
+            y: y * 100.0,
+            z: z * 100.0,
+            white_point: WhitePoint::D65,
+        }
+    }
+}
+
+impl Display for XYZ {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xyz({:.2} {:.2} {:.2})", self.x, self.y, self.z)
+    }
+}
+
+impl XYZ {
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+
+    pub fn z(&self) -> f32 {
+        self.z
+    }
+
+    pub fn white_point(&self) -> WhitePoint {
+        self.white_point
+    }
+
+    /// Returns the same color relative to another white point, using the Bradford chromatic adaptation.
+    pub fn to_white_point(&self, white_point: WhitePoint) -> Self {
+        let matrix = match (self.white_point, white_point) {
+            (WhitePoint::D65, WhitePoint::D50) => &D65_TO_D50,
+            (WhitePoint::D50, WhitePoint::D65) => &D50_TO_D65,
+            _ => return *self,
+        };
+        let (x, y, z) = multiply_matrix(matrix, (self.x, self.y, self.z));
+        Self {
+            x,
+            y,
+            z,
+            white_point,
+        }
+    }
+}
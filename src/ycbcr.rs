@@ -0,0 +1,141 @@
+use crate::{ColorError, RGB};
+use std::fmt::{Display, Formatter};
+
+/// The luma coefficients used by a YCbCr matrix.
+/// * Bt601 - standard definition video and JPEG, it is the default one
+/// * Bt709 - HD video
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Standard {
+    #[default]
+    Bt601,
+    Bt709,
+}
+
+impl Standard {
+    /// Returns the (Kr, Kb) coefficients of the standard
+    pub(crate) fn coefficients(&self) -> (f32, f32) {
+        match self {
+            Standard::Bt601 => (0.299, 0.114),
+            Standard::Bt709 => (0.2126, 0.0722),
+        }
+    }
+}
+
+/// The value range of YCbCr channels.
+/// * Full - every channel uses 0~255, like JPEG does, it is the default one
+/// * Studio - Y uses 16~235 and Cb/Cr use 16~240, like most video does
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum ColorRange {
+    #[default]
+    Full,
+    Studio,
+}
+
+/// YCbCr can be created from a tuple (y,cb,cr), which is BT.601 full range, or converted from RGB with any `Standard` and `ColorRange`.
+/// * y:u8 - luma
+/// * cb:u8 - blue-difference chroma, 128 is neutral
+/// * cr:u8 - red-difference chroma, 128 is neutral
+///
+/// In studio range the channels are clamped to 16~235 / 16~240 both when converted from and to RGB.
+/// ### example
+/// ```rust
+/// use easy_color::{ColorRange, RGB, Standard, YCbCr};
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// let ycbcr:YCbCr = rgb.into();
+/// assert_eq!(ycbcr.to_string(), "ycbcr(76,85,255)");
+///
+/// let ycbcr = YCbCr::from_rgb(rgb, Standard::Bt709, ColorRange::Studio);
+/// assert_eq!(ycbcr.to_string(), "ycbcr(63,102,240)");
+///
+/// // 8 bit studio range can't represent every rgb exactly
+/// let rgb:RGB = ycbcr.into();
+/// assert_eq!(rgb.to_string(), "rgb(255,1,0)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct YCbCr {
+    pub(crate) y: u8,
+    pub(crate) cb: u8,
+    pub(crate) cr: u8,
+    pub(crate) standard: Standard,
+    pub(crate) range: ColorRange,
+}
+
+impl TryFrom<(u8, u8, u8)> for YCbCr {
+    type Error = ColorError;
+    fn try_from(value: (u8, u8, u8)) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            value.0,
+            value.1,
+            value.2,
+            Standard::Bt601,
+            ColorRange::Full,
+        ))
+    }
+}
+
+impl From<RGB> for YCbCr {
+    fn from(rgb: RGB) -> Self {
+        Self::from_rgb(rgb, Standard::Bt601, ColorRange::Full)
+    }
+}
+
+impl Display for YCbCr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ycbcr({},{},{})", self.y, self.cb, self.cr)
+    }
+}
+
+impl YCbCr {
+    pub fn new(y: u8, cb: u8, cr: u8, standard: Standard, range: ColorRange) -> Self {
+        Self {
+            y,
+            cb,
+            cr,
+            standard,
+            range,
+        }
+    }
+
+    /// Convert a color into YCbCr with the given matrix and range.
+    pub fn from_rgb(rgb: impl Into<RGB>, standard: Standard, range: ColorRange) -> Self {
+        let RGB { r, g, b } = rgb.into();
+        let (kr, kb) = standard.coefficients();
+        let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+        let luma = kr * r + (1.0 - kr - kb) * g + kb * b;
+        let pb = (b - luma) / (2.0 * (1.0 - kb));
+        let pr = (r - luma) / (2.0 * (1.0 - kr));
+        let (y, cb, cr) = match range {
+            ColorRange::Full => (
+                (luma * 255.0).round(),
+                (128.0 + pb * 255.0).round().clamp(0.0, 255.0),
+                (128.0 + pr * 255.0).round().clamp(0.0, 255.0),
+            ),
+            ColorRange::Studio => (
+                (16.0 + luma * 219.0).round().clamp(16.0, 235.0),
+                (128.0 + pb * 224.0).round().clamp(16.0, 240.0),
+                (128.0 + pr * 224.0).round().clamp(16.0, 240.0),
+            ),
+        };
+        Self::new(y as u8, cb as u8, cr as u8, standard, range)
+    }
+
+    pub fn y(&self) -> u8 {
+        self.y
+    }
+
+    pub fn cb(&self) -> u8 {
+        self.cb
+    }
+
+    pub fn cr(&self) -> u8 {
+        self.cr
+    }
+
+    pub fn standard(&self) -> Standard {
+        self.standard
+    }
+
+    pub fn range(&self) -> ColorRange {
+        self.range
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{ColorError, Hex, RGB, RGBA};
+use std::fmt::{Display, Formatter};
+
+/// YIQ is the color space of NTSC television, it can be created from a tuple (y,i,q) or converted from RGB, RGBA and Hex.
+/// * y:f32 - luma(0~1)
+/// * i:f32 - in-phase(-0.5957~0.5957), orange-blue axis
+/// * q:f32 - quadrature(-0.5226~0.5226), purple-green axis
+///
+/// The channels are clamped before converted back to RGB, so very saturated I/Q values do not wrap.
+/// ### example
+/// ```rust
+/// use easy_color::{IntoHex, RGB, YIQ};
+/// let rgb:RGB = (255,0,0).try_into().unwrap();
+/// let yiq:YIQ = rgb.into();
+/// assert_eq!(yiq.to_string(), "yiq(0.299,0.596,0.212)");
+/// assert_eq!(yiq.luma(), 0.299);
+///
+/// let yiq:YIQ = (0.5, 0.2, -0.1).try_into().unwrap();
+/// assert_eq!(yiq.to_hex().to_string(), "#A0821C");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct YIQ {
+    pub(crate) y: f32,
+    pub(crate) i: f32,
+    pub(crate) q: f32,
+}
+
+impl TryFrom<(f32, f32, f32)> for YIQ {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(-0.5957..=0.5957).contains(&value.1)
+            || !(-0.5226..=0.5226).contains(&value.2)
+        {
+            Err(ColorError::ValueErr { input: format!("{:?}", value), reason: format!("YIQ: args ({},{},{}) value error, y must between 0~1, i must between -0.5957~0.5957, and q must between -0.5226~0.5226!", value.0, value.1, value.2) })
+        } else {
+            Ok(Self {
+                y: value.0,
+                i: value.1,
+                q: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for YIQ {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for YIQ {
+    fn from(rgb: RGB) -> Self {
+        let r = rgb.r as f32 / 255.0;
+        let g = rgb.g as f32 / 255.0;
+        let b = rgb.b as f32 / 255.0;
+        Self {
+            y: 0.299 * r + 0.587 * g + 0.114 * b,
+            i: 0.5959 * r - 0.2746 * g - 0.3213 * b,
+            q: 0.2115 * r - 0.5227 * g + 0.3112 * b,
+        }
+    }
+}
+
+impl From<RGBA> for YIQ {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for YIQ {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "yiq({:.3},{:.3},{:.3})", self.y, self.i, self.q)
+    }
+}
+
+impl YIQ {
+    pub fn luma(&self) -> f32 {
+        self.y
+    }
+
+    pub fn i(&self) -> f32 {
+        self.i
+    }
+
+    pub fn q(&self) -> f32 {
+        self.q
+    }
+}